@@ -4,11 +4,13 @@
 //! Implements a basic rule-based strategy with hand evaluation and pot odds calculation.
 
 use crate::AIOpponent;
-use axiomind_engine::cards::{Card, Rank};
-use axiomind_engine::engine::Engine;
-use axiomind_engine::hand::{Category, evaluate_hand};
+use axiomind_engine::cards::Card;
+use axiomind_engine::hand::Category;
 use axiomind_engine::logger::Street;
+use axiomind_engine::observation::PlayerObservation;
 use axiomind_engine::player::PlayerAction;
+use axiomind_engine::rules::LegalActions;
+use axiomind_engine::variant::GameVariant;
 
 /// Simple baseline AI implementation for testing and comparison.
 ///
@@ -46,7 +48,7 @@ use axiomind_engine::player::PlayerAction;
 /// engine.deal_hand().expect("Failed to deal hand");
 ///
 /// let player_id = engine.current_player().expect("No current player");
-/// let action = ai.get_action(&engine, player_id);
+/// let action = ai.get_action(&engine.observation(player_id).unwrap());
 /// // Action will be determined by hand strength and game state
 /// ```
 #[derive(Debug, Clone)]
@@ -199,36 +201,27 @@ impl BaselineAI {
         }
     }
 
-    /// Evaluate postflop hand strength using 7-card evaluation.
+    /// Evaluate postflop hand strength from the best five of hole cards plus board.
     ///
     /// # Arguments
     ///
-    /// * `hole_cards` - Two hole cards
+    /// * `variant` - Game variant, which decides how hole cards combine with the board
+    /// * `hole_cards` - All hole cards of the player
     /// * `board` - Community cards on the board
     ///
     /// # Returns
     ///
     /// Hand strength on scale of 0-10, or None if board has fewer than 3 cards
-    fn evaluate_postflop_strength(hole_cards: [Card; 2], board: &[Card]) -> Option<u8> {
+    fn evaluate_postflop_strength(
+        variant: GameVariant,
+        hole_cards: &[Card],
+        board: &[Card],
+    ) -> Option<u8> {
         if board.len() < 3 {
             return None;
         }
 
-        // For turn and river with <5 board cards, use what we have + dummy cards
-        let mut seven_cards = vec![hole_cards[0], hole_cards[1]];
-        seven_cards.extend_from_slice(board);
-
-        // Pad with dummy cards if needed (won't affect relative hand strength much)
-        while seven_cards.len() < 7 {
-            // Add dummy low cards that are unlikely to affect evaluation
-            seven_cards.push(Card {
-                suit: axiomind_engine::cards::Suit::Clubs,
-                rank: Rank::Two,
-            });
-        }
-
-        let cards_array: [Card; 7] = seven_cards[..7].try_into().ok()?;
-        let strength = evaluate_hand(&cards_array);
+        let strength = variant.best_hand(hole_cards, board).ok()?.strength;
 
         // Convert category to 0-10 scale
         let base_strength = match strength.category {
@@ -370,6 +363,22 @@ impl BaselineAI {
             _ => PlayerAction::Check,
         }
     }
+
+    /// Snap bet and raise sizes onto the legal range and chip unit.
+    ///
+    /// A raise that is not allowed (e.g. the stack only covers a call) becomes a call.
+    fn fit_to_legal(action: PlayerAction, legal: &LegalActions) -> PlayerAction {
+        match action {
+            PlayerAction::Bet(amount) => match legal.bet {
+                Some(range) => PlayerAction::Bet(range.clamp(amount)),
+                None => PlayerAction::Check,
+            },
+            PlayerAction::Raise(amount) => legal
+                .raise_to_action(legal.current_bet.saturating_add(amount))
+                .unwrap_or(PlayerAction::Call),
+            other => other,
+        }
+    }
 }
 
 impl Default for BaselineAI {
@@ -389,24 +398,20 @@ impl AIOpponent for BaselineAI {
     ///
     /// # Arguments
     ///
-    /// * `engine` - Reference to the game engine
-    /// * `player_id` - The player ID making the decision
+    /// * `observation` - The acting player's view of the hand
     ///
     /// # Returns
     ///
     /// A valid `PlayerAction` that will not cause the game to crash
-    fn get_action(&self, engine: &Engine, player_id: usize) -> PlayerAction {
-        // Get player info
-        let players = engine.players();
-        let player = &players[player_id];
+    fn get_action(&self, observation: &PlayerObservation) -> PlayerAction {
+        let legal = &observation.legal_actions;
 
         // Extract hole cards - if not available, default to conservative play
-        let hole_cards_opt = player.hole_cards();
-        let hole_cards = match (hole_cards_opt[0], hole_cards_opt[1]) {
-            (Some(c1), Some(c2)) => [c1, c2],
+        let hole_cards = match observation.hole[..] {
+            [c1, c2, ..] => [c1, c2],
             _ => {
                 // No hole cards, default to check/fold
-                return if engine.to_call(player_id).unwrap_or(0) == 0 {
+                return if observation.to_call == 0 {
                     PlayerAction::Check
                 } else {
                     PlayerAction::Fold
@@ -415,27 +420,33 @@ impl AIOpponent for BaselineAI {
         };
 
         // Get game state
-        let to_call = engine.to_call(player_id).unwrap_or(0);
-        let min_raise = engine.min_raise().unwrap_or(100);
-        let stack = player.stack();
-        let pot = engine.pot();
-        let board = engine.board();
-        let street = engine.current_street();
+        let to_call = observation.to_call;
+        let stack = observation.stack();
+        let min_raise = legal
+            .bet
+            .map(|range| range.min)
+            .or_else(|| legal.raise_to.map(|range| range.min - legal.current_bet))
+            .unwrap_or(stack);
+        let pot = observation.pot;
+        let board = &observation.board;
+        let street = observation.street;
 
         // Evaluate hand strength based on street
         let hand_strength = if street.is_none() || street == Some(Street::Preflop) {
             // Preflop evaluation
             Self::evaluate_preflop_strength(hole_cards)
         } else {
-            // Postflop evaluation
-            Self::evaluate_postflop_strength(hole_cards, board).unwrap_or_else(|| {
-                // Fallback to preflop if board evaluation fails
-                Self::evaluate_preflop_strength(hole_cards)
-            })
+            // Postflop evaluation, on all four hole cards in Omaha
+            Self::evaluate_postflop_strength(observation.variant, &observation.hole, board)
+                .unwrap_or_else(|| {
+                    // Fallback to preflop if board evaluation fails
+                    Self::evaluate_preflop_strength(hole_cards)
+                })
         };
 
         // Make deterministic decision based on all factors
-        Self::decide_action(hand_strength, to_call, min_raise, stack, pot)
+        let action = Self::decide_action(hand_strength, to_call, min_raise, stack, pot);
+        Self::fit_to_legal(action, legal)
     }
 
     /// Return the name of this AI implementation.
@@ -451,7 +462,8 @@ impl AIOpponent for BaselineAI {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use axiomind_engine::cards::{Rank, Suit};
+    use axiomind_engine::cards::{Rank, Suit, parse_cards};
+    use axiomind_engine::engine::Engine;
 
     #[test]
     fn test_baseline_ai_creation() {
@@ -584,11 +596,20 @@ mod tests {
             },
         ];
 
-        let strength = BaselineAI::evaluate_postflop_strength(hole, &board);
+        let strength = BaselineAI::evaluate_postflop_strength(GameVariant::Holdem, &hole, &board);
         assert!(strength.is_some());
         assert!(strength.unwrap() >= 6); // Three of a kind or better
     }
 
+    #[test]
+    fn test_postflop_strength_on_flop_has_no_phantom_straight() {
+        let hole = parse_cards("3h4d").unwrap();
+        let board = parse_cards("5c9sKd").unwrap();
+
+        let strength = BaselineAI::evaluate_postflop_strength(GameVariant::Holdem, &hole, &board);
+        assert_eq!(strength, Some(2)); // King-high, nothing more
+    }
+
     #[test]
     fn test_baseline_ai_action_with_hole_cards() {
         let ai = BaselineAI::new();
@@ -598,7 +619,7 @@ mod tests {
         engine.deal_hand().expect("Failed to deal hand");
 
         let player_id = engine.current_player().expect("No current player");
-        let action = ai.get_action(&engine, player_id);
+        let action = ai.get_action(&engine.observation(player_id).unwrap());
 
         // Should return a valid action (not panic)
         // Action should be one of the valid types
@@ -620,7 +641,7 @@ mod tests {
         let engine = Engine::new(Some(42), 1);
 
         // No hand dealt yet
-        let action = ai.get_action(&engine, 0);
+        let action = ai.get_action(&engine.observation(0).unwrap());
 
         // Should default to check or fold without panicking
         match action {
@@ -24,7 +24,7 @@
 //! engine.deal_hand().expect("Failed to deal hand");
 //!
 //! let player_id = engine.current_player().expect("No current player");
-//! let action = ai.get_action(&engine, player_id);
+//! let action = ai.get_action(&engine.observation(player_id).unwrap());
 //! println!("AI chose action: {:?}", action);
 //! ```
 //!
@@ -33,7 +33,7 @@
 //! Currently supported AI types:
 //! - `"baseline"` - Simple baseline AI for testing and benchmarking
 
-use axiomind_engine::engine::Engine;
+use axiomind_engine::observation::PlayerObservation;
 use axiomind_engine::player::PlayerAction;
 
 pub mod baseline;
@@ -43,22 +43,26 @@ pub mod baseline;
 ///
 /// # Required Methods
 ///
-/// - [`get_action`](AIOpponent::get_action) - Determine the next action based on game state
+/// - [`get_action`](AIOpponent::get_action) - Determine the next action from what the player can see
 /// - [`name`](AIOpponent::name) - Return the AI's identifier/name
 ///
 /// # Example Implementation
 ///
 /// ```rust
 /// use axiomind_ai::AIOpponent;
-/// use axiomind_engine::engine::Engine;
+/// use axiomind_engine::observation::PlayerObservation;
 /// use axiomind_engine::player::PlayerAction;
 ///
 /// struct MyAI;
 ///
 /// impl AIOpponent for MyAI {
-///     fn get_action(&self, engine: &Engine, player_id: usize) -> PlayerAction {
-///         // Simple strategy: always check or call
-///         PlayerAction::Call
+///     fn get_action(&self, observation: &PlayerObservation) -> PlayerAction {
+///         // Simple strategy: check when free, otherwise call
+///         if observation.legal_actions.check {
+///             PlayerAction::Check
+///         } else {
+///             PlayerAction::Call
+///         }
 ///     }
 ///
 ///     fn name(&self) -> &str {
@@ -67,12 +71,16 @@ pub mod baseline;
 /// }
 /// ```
 pub trait AIOpponent: Send + Sync {
-    /// Determine the next action for the AI player based on current game state.
+    /// Determine the next action for the AI player from its view of the hand.
+    ///
+    /// The observation holds only the AI's own hole cards and public
+    /// information, so a policy cannot peek at the opponent's cards or the deck.
     ///
     /// # Arguments
     ///
-    /// * `engine` - Reference to the game engine containing current state
-    /// * `player_id` - The player ID for which to determine an action (0 or 1)
+    /// * `observation` - What the acting player can see, from [`Engine::observation`]
+    ///
+    /// [`Engine::observation`]: axiomind_engine::engine::Engine::observation
     ///
     /// # Returns
     ///
@@ -81,14 +89,14 @@ pub trait AIOpponent: Send + Sync {
     /// # Example
     ///
     /// ```ignore
-    /// let action = ai.get_action(&engine, 0);
+    /// let action = ai.get_action(&engine.observation(0).unwrap());
     /// match action {
     ///     PlayerAction::Call => println!("AI calls"),
     ///     PlayerAction::Raise(amount) => println!("AI raises {}", amount),
     ///     _ => println!("AI takes other action"),
     /// }
     /// ```
-    fn get_action(&self, engine: &Engine, player_id: usize) -> PlayerAction;
+    fn get_action(&self, observation: &PlayerObservation) -> PlayerAction;
 
     /// Return the name/identifier of this AI implementation.
     ///
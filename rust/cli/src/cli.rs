@@ -65,11 +65,14 @@ pub enum Commands {
     /// * `--hands` - Number of hands to play (default: 1)
     /// * `--seed` - RNG seed for reproducibility (default: random)
     /// * `--level` - Blind level (1-20, higher means bigger blinds; levels 21+ treated as level 20)
+    /// * `--output` - Path to save completed hands (JSONL format)
+    /// * `--variant` - Game variant: `holdem` (default), `omaha` (pot-limit, four hole cards)
+    ///   or `short_deck` (36 cards, Six to Ace)
     ///
     /// # Example
     ///
     /// ```bash
-    /// axiomind play --vs ai --hands 10 --seed 42 --level 2
+    /// axiomind play --vs ai --hands 10 --seed 42 --level 2 --output data/play.jsonl
     /// ```
     Play {
         #[arg(long, value_enum)]
@@ -80,6 +83,10 @@ pub enum Commands {
         seed: Option<u64>,
         #[arg(long, value_parser = clap::value_parser!(u8).range(1..=20))]
         level: Option<u8>,
+        #[arg(long)]
+        output: Option<String>,
+        #[arg(long)]
+        variant: Option<String>,
     },
     /// Replay previously recorded hands from a JSONL file.
     ///
@@ -151,6 +158,37 @@ pub enum Commands {
         #[arg(long)]
         seed: Option<u64>,
     },
+    /// Calculate hand-versus-hand equity.
+    ///
+    /// Report win/tie/lose equity for two or more hands on an optional board.
+    /// Spots with few enough runouts are enumerated exactly; the rest use
+    /// seeded Monte Carlo sampling with a standard error.
+    ///
+    /// # Options
+    ///
+    /// * `<HANDS>...` - Two or more hands in card notation (e.g. `AhKh QsQd`)
+    /// * `--board` - Board cards (e.g. `Jh7h2c`)
+    /// * `--dead` - Dead cards removed from the deck
+    /// * `--samples` - Monte Carlo samples (default: 100000)
+    /// * `--seed` - Monte Carlo RNG seed (default: 0)
+    ///
+    /// # Example
+    ///
+    /// ```bash
+    /// axiomind equity AhKh QsQd --board Jh7h2c
+    /// ```
+    Equity {
+        #[arg(required = true, num_args = 2..)]
+        hands: Vec<String>,
+        #[arg(long)]
+        board: Option<String>,
+        #[arg(long)]
+        dead: Option<String>,
+        #[arg(long)]
+        samples: Option<u64>,
+        #[arg(long)]
+        seed: Option<u64>,
+    },
     /// Validate hand history integrity and game rules.
     ///
     /// Perform comprehensive validation checks on hand histories:
@@ -164,6 +202,8 @@ pub enum Commands {
     /// # Options
     ///
     /// * `--input` - Path to JSONL file to verify
+    /// * `--fairness` - Also check each hand's shuffle commitment: the revealed seed and
+    ///   deck must match it and every card must come from its place in that deck
     ///
     /// # Returns
     ///
@@ -173,10 +213,13 @@ pub enum Commands {
     ///
     /// ```bash
     /// axiomind verify --input data/hands/session.jsonl
+    /// axiomind verify --input data/hands/web.jsonl --fairness
     /// ```
     Verify {
         #[arg(long)]
         input: Option<String>,
+        #[arg(long)]
+        fairness: bool,
     },
     /// Deal a single hand for inspection.
     ///
@@ -186,19 +229,25 @@ pub enum Commands {
     /// # Options
     ///
     /// * `--seed` - RNG seed for reproducible deals (default: random)
+    /// * `--cards` - Cards to pin: each seat's hole cards, then the board
+    ///   after `|`, with `??` for a card left to the seed
     ///
     /// # Example
     ///
     /// ```bash
     /// axiomind deal --seed 12345
+    /// axiomind deal --cards "7h7d KcKs | Kd7c2s"
     /// ```
     Deal {
         #[arg(long)]
         seed: Option<u64>,
+        #[arg(long)]
+        cards: Option<String>,
     },
     /// Benchmark hand evaluation performance.
     ///
-    /// Evaluates 200 random 7-card hands and reports execution time.
+    /// Evaluates 200 random 7-card hands with the baseline and lookup-table
+    /// evaluators and reports execution time for each.
     /// Used for performance regression testing and optimization validation.
     ///
     /// # Example
@@ -216,9 +265,20 @@ pub enum Commands {
     ///
     /// * `--hands` - Total number of hands to simulate
     /// * `--output` - Path to save hand histories (JSONL format)
-    /// * `--seed` - Base RNG seed (each hand uses seed + hand_index)
+    /// * `--seed` - Root of the seed tree (default: random); hand `i` is shuffled from the
+    ///   seed of hand `i`, recorded in `meta.seed_derivation`
     /// * `--level` - Blind level (1-20, higher means bigger blinds; levels 21+ treated as level 20)
     /// * `--resume` - Resume from existing JSONL file (skips completed hands)
+    /// * `--betting` - Betting structure: `nl`, `pl` or `fl:SMALL/BIG[/CAP]`; defaults to
+    ///   `nl` for Hold'em and `pl` for Omaha
+    /// * `--variant` - Game variant: `holdem` (default), `omaha` (four hole cards) or
+    ///   `short_deck` (36 cards, Six to Ace)
+    /// * `--runouts` - Times to deal the rest of the board once all players are all-in
+    ///   (1-4, default 1)
+    /// * `--shuffle` - Shuffle source: `chacha20` (seeded, default), `os`, or the path of a
+    ///   recorded shuffle file with one deck order per line
+    /// * `--commit-reveal` - Commit to every deck before dealing and record the commitment
+    ///   and the revealed seed in `meta.fairness`
     ///
     /// # Environment Variables
     ///
@@ -242,6 +302,16 @@ pub enum Commands {
         level: Option<u8>,
         #[arg(long)]
         resume: Option<String>,
+        #[arg(long)]
+        betting: Option<String>,
+        #[arg(long)]
+        variant: Option<String>,
+        #[arg(long)]
+        runouts: Option<usize>,
+        #[arg(long)]
+        shuffle: Option<String>,
+        #[arg(long)]
+        commit_reveal: bool,
     },
     /// Convert hand histories to various formats.
     ///
@@ -2,18 +2,22 @@
 //!
 //! This module provides the `bench` command which performs a quick benchmark
 //! of the hand evaluation system by evaluating 200 unique 7-card hands from
-//! a shuffled deck and reporting the execution time.
+//! a shuffled deck with both the baseline and the lookup-table evaluator, and
+//! reporting the execution time of each.
 
 use crate::error::CliError;
 use axiomind_engine::cards::Card;
 use axiomind_engine::deck::Deck;
+use axiomind_engine::hand::{evaluate_hand, evaluate_hand_optimized};
 use std::io::Write;
 
 /// Handle the bench command.
 ///
 /// Performs a quick benchmark by evaluating 200 unique 7-card hands from
-/// a shuffled deck using deterministic seed 1 for reproducibility. Reports
-/// the number of iterations and total execution time.
+/// a shuffled deck using deterministic seed 1 for reproducibility. The same
+/// hands are timed with the baseline `evaluate_hand` and the lookup-table
+/// `evaluate_hand_optimized`. Reports the number of iterations, the total
+/// execution time and the time spent in each evaluator.
 ///
 /// # Arguments
 ///
@@ -33,8 +37,7 @@ use std::io::Write;
 /// ```
 pub fn handle_bench_command(out: &mut dyn Write) -> Result<(), CliError> {
     // quick bench: evaluate 200 unique 7-card draws from shuffled deck
-    let start = std::time::Instant::now();
-    let mut cnt = 0u64;
+    let mut hands: Vec<[Card; 7]> = Vec::with_capacity(200);
     let mut deck = Deck::new_with_seed(1);
     deck.shuffle();
     for _ in 0..200 {
@@ -45,12 +48,32 @@ pub fn handle_bench_command(out: &mut dyn Write) -> Result<(), CliError> {
         for item in arr.iter_mut().skip(1) {
             *item = deck.deal_card().unwrap();
         }
+        hands.push(arr);
+    }
+
+    // Build the lookup tables up front so they are not part of the timing
+    let _ = evaluate_hand_optimized(&hands[0]);
+
+    let start = std::time::Instant::now();
+    for arr in &hands {
         // Result intentionally unused - benchmark only measures performance
-        let _ = axiomind_engine::hand::evaluate_hand(&arr);
-        cnt += 1;
+        let _ = std::hint::black_box(evaluate_hand(arr));
     }
-    let dur = start.elapsed();
-    writeln!(out, "Benchmark: {} iters in {:?}", cnt, dur)?;
+    let baseline = start.elapsed();
+    let start = std::time::Instant::now();
+    for arr in &hands {
+        let _ = std::hint::black_box(evaluate_hand_optimized(arr));
+    }
+    let lookup = start.elapsed();
+
+    writeln!(
+        out,
+        "Benchmark: {} iters in {:?}",
+        hands.len(),
+        baseline + lookup
+    )?;
+    writeln!(out, "  baseline: {:?}", baseline)?;
+    writeln!(out, "  lookup:   {:?}", lookup)?;
     Ok(())
 }
 
@@ -133,4 +156,17 @@ mod tests {
             "Output should include timing units"
         );
     }
+
+    #[test]
+    fn test_bench_command_reports_each_evaluator() {
+        let mut out = Vec::new();
+        handle_bench_command(&mut out).unwrap();
+
+        let output = String::from_utf8(out).unwrap();
+        assert!(output.contains("baseline:"), "Output should time baseline");
+        assert!(
+            output.contains("lookup:"),
+            "Output should time lookup table"
+        );
+    }
 }
@@ -2,9 +2,11 @@
 //!
 //! This module provides the `deal` command which deals a single poker hand
 //! and displays the hole cards for both players and the complete board.
-//! The command supports optional seeding for deterministic dealing.
+//! The command supports optional seeding for deterministic dealing, and
+//! pinning specific hole and board cards to reproduce a reported spot.
 
 use crate::error::CliError;
+use axiomind_engine::deck::DealSpec;
 use axiomind_engine::engine::Engine;
 use std::io::Write;
 
@@ -17,11 +19,14 @@ use std::io::Write;
 /// # Arguments
 ///
 /// * `seed` - Optional RNG seed for deterministic dealing
+/// * `cards` - Optional [`DealSpec`] such as `"7h7d KcKs | Kd7c2s"`; cards it
+///   leaves open are dealt from the seed
 /// * `out` - Output stream for command results
 ///
 /// # Returns
 ///
-/// Returns `Ok(())` on success, or `CliError` on I/O errors.
+/// Returns `Ok(())` on success, `CliError::InvalidInput` for a malformed card
+/// specification, or `CliError` on I/O errors.
 ///
 /// # Examples
 ///
@@ -29,11 +34,24 @@ use std::io::Write;
 /// // Internal command handler - not part of public API
 /// use axiomind_cli::commands::deal::handle_deal_command;
 /// let mut out = Vec::new();
-/// handle_deal_command(Some(42), &mut out).unwrap();
+/// handle_deal_command(Some(42), Some("AhAd ????"), &mut out).unwrap();
 /// ```
-pub fn handle_deal_command(seed: Option<u64>, out: &mut dyn Write) -> Result<(), CliError> {
+pub fn handle_deal_command(
+    seed: Option<u64>,
+    cards: Option<&str>,
+    out: &mut dyn Write,
+) -> Result<(), CliError> {
     let base_seed = seed.unwrap_or_else(rand::random);
     let mut eng = Engine::new(Some(base_seed), 1);
+    if let Some(cards) = cards {
+        let deck = cards
+            .parse::<DealSpec>()
+            .and_then(|spec| spec.deck(base_seed, eng.players().len(), eng.variant()))
+            .map_err(|e| CliError::InvalidInput(e.to_string()))?;
+        eng = eng
+            .with_deck(deck)
+            .map_err(|e| CliError::InvalidInput(e.to_string()))?;
+    }
     eng.shuffle();
     // Return value intentionally unused - engine state is what matters
     eng.deal_hand()?;
@@ -50,7 +68,10 @@ pub fn handle_deal_command(seed: Option<u64>, out: &mut dyn Write) -> Result<(),
 
     writeln!(out, "Hole P1: {} {}", fmt(card1_p1), fmt(card2_p1))?;
     writeln!(out, "Hole P2: {} {}", fmt(card1_p2), fmt(card2_p2))?;
-    let b = eng.board();
+    // The engine reveals the board street by street; run it out to show the full deal
+    let b = eng
+        .run_out_board()
+        .map_err(|e| CliError::Engine(e.to_string()))?;
     writeln!(
         out,
         "Board: {} {} {} {} {}",
@@ -71,7 +92,7 @@ mod tests {
     fn test_deal_command_with_seed() {
         // Test that deal command produces deterministic output with a seed
         let mut out = Vec::new();
-        let result = handle_deal_command(Some(42), &mut out);
+        let result = handle_deal_command(Some(42), None, &mut out);
 
         assert!(result.is_ok(), "Deal command should succeed");
 
@@ -96,8 +117,8 @@ mod tests {
         let mut out1 = Vec::new();
         let mut out2 = Vec::new();
 
-        handle_deal_command(Some(12345), &mut out1).unwrap();
-        handle_deal_command(Some(12345), &mut out2).unwrap();
+        handle_deal_command(Some(12345), None, &mut out1).unwrap();
+        handle_deal_command(Some(12345), None, &mut out2).unwrap();
 
         assert_eq!(out1, out2, "Same seed should produce identical output");
     }
@@ -106,7 +127,7 @@ mod tests {
     fn test_deal_command_without_seed() {
         // Test that deal command works without explicit seed
         let mut out = Vec::new();
-        let result = handle_deal_command(None, &mut out);
+        let result = handle_deal_command(None, None, &mut out);
 
         assert!(result.is_ok(), "Deal command should succeed without seed");
 
@@ -125,11 +146,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_deal_command_with_pinned_cards() {
+        let mut out = Vec::new();
+        handle_deal_command(Some(7), Some("7h7d KcKs | Kd7c2s"), &mut out).unwrap();
+
+        let output = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines[0], "Hole P1: SevenHearts SevenDiamonds");
+        assert_eq!(lines[1], "Hole P2: KingClubs KingSpades");
+        assert!(lines[2].starts_with("Board: KingDiamonds SevenClubs TwoSpades "));
+    }
+
+    #[test]
+    fn test_deal_command_rejects_bad_cards() {
+        for cards in ["AhAh", "Zz??", "AhKd | 2c3c4c5c6c7c"] {
+            let mut out = Vec::new();
+            let result = handle_deal_command(Some(1), Some(cards), &mut out);
+            assert!(matches!(result, Err(CliError::InvalidInput(_))), "{cards}");
+        }
+    }
+
     #[test]
     fn test_deal_command_output_format() {
         // Test that output contains exactly 3 lines (P1, P2, Board)
         let mut out = Vec::new();
-        handle_deal_command(Some(999), &mut out).unwrap();
+        handle_deal_command(Some(999), None, &mut out).unwrap();
 
         let output = String::from_utf8(out).unwrap();
         let lines: Vec<&str> = output.lines().collect();
@@ -0,0 +1,162 @@
+//! Equity command handler for hand-versus-hand equity calculation.
+//!
+//! This module provides the `equity` command which reports win/tie/lose
+//! equity for two or more known hands on an optional partial board, with
+//! optional dead cards. Small spots are enumerated exactly; larger ones use
+//! seeded Monte Carlo sampling and report a standard error.
+
+use crate::error::CliError;
+use crate::formatters::format_board;
+use axiomind_engine::cards::{Card, format_cards, parse_cards};
+use axiomind_engine::equity::EquityCalculator;
+use std::io::Write;
+
+/// Handle the equity command.
+///
+/// # Arguments
+///
+/// * `hands` - Two or more hands in card notation, e.g. `["AhKh", "QsQd"]`
+/// * `board` - Optional board cards, e.g. `"Jh7h2c"`
+/// * `dead` - Optional dead cards removed from the deck
+/// * `samples` - Monte Carlo samples when the spot is too large to enumerate
+/// * `seed` - Monte Carlo RNG seed
+/// * `out` - Output stream for the equity table
+///
+/// # Returns
+///
+/// Returns `Ok(())` on success, or `CliError::InvalidInput` for malformed
+/// cards or an impossible query.
+///
+/// # Examples
+///
+/// ```ignore
+/// // Internal command handler - not part of public API
+/// use axiomind_cli::commands::handle_equity_command;
+/// let mut out = Vec::new();
+/// let hands = vec!["AhKh".to_string(), "QsQd".to_string()];
+/// handle_equity_command(&hands, Some("Jh7h2c"), None, None, None, &mut out).unwrap();
+/// ```
+pub fn handle_equity_command(
+    hands: &[String],
+    board: Option<&str>,
+    dead: Option<&str>,
+    samples: Option<u64>,
+    seed: Option<u64>,
+    out: &mut dyn Write,
+) -> Result<(), CliError> {
+    let hole_cards = hands
+        .iter()
+        .map(|h| parse_hand(h))
+        .collect::<Result<Vec<_>, _>>()?;
+    let board = parse_optional(board)?;
+    let dead = parse_optional(dead)?;
+
+    let mut calculator = EquityCalculator::new(hole_cards.clone())
+        .with_board(board.clone())
+        .with_dead_cards(dead);
+    if let Some(samples) = samples {
+        calculator = calculator.with_samples(samples);
+    }
+    if let Some(seed) = seed {
+        calculator = calculator.with_seed(seed);
+    }
+    let result = calculator
+        .calculate()
+        .map_err(|e| CliError::InvalidInput(e.to_string()))?;
+
+    writeln!(out, "Board: {}", format_board(&board))?;
+    if result.exact {
+        writeln!(out, "Exact: {} runouts", result.runouts)?;
+    } else {
+        writeln!(
+            out,
+            "Monte Carlo: {} samples (seed {})",
+            result.runouts,
+            seed.unwrap_or(0)
+        )?;
+    }
+    writeln!(
+        out,
+        "{:<6} {:>8} {:>8} {:>8}",
+        "Hand", "Equity", "Win", "Tie"
+    )?;
+    for (hole, eq) in hole_cards.iter().zip(&result.hands) {
+        write!(
+            out,
+            "{:<6} {:>7.2}% {:>7.2}% {:>7.2}%",
+            format_cards(hole),
+            eq.equity * 100.0,
+            eq.win * 100.0,
+            eq.tie * 100.0
+        )?;
+        if result.exact {
+            writeln!(out)?;
+        } else {
+            writeln!(out, "  ±{:.2}%", eq.std_error * 100.0)?;
+        }
+    }
+    Ok(())
+}
+
+/// Module-private helper: Parse exactly two hole cards.
+fn parse_hand(s: &str) -> Result<[Card; 2], CliError> {
+    let cards = parse_cards(s).map_err(|e| CliError::InvalidInput(e.to_string()))?;
+    cards
+        .try_into()
+        .map_err(|_| CliError::InvalidInput(format!("Hand '{}' must be exactly 2 cards", s)))
+}
+
+/// Module-private helper: Parse an optional card list, empty when absent.
+fn parse_optional(s: Option<&str>) -> Result<Vec<Card>, CliError> {
+    s.map(parse_cards)
+        .transpose()
+        .map(Option::unwrap_or_default)
+        .map_err(|e| CliError::InvalidInput(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(hands: &[&str], board: Option<&str>, dead: Option<&str>) -> Result<String, CliError> {
+        let hands: Vec<String> = hands.iter().map(|h| h.to_string()).collect();
+        let mut out = Vec::new();
+        handle_equity_command(&hands, board, dead, Some(2_000), Some(1), &mut out)?;
+        Ok(String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn test_equity_on_flop_is_exact() {
+        let output = run(&["AhKh", "QsQd"], Some("Jh7h2c"), None).unwrap();
+        assert!(output.contains("Exact: 990 runouts"));
+        assert!(output.contains("AhKh"));
+        assert!(output.contains("QsQd"));
+    }
+
+    #[test]
+    fn test_equity_preflop_samples() {
+        let output = run(&["AhKh", "QsQd"], None, None).unwrap();
+        assert!(output.contains("Monte Carlo: 2000 samples (seed 1)"));
+        assert!(output.contains('±'));
+    }
+
+    #[test]
+    fn test_equity_rejects_bad_input() {
+        assert!(matches!(
+            run(&["AhKh"], None, None),
+            Err(CliError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            run(&["AhKhQc", "QsQd"], None, None),
+            Err(CliError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            run(&["AhKh", "AhQd"], None, None),
+            Err(CliError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            run(&["AhKh", "QsQd"], Some("Jh7h2c"), Some("Jh")),
+            Err(CliError::InvalidInput(_))
+        ));
+    }
+}
@@ -5,10 +5,9 @@
 
 use crate::error::CliError;
 use axiomind_ai::create_ai;
-use axiomind_engine::cards::Card;
 use axiomind_engine::engine::Engine;
-use axiomind_engine::hand::{compare_hands, evaluate_hand};
 use axiomind_engine::logger::ActionRecord;
+use axiomind_engine::seed::{SeedPath, SeedTree};
 use std::io::Write;
 
 /// Statistics tracked for AI evaluation comparison
@@ -117,7 +116,7 @@ impl EvalStats {
 /// * `ai_a` - First AI policy identifier
 /// * `ai_b` - Second AI policy identifier
 /// * `hands` - Number of hands to play
-/// * `seed` - Optional seed for reproducibility
+/// * `seed` - Optional root seed; each hand is shuffled from its own seed in the tree
 /// * `out` - Output stream for evaluation results
 ///
 /// # Returns
@@ -149,22 +148,23 @@ pub fn handle_eval_command(
     let mut stats_a = EvalStats::new();
     let mut stats_b = EvalStats::new();
 
-    // Determine base seed
+    // Every hand is dealt from its own seed in the tree rooted at the seed
     let base_seed = seed.unwrap_or_else(rand::random);
+    let seeds = SeedTree::new(base_seed);
 
     // Play N hands
     for hand_num in 0..hands {
-        // Create unique seed for this hand
-        let hand_seed = base_seed.wrapping_add(hand_num as u64);
+        // Odd hands swap the AIs' seats, which the seed path records
+        let mut path = SeedPath::hand(hand_num as u64);
+        path.seat_swap = hand_num % 2 == 1;
 
         // Create and setup engine
-        let mut engine = Engine::new(Some(hand_seed), 1);
+        let mut engine = Engine::new(None, 1)
+            .with_seed_path(seeds, path)
+            .expect("fresh engines shuffle from the seed");
         engine.shuffle();
         let _ = engine.deal_hand();
 
-        // Record initial stacks
-        let initial_stacks = [engine.players()[0].stack(), engine.players()[1].stack()];
-
         // Assign AIs to positions (alternate button for fairness)
         let (ai_0, ai_1, ai_a_position) = if hand_num % 2 == 0 {
             (ai_policy_a.as_ref(), ai_policy_b.as_ref(), 0)
@@ -173,45 +173,18 @@ pub fn handle_eval_command(
         };
 
         // Play hand to completion
-        let (actions, _result_string, showdown, pot) =
-            play_hand_with_two_ais(&mut engine, ai_0, ai_1);
-
-        // Determine winner(s)
-        let (winner_ids, tied) = if let Some(showdown_data) = showdown {
-            if let Some(winners) = showdown_data.get("winners") {
-                if let Some(winners_array) = winners.as_array() {
-                    let winner_vec: Vec<usize> = winners_array
-                        .iter()
-                        .filter_map(|v| v.as_u64().map(|n| n as usize))
-                        .collect();
-                    let is_tie = winner_vec.len() > 1;
-                    (winner_vec, is_tie)
-                } else {
-                    (vec![], false)
-                }
-            } else {
-                (vec![], false)
-            }
-        } else if _result_string.contains("Player 0 wins") {
-            (vec![0], false)
-        } else if _result_string.contains("Player 1 wins") {
-            (vec![1], false)
-        } else {
-            (vec![], false)
-        };
+        let (actions, pot) = play_hand_with_two_ais(&mut engine, ai_0, ai_1);
 
-        // Calculate chip deltas
-        let final_stacks = [engine.players()[0].stack(), engine.players()[1].stack()];
-
-        let delta_0 = final_stacks[0] as i64 - initial_stacks[0] as i64;
-        let delta_1 = final_stacks[1] as i64 - initial_stacks[1] as i64;
+        // Winners and chip deltas come straight from the engine's settlement
+        let (winner_ids, net) = engine
+            .hand_outcome()
+            .map(|outcome| (outcome.winners.clone(), outcome.net.clone()))
+            .unwrap_or_else(|| (vec![], vec![0, 0]));
+        let tied = winner_ids.len() > 1;
 
         // Update statistics based on AI-A's position
-        let (ai_a_won, ai_a_delta) = if ai_a_position == 0 {
-            (winner_ids.contains(&0), delta_0)
-        } else {
-            (winner_ids.contains(&1), delta_1)
-        };
+        let ai_a_won = winner_ids.contains(&ai_a_position);
+        let ai_a_delta = net[ai_a_position];
 
         let ai_b_won = !tied && !ai_a_won;
         let ai_b_delta = -ai_a_delta;
@@ -236,13 +209,16 @@ fn play_hand_with_two_ais(
     engine: &mut Engine,
     ai_0: &dyn axiomind_ai::AIOpponent,
     ai_1: &dyn axiomind_ai::AIOpponent,
-) -> (Vec<ActionRecord>, String, Option<serde_json::Value>, u32) {
+) -> (Vec<ActionRecord>, u32) {
     // Play through the hand
     while let Ok(current_player) = engine.current_player() {
+        let Some(observation) = engine.observation(current_player) else {
+            break;
+        };
         let action = if current_player == 0 {
-            ai_0.get_action(engine, current_player)
+            ai_0.get_action(&observation)
         } else {
-            ai_1.get_action(engine, current_player)
+            ai_1.get_action(&observation)
         };
 
         match engine.apply_action(current_player, action) {
@@ -256,62 +232,7 @@ fn play_hand_with_two_ais(
     let actions = engine.action_history();
     let pot = engine.pot();
 
-    // Determine winner
-    let (result_string, showdown) = if let Some(folded) = engine.folded_player() {
-        let winner = 1 - folded;
-        (format!("Player {} wins {} (fold)", winner, pot), None)
-    } else if engine.reached_showdown() {
-        let players = engine.players();
-        let board = engine.community_cards();
-
-        let mut player0_cards = Vec::new();
-        let mut player1_cards = Vec::new();
-
-        if let [Some(c1), Some(c2)] = players[0].hole_cards() {
-            player0_cards.push(c1);
-            player0_cards.push(c2);
-        }
-        if let [Some(c1), Some(c2)] = players[1].hole_cards() {
-            player1_cards.push(c1);
-            player1_cards.push(c2);
-        }
-
-        player0_cards.extend_from_slice(&board);
-        player1_cards.extend_from_slice(&board);
-
-        if player0_cards.len() == 7 && player1_cards.len() == 7 {
-            let hand0: [Card; 7] = player0_cards.try_into().unwrap();
-            let hand1: [Card; 7] = player1_cards.try_into().unwrap();
-
-            let strength0 = evaluate_hand(&hand0);
-            let strength1 = evaluate_hand(&hand1);
-
-            let comparison = compare_hands(&strength0, &strength1);
-
-            use std::cmp::Ordering;
-            let (result_str, showdown_info) = match comparison {
-                Ordering::Greater => (
-                    format!("Player 0 wins {} (showdown)", pot),
-                    Some(serde_json::json!({"winners": [0]})),
-                ),
-                Ordering::Less => (
-                    format!("Player 1 wins {} (showdown)", pot),
-                    Some(serde_json::json!({"winners": [1]})),
-                ),
-                Ordering::Equal => (
-                    format!("Split pot {} (tie)", pot),
-                    Some(serde_json::json!({"winners": [0, 1]})),
-                ),
-            };
-            (result_str, showdown_info)
-        } else {
-            ("Unknown result".to_string(), None)
-        }
-    } else {
-        ("Hand incomplete".to_string(), None)
-    };
-
-    (actions, result_string, showdown, pot)
+    (actions, pot)
 }
 
 /// Print evaluation results comparing two AIs
@@ -6,6 +6,8 @@
 use crate::error::CliError;
 use crate::io_utils::read_text_auto;
 use crate::ui;
+use axiomind_engine::engine::EndReason;
+use axiomind_engine::logger::HandRecord;
 use std::io::Write;
 
 /// Handles the export command to convert hand histories between formats.
@@ -63,9 +65,12 @@ fn export_csv(content: &str, output: &str, err: &mut dyn Write) -> Result<(), Cl
             let _ = ui::write_error(err, &format!("Failed to write {}: {}", output, e));
             CliError::Io(e)
         })?;
-    writeln!(w, "hand_id,seed,result,ts,actions,board")?;
+    writeln!(
+        w,
+        "hand_id,seed,result,ts,actions,board,level,sb,bb,end_reason"
+    )?;
     for (idx, line) in content.lines().filter(|l| !l.trim().is_empty()).enumerate() {
-        let rec: axiomind_engine::logger::HandRecord = match serde_json::from_str(line) {
+        let rec: HandRecord = match serde_json::from_str(line) {
             Ok(r) => r,
             Err(e) => {
                 ui::write_error(err, &format!("Invalid record at line {}: {}", idx + 1, e))?;
@@ -79,25 +84,40 @@ fn export_csv(content: &str, output: &str, err: &mut dyn Write) -> Result<(), Cl
         let seed_str = rec.seed.map(|v| v.to_string()).unwrap_or_else(|| "".into());
         let result = rec.result.unwrap_or_default();
         let ts = rec.ts.unwrap_or_default();
+        let opt = |v: Option<u32>| v.map(|v| v.to_string()).unwrap_or_default();
         writeln!(
             w,
-            "{},{},{},{},{},{}",
+            "{},{},{},{},{},{},{},{},{},{}",
             rec.hand_id,
             seed_str,
             result,
             ts,
             rec.actions.len(),
-            rec.board.len()
+            rec.board.len(),
+            opt(rec.level.map(u32::from)),
+            opt(rec.sb),
+            opt(rec.bb),
+            end_reason_str(rec.end_reason)
         )?;
     }
     Ok(())
 }
 
-/// Export to JSON array format
+/// Text form of an end reason for tabular exports; empty when unknown.
+fn end_reason_str(reason: Option<EndReason>) -> &'static str {
+    match reason {
+        Some(EndReason::Fold) => "fold",
+        Some(EndReason::Showdown) => "showdown",
+        None => "",
+    }
+}
+
+/// Export to JSON array format; older records are written out upgraded to the
+/// current schema.
 fn export_json(content: &str, output: &str, err: &mut dyn Write) -> Result<(), CliError> {
     let mut arr = Vec::new();
     for (idx, line) in content.lines().filter(|l| !l.trim().is_empty()).enumerate() {
-        let v: serde_json::Value = match serde_json::from_str(line) {
+        let v: HandRecord = match serde_json::from_str(line) {
             Ok(v) => v,
             Err(e) => {
                 ui::write_error(err, &format!("Invalid record at line {}: {}", idx + 1, e))?;
@@ -195,6 +215,11 @@ fn export_sqlite(content: &str, output: &str, err: &mut dyn Write) -> Result<(),
                 ts TEXT,
                 actions INTEGER NOT NULL,
                 board INTEGER NOT NULL,
+                schema_version INTEGER NOT NULL,
+                level INTEGER,
+                sb INTEGER,
+                bb INTEGER,
+                end_reason TEXT,
                 raw_json TEXT NOT NULL
             )",
             [],
@@ -209,8 +234,9 @@ fn export_sqlite(content: &str, output: &str, err: &mut dyn Write) -> Result<(),
 
         let mut stmt = tx
             .prepare(
-                "INSERT INTO hands (hand_id, seed, result, ts, actions, board, raw_json)
-         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                "INSERT INTO hands (hand_id, seed, result, ts, actions, board,
+                    schema_version, level, sb, bb, end_reason, raw_json)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)",
             )
             .map_err(|e| {
                 if sqlite_busy(&e) {
@@ -226,16 +252,25 @@ fn export_sqlite(content: &str, output: &str, err: &mut dyn Write) -> Result<(),
                 continue;
             }
 
-            let record: axiomind_engine::logger::HandRecord = serde_json::from_str(raw)
+            let record: HandRecord = serde_json::from_str(raw)
                 .map_err(|e| ExportAttemptError::Fatal(format!("Invalid record: {}", e)))?;
+            // Store the record upgraded to the current schema
+            let raw_json = serde_json::to_string(&record).map_err(|e| {
+                ExportAttemptError::Fatal(format!("Failed to serialize record: {}", e))
+            })?;
 
-            let axiomind_engine::logger::HandRecord {
+            let HandRecord {
                 hand_id,
                 seed,
                 actions,
                 board,
                 result,
                 ts,
+                schema_version,
+                level,
+                sb,
+                bb,
+                end_reason,
                 ..
             } = record;
 
@@ -261,7 +296,12 @@ fn export_sqlite(content: &str, output: &str, err: &mut dyn Write) -> Result<(),
                 &ts_val,
                 actions_count,
                 board_count,
-                raw
+                schema_version,
+                level,
+                sb,
+                bb,
+                end_reason_str(end_reason),
+                raw_json
             ])
             .map_err(|e| {
                 if sqlite_busy(&e) {
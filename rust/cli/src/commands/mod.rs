@@ -12,7 +12,7 @@
 //!
 //! Commands are organized by complexity:
 //! - **Simple commands** (Phase 2): cfg, doctor, rng, deal, bench
-//! - **Moderate commands** (Phase 3): play, stats, eval, export
+//! - **Moderate commands** (Phase 3): play, stats, eval, equity, export
 //! - **Complex commands** (Phase 4): replay, verify, sim, dataset
 //!
 //! # Example
@@ -41,11 +41,13 @@ pub use doctor::handle_doctor_command;
 pub use rng::handle_rng_command;
 
 // Phase 3: Moderate command modules
+mod equity;
 mod eval;
 mod export;
 mod play;
 mod stats;
 
+pub use equity::handle_equity_command;
 pub use eval::handle_eval_command;
 pub use export::handle_export_command;
 pub use play::handle_play_command;
@@ -18,12 +18,15 @@
 
 use crate::cli::Vs;
 use crate::error::CliError;
-use crate::formatters::format_action;
+use crate::formatters::{format_action, format_board, format_legal_actions};
 use crate::io_utils::read_stdin_line;
 use crate::ui;
 use crate::validation::{ParseResult, parse_player_action};
-use axiomind_ai::create_ai;
-use axiomind_engine::engine::Engine;
+use axiomind_ai::{AIOpponent, create_ai};
+use axiomind_engine::events::{EngineEvent, EventQueue};
+use axiomind_engine::game::Match;
+use axiomind_engine::logger::HandLogger;
+use axiomind_engine::variant::GameVariant;
 use std::io::{BufRead, Write};
 
 /// Handle the play command: interactive poker gameplay
@@ -32,8 +35,10 @@ use std::io::{BufRead, Write};
 ///
 /// * `vs` - Opponent type (AI or Human)
 /// * `hands` - Number of hands to play (must be >= 1, default: 1)
-/// * `seed` - RNG seed for reproducibility (default: random)
+/// * `seed` - Root seed of the session's seed tree, for reproducibility (default: random)
 /// * `level` - Blind level (1-20, default: 1)
+/// * `output` - Optional path to save completed hands (JSONL format)
+/// * `variant` - Game variant (`holdem`, `omaha` or `short_deck`, default: holdem); Omaha is pot-limit
 /// * `out` - Output stream for game display
 /// * `err` - Error stream for warnings and errors
 /// * `stdin` - Input stream for player actions
@@ -54,32 +59,47 @@ use std::io::{BufRead, Write};
 /// let mut err = stderr();
 /// let mut input = stdin().lock();
 ///
-/// handle_play_command(Vs::Ai, Some(1), None, None, &mut out, &mut err, &mut input).unwrap();
+/// handle_play_command(Vs::Ai, Some(1), None, None, None, None, &mut out, &mut err, &mut input)
+///     .unwrap();
 /// ```
+#[allow(clippy::too_many_arguments)]
 pub fn handle_play_command(
     vs: Vs,
     hands: Option<u32>,
     seed: Option<u64>,
     level: Option<u8>,
+    output: Option<String>,
+    variant: Option<String>,
     out: &mut dyn Write,
     err: &mut dyn Write,
     stdin: &mut dyn BufRead,
 ) -> Result<(), CliError> {
     let hands = hands.unwrap_or(1);
     let level = level.unwrap_or(1).clamp(1, 20);
+    let variant = match variant.as_deref().map(str::parse::<GameVariant>) {
+        None => GameVariant::default(),
+        Some(Ok(variant)) => variant,
+        Some(Err(e)) => {
+            ui::write_error(err, &e.to_string())?;
+            return Err(CliError::InvalidInput(e.to_string()));
+        }
+    };
 
-    execute_play_command(vs, hands, seed, level, stdin, out, err)
+    execute_play_command(vs, hands, seed, level, output, variant, stdin, out, err)
 }
 
 /// Execute the play command with specified parameters (module-private helper)
 ///
 /// This is the core implementation that handles game loop, player interaction,
 /// and AI opponent moves.
+#[allow(clippy::too_many_arguments)]
 fn execute_play_command(
     vs: Vs,
     hands: u32,
     seed: Option<u64>,
     level: u8,
+    output: Option<String>,
+    variant: GameVariant,
     stdin: &mut dyn BufRead,
     out: &mut dyn Write,
     err: &mut dyn Write,
@@ -92,13 +112,6 @@ fn execute_play_command(
     let seed = seed.unwrap_or_else(rand::random);
     let level = level.clamp(1, 20);
 
-    if matches!(vs, Vs::Ai) {
-        ui::display_warning(
-            err,
-            "AI opponent is a placeholder that always checks. Use for demo purposes only.",
-        )?;
-    }
-
     writeln!(
         out,
         "play: vs={} hands={} seed={}",
@@ -108,29 +121,40 @@ fn execute_play_command(
     )?;
     writeln!(out, "Level: {}", level)?;
 
-    let mut eng = Engine::new(Some(seed), level);
-    eng.shuffle();
+    let mut logger = match output.as_deref().map(HandLogger::create).transpose() {
+        Ok(logger) => logger,
+        Err(e) => {
+            ui::write_error(err, &format!("Failed to open output file: {}", e))?;
+            return Err(CliError::Io(e));
+        }
+    };
+
+    let mut game = Match::new(Some(seed), level).with_variant(variant);
+    let events = EventQueue::new();
+    game.add_observer(events.clone());
 
-    // Create AI opponent for human vs AI mode
+    // Baseline AI for the AI seats of either mode
     let ai = create_ai("baseline");
 
     let mut played = 0u32;
     let mut quit_requested = false;
 
     for i in 1..=hands {
-        if quit_requested {
+        if quit_requested || game.is_over() {
             break;
         }
 
-        // level progression: +1 every 15 hands
-        let cur_level: u8 = level
-            .saturating_add(((i - 1) / axiomind_engine::player::HANDS_PER_LEVEL) as u8)
-            .clamp(1, 20);
+        // The match raises the level every HANDS_PER_LEVEL hands
         if i > 1 {
-            writeln!(out, "Level: {}", cur_level)?;
+            writeln!(out, "Level: {}", game.level())?;
+        }
+        if let Err(e) = game.start_hand() {
+            ui::write_error(err, &format!("Failed to deal hand: {}", e))?;
+            return Err(CliError::Engine(format!("Failed to deal hand: {}", e)));
         }
-        eng.set_level(cur_level);
-        let (sb, bb) = match eng.blinds() {
+        // The table display starts with the first action
+        events.drain();
+        let (sb, bb) = match game.engine().blinds() {
             Ok(blinds) => blinds,
             Err(e) => {
                 ui::write_error(err, &format!("Failed to get blinds: {}", e))?;
@@ -139,18 +163,18 @@ fn execute_play_command(
         };
         writeln!(out, "Blinds: SB={} BB={}", sb, bb)?;
         writeln!(out, "Hand {}", i)?;
-        if let Err(e) = eng.deal_hand() {
-            ui::write_error(err, &format!("Failed to deal hand: {}", e))?;
-            return Err(CliError::Engine(format!("Failed to deal hand: {}", e)));
-        }
 
         match vs {
             Vs::Human => {
                 let human_player_id = 0;
 
                 loop {
+                    // Blinds that put everyone all-in end the hand before anyone acts
+                    if game.engine().is_hand_complete() {
+                        break;
+                    }
                     // Get current actor from engine
-                    let current_player = match eng.current_player() {
+                    let current_player = match game.engine().current_player() {
                         Ok(player) => player,
                         Err(e) => {
                             ui::write_error(err, &format!("Failed to get current player: {}", e))?;
@@ -163,19 +187,19 @@ fn execute_play_command(
 
                     if current_player == human_player_id {
                         // Human player's turn
-                        write!(out, "Enter action (check/call/bet/raise/fold/q): ")?;
+                        let legal = game.engine().legal_actions(human_player_id);
+                        write!(out, "Enter action ({}/q): ", format_legal_actions(&legal))?;
                         out.flush()?;
 
                         match read_stdin_line(stdin) {
                             Some(input) => match parse_player_action(&input) {
                                 ParseResult::Action(action) => {
-                                    match eng.apply_action(human_player_id, action.clone()) {
-                                        Ok(state) => {
+                                    match game.apply_action(human_player_id, action.clone()) {
+                                        Ok(outcome) => {
                                             let action_str = format_action(&action);
                                             writeln!(out, "Action: {}", action_str)?;
-                                            writeln!(out, "Pot: {}", state.pot())?;
-                                            if state.is_hand_complete() {
-                                                writeln!(out, "Hand complete.")?;
+                                            write_events(out, &events)?;
+                                            if outcome.is_some() {
                                                 break;
                                             }
                                         }
@@ -202,13 +226,16 @@ fn execute_play_command(
                         }
                     } else {
                         // AI turn - use AI to determine action
-                        let ai_action = ai.get_action(&eng, current_player);
-                        match eng.apply_action(current_player, ai_action.clone()) {
-                            Ok(state) => {
+                        let Some(observation) = game.engine().observation(current_player) else {
+                            ui::write_error(err, "AI seat is not at the table")?;
+                            break;
+                        };
+                        let ai_action = ai.get_action(&observation);
+                        match game.apply_action(current_player, ai_action.clone()) {
+                            Ok(outcome) => {
                                 writeln!(out, "AI: {}", format_action(&ai_action))?;
-                                writeln!(out, "Pot: {}", state.pot())?;
-                                if state.is_hand_complete() {
-                                    writeln!(out, "Hand complete.")?;
+                                write_events(out, &events)?;
+                                if outcome.is_some() {
                                     break;
                                 }
                             }
@@ -220,30 +247,82 @@ fn execute_play_command(
                     }
                 }
             }
-            Vs::Ai => {
-                // Existing AI mode placeholder
-                writeln!(out, "{}", ui::tag_demo_output("ai: check"))?;
+            Vs::Ai => play_hand_with_two_ais(&mut game, ai.as_ref(), &events, out, err)?,
+        }
+        if let Some(logger) = logger.as_mut()
+            && game.engine().is_hand_complete()
+        {
+            let record = game
+                .engine()
+                .hand_record(logger.next_id(), game.engine().hand_seed());
+            if let Err(e) = logger.write(&record) {
+                ui::write_error(err, &format!("Failed to write hand: {}", e))?;
+                return Err(CliError::Io(e));
             }
         }
         played += 1;
     }
 
+    if let Some(winner) = game.winner() {
+        writeln!(out, "Match over: {} wins", game.name(winner))?;
+    }
     writeln!(out, "Session hands={}", hands)?;
     writeln!(out, "Hands played: {} (completed)", played)?;
     Ok(())
 }
 
+/// Print what the engine reported since the last call (module-private helper)
+///
+/// Shows the pot after each action, the board whenever a street opens or is
+/// run again, and the end of the hand.
+fn write_events(out: &mut dyn Write, events: &EventQueue) -> std::io::Result<()> {
+    for event in events.drain() {
+        match event {
+            EngineEvent::ActionApplied { pot, .. } => writeln!(out, "Pot: {}", pot)?,
+            EngineEvent::StreetChanged { board, .. } => {
+                writeln!(out, "Board: {}", format_board(&board))?
+            }
+            EngineEvent::RunoutDealt { run, board } => {
+                writeln!(out, "Board (run {}): {}", run + 1, format_board(&board))?
+            }
+            EngineEvent::HandCompleted(_) => writeln!(out, "Hand complete.")?,
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
 /// Play a hand with two AI players (module-private helper)
 ///
-/// Used for AI vs AI mode where both players make automated decisions.
-#[allow(dead_code)]
+/// Used for AI vs AI mode. Each seat asks `ai` for an action from its own
+/// observation of the hand, and every action is printed with its seat.
 fn play_hand_with_two_ais(
-    _engine: &mut Engine,
-    _out: &mut dyn Write,
-    _err: &mut dyn Write,
+    game: &mut Match,
+    ai: &dyn AIOpponent,
+    events: &EventQueue,
+    out: &mut dyn Write,
+    err: &mut dyn Write,
 ) -> Result<(), CliError> {
-    // This function is reserved for future AI vs AI gameplay implementation
-    // Currently not used in the command flow
+    while let Ok(current_player) = game.engine().current_player() {
+        let Some(observation) = game.engine().observation(current_player) else {
+            ui::write_error(err, "AI seat is not at the table")?;
+            return Err(CliError::Engine("AI seat is not at the table".to_string()));
+        };
+        let action = ai.get_action(&observation);
+        match game.apply_action(current_player, action.clone()) {
+            Ok(outcome) => {
+                writeln!(out, "AI {}: {}", current_player, format_action(&action))?;
+                write_events(out, events)?;
+                if outcome.is_some() {
+                    break;
+                }
+            }
+            Err(e) => {
+                ui::write_error(err, &format!("AI action failed: {}", e))?;
+                return Err(CliError::Engine(e.to_string()));
+            }
+        }
+    }
     Ok(())
 }
 
@@ -264,8 +343,17 @@ mod tests {
         let mut err = Vec::new();
         let mut input = Cursor::new(b"");
 
-        let result =
-            handle_play_command(Vs::Ai, Some(1), None, None, &mut out, &mut err, &mut input);
+        let result = handle_play_command(
+            Vs::Ai,
+            Some(1),
+            None,
+            None,
+            None,
+            None,
+            &mut out,
+            &mut err,
+            &mut input,
+        );
         assert!(result.is_ok(), "AI mode should succeed");
 
         let output = String::from_utf8(out).unwrap();
@@ -279,8 +367,17 @@ mod tests {
         let mut err = Vec::new();
         let mut input = Cursor::new(b"");
 
-        let result =
-            handle_play_command(Vs::Ai, Some(0), None, None, &mut out, &mut err, &mut input);
+        let result = handle_play_command(
+            Vs::Ai,
+            Some(0),
+            None,
+            None,
+            None,
+            None,
+            &mut out,
+            &mut err,
+            &mut input,
+        );
         assert!(result.is_err(), "Zero hands should fail");
         assert!(matches!(result, Err(CliError::InvalidInput(_))));
     }
@@ -291,7 +388,17 @@ mod tests {
         let mut err = Vec::new();
         let mut input = Cursor::new(b"");
 
-        let result = handle_play_command(Vs::Ai, None, None, None, &mut out, &mut err, &mut input);
+        let result = handle_play_command(
+            Vs::Ai,
+            None,
+            None,
+            None,
+            None,
+            None,
+            &mut out,
+            &mut err,
+            &mut input,
+        );
         assert!(result.is_ok(), "Default hands (1) should succeed");
 
         let output = String::from_utf8(out).unwrap();
@@ -310,6 +417,8 @@ mod tests {
             Some(1),
             None,
             None,
+            None,
+            None,
             &mut out,
             &mut err,
             &mut input,
@@ -326,8 +435,17 @@ mod tests {
         let mut err = Vec::new();
         let mut input = Cursor::new(b"");
 
-        let result =
-            handle_play_command(Vs::Ai, Some(1), None, None, &mut out, &mut err, &mut input);
+        let result = handle_play_command(
+            Vs::Ai,
+            Some(1),
+            None,
+            None,
+            None,
+            None,
+            &mut out,
+            &mut err,
+            &mut input,
+        );
         assert!(result.is_ok());
 
         let output = String::from_utf8(out).unwrap();
@@ -350,6 +468,8 @@ mod tests {
             Some(1),
             None,
             None,
+            None,
+            None,
             &mut out1,
             &mut err1,
             &mut input1,
@@ -359,6 +479,8 @@ mod tests {
             Some(1),
             None,
             None,
+            None,
+            None,
             &mut out2,
             &mut err2,
             &mut input2,
@@ -381,8 +503,17 @@ mod tests {
         let mut err = Vec::new();
         let mut input = Cursor::new(b"");
 
-        let result =
-            handle_play_command(Vs::Ai, Some(3), None, None, &mut out, &mut err, &mut input);
+        let result = handle_play_command(
+            Vs::Ai,
+            Some(3),
+            None,
+            None,
+            None,
+            None,
+            &mut out,
+            &mut err,
+            &mut input,
+        );
         assert!(result.is_ok(), "Multiple hands should succeed");
 
         let output = String::from_utf8(out).unwrap();
@@ -393,19 +524,29 @@ mod tests {
     }
 
     #[test]
-    fn test_handle_play_command_ai_warning() {
+    fn test_handle_play_command_ai_mode_has_no_placeholder_warning() {
         let mut out = Vec::new();
         let mut err = Vec::new();
         let mut input = Cursor::new(b"");
 
-        let result =
-            handle_play_command(Vs::Ai, Some(1), None, None, &mut out, &mut err, &mut input);
+        let result = handle_play_command(
+            Vs::Ai,
+            Some(1),
+            None,
+            None,
+            None,
+            None,
+            &mut out,
+            &mut err,
+            &mut input,
+        );
         assert!(result.is_ok());
 
         let errors = String::from_utf8(err).unwrap();
         assert!(
-            errors.contains("placeholder") || errors.contains("demo"),
-            "Should warn about AI placeholder"
+            !errors.contains("placeholder"),
+            "baseline AI plays both seats, got: {}",
+            errors
         );
     }
 
@@ -416,7 +557,17 @@ mod tests {
         let mut input = Cursor::new(b"");
 
         // Zero hands should fail
-        let result = execute_play_command(Vs::Ai, 0, None, 1, &mut input, &mut out, &mut err);
+        let result = execute_play_command(
+            Vs::Ai,
+            0,
+            None,
+            1,
+            None,
+            GameVariant::default(),
+            &mut input,
+            &mut out,
+            &mut err,
+        );
         assert!(result.is_err(), "Zero hands should return error");
     }
 
@@ -427,7 +578,17 @@ mod tests {
         let mut input = Cursor::new(b"");
 
         // Level should be clamped to 1-20 range
-        let result = execute_play_command(Vs::Ai, 1, Some(42), 100, &mut input, &mut out, &mut err);
+        let result = execute_play_command(
+            Vs::Ai,
+            1,
+            Some(42),
+            100,
+            None,
+            GameVariant::default(),
+            &mut input,
+            &mut out,
+            &mut err,
+        );
         assert!(result.is_ok(), "Level should be clamped");
 
         let output = String::from_utf8(out).unwrap();
@@ -439,12 +600,14 @@ mod tests {
         let mut out = Vec::new();
         let mut err = Vec::new();
 
-        let seed = Some(12345u64);
-        let mut engine = Engine::new(seed, 1);
-        engine.shuffle();
-        engine.deal_hand().unwrap();
+        let mut game = Match::new(Some(12345), 1);
+        let events = EventQueue::new();
+        game.add_observer(events.clone());
+        game.start_hand().unwrap();
+        let ai = create_ai("baseline");
 
-        let result = play_hand_with_two_ais(&mut engine, &mut out, &mut err);
+        let result = play_hand_with_two_ais(&mut game, ai.as_ref(), &events, &mut out, &mut err);
         assert!(result.is_ok(), "AI vs AI hand should succeed");
+        assert_eq!(game.hands_played(), 1, "hand should be played out");
     }
 }
@@ -13,7 +13,7 @@
 //! ## Format
 //!
 //! Replays hands from JSONL files containing `HandRecord` structures with:
-//! - Hand metadata (seed, level, button position)
+//! - Hand metadata (seed and its seed tree derivation, level, button position)
 //! - Action sequences with street information
 //! - Final results and showdown details
 
@@ -22,8 +22,10 @@ use crate::formatters::{format_action, format_board};
 use crate::io_utils::read_text_auto;
 use crate::ui;
 use crate::validation::validate_speed;
-use axiomind_engine::engine::blinds_for_level;
 use axiomind_engine::logger::{HandRecord, Street};
+use axiomind_engine::rules::{BlindLevel, BlindStructure};
+use axiomind_engine::seed::SeedDerivation;
+use axiomind_engine::variant::GameVariant;
 use std::io::Write;
 
 /// Handle the replay command.
@@ -95,36 +97,38 @@ pub fn handle_replay_command(
         };
         hands_shown += 1;
 
-        // Extract level from metadata
-        let level = if let Some(meta) = &record.meta {
-            if let Some(level_val) = meta.get("level") {
-                level_val.as_u64().unwrap_or(1) as u8
-            } else {
-                1
-            }
-        } else {
-            1
-        };
-
-        // Extract button position from metadata
-        let button_position = if let Some(meta) = &record.meta {
-            if let Some(button_val) = meta.get("button_position") {
-                button_val.as_u64().unwrap_or(0) as usize
-            } else {
-                0
-            }
-        } else {
-            0
-        };
-
-        // Get blinds for level
-        let (sb, bb) = match blinds_for_level(level) {
-            Ok(amounts) => amounts,
+        // Level and button are part of the record (upgraded from meta for v1 files)
+        let level = record.level.unwrap_or(1);
+        let button_position = record
+            .button
+            .as_deref()
+            .and_then(|id| id.strip_prefix('p'))
+            .and_then(|seat| seat.parse::<usize>().ok())
+            .filter(|seat| *seat < 2)
+            .unwrap_or(0);
+
+        // Get blinds for level from the recorded structure, or the default table
+        let structure = record
+            .meta
+            .as_ref()
+            .and_then(|meta| meta.get("blind_structure"))
+            .and_then(|v| serde_json::from_value::<BlindStructure>(v.clone()).ok())
+            .unwrap_or_default();
+        let blinds = match structure.level(level) {
+            Ok(blinds) => blinds,
             Err(e) => {
                 ui::write_error(err, &format!("Invalid blind level {}: {}", level, e))?;
-                (0, 0)
+                BlindLevel {
+                    sb: 0,
+                    bb: 0,
+                    ante: 0,
+                }
             }
         };
+        let (sb, bb) = (
+            record.sb.unwrap_or(blinds.sb),
+            record.bb.unwrap_or(blinds.bb),
+        );
 
         // Display hand header
         writeln!(
@@ -138,13 +142,51 @@ pub fn handle_replay_command(
             level
         )?;
         writeln!(out, "═══════════════════════════════════════")?;
+        if let Some(derivation) = record
+            .meta
+            .as_ref()
+            .and_then(|meta| meta.get("seed_derivation"))
+            .and_then(|v| serde_json::from_value::<SeedDerivation>(v.clone()).ok())
+        {
+            writeln!(
+                out,
+                "Seed tree: root {}, match {}, hand {}{} ({})",
+                derivation.root,
+                derivation.path.match_index,
+                derivation.path.hand,
+                if derivation.path.seat_swap {
+                    ", seats swapped"
+                } else {
+                    ""
+                },
+                derivation.scheme
+            )?;
+        }
         writeln!(out, "Blinds: SB={} BB={}", sb, bb)?;
+        if blinds.ante > 0 {
+            let payer = if structure.big_blind_ante {
+                " (big blind)"
+            } else {
+                ""
+            };
+            writeln!(out, "Ante: {}{}", blinds.ante, payer)?;
+        }
         writeln!(out, "Button: Player {}", button_position)?;
         writeln!(out)?;
 
         // Initialize stack and pot tracking
         const STARTING_STACK: u32 = 20000;
         let mut stacks = [STARTING_STACK, STARTING_STACK];
+        for (seat, player) in record.players.iter().take(2).enumerate() {
+            stacks[seat] = player.stack_start;
+        }
+        let hole_cards = |seat: usize| {
+            record
+                .players
+                .get(seat)
+                .and_then(|p| p.hole_cards.as_deref())
+                .map_or_else(|| "?? ??".to_string(), format_board)
+        };
         let mut pot: u32 = 0;
 
         // Track commit amount and current bet for each street
@@ -160,6 +202,17 @@ pub fn handle_replay_command(
         stacks[button_position] = stacks[button_position].saturating_sub(sb);
         stacks[other_player] = stacks[other_player].saturating_sub(bb);
         pot = pot.saturating_add(sb).saturating_add(bb);
+
+        // Antes are dead money: into the pot, but not part of the amount to call
+        let ante_payers = if structure.big_blind_ante {
+            vec![other_player]
+        } else {
+            vec![button_position, other_player]
+        };
+        for seat in ante_payers {
+            stacks[seat] = stacks[seat].saturating_sub(blinds.ante);
+            pot = pot.saturating_add(blinds.ante);
+        }
         current_bet = bb;
 
         // Process actions by street
@@ -203,22 +256,24 @@ pub fn handle_replay_command(
                         };
                         writeln!(
                             out,
-                            "  Player 0 {}: ?? ??  (Stack: {})",
+                            "  Player 0 {}: {}  (Stack: {})",
                             if button_position == 0 {
                                 btn_pos_label
                             } else {
                                 other_pos_label
                             },
+                            hole_cards(0),
                             stacks[0]
                         )?;
                         writeln!(
                             out,
-                            "  Player 1 {}: ?? ??  (Stack: {})",
+                            "  Player 1 {}: {}  (Stack: {})",
                             if button_position == 1 {
                                 btn_pos_label
                             } else {
                                 other_pos_label
                             },
+                            hole_cards(1),
                             stacks[1]
                         )?;
                         writeln!(out)?;
@@ -291,6 +346,11 @@ pub fn handle_replay_command(
                         }
                     }
 
+                    // v2 records carry the exact chips each action put in
+                    if let Some(amount) = action_rec.amount {
+                        delta = amount;
+                    }
+
                     if delta > 0 {
                         stacks[player_id] = stacks[player_id].saturating_sub(delta);
                         pot = pot.saturating_add(delta);
@@ -306,6 +366,30 @@ pub fn handle_replay_command(
         // Display showdown or result
         if let Some(showdown) = &record.showdown {
             writeln!(out, "Showdown:")?;
+            // A board run more than once shows every runout
+            for (run, board) in showdown.runouts.iter().enumerate() {
+                writeln!(out, "  Run {}: {}", run + 1, format_board(board))?;
+            }
+            for hand in &showdown.hands {
+                // Records written before descriptions existed are described here
+                let description = hand.hand.clone().or_else(|| {
+                    GameVariant::from_hole_cards(hand.cards.len())
+                        .unwrap_or_default()
+                        .best_hand(&hand.cards, &record.board)
+                        .ok()
+                        .map(|best| best.describe())
+                });
+                match description {
+                    Some(d) => writeln!(
+                        out,
+                        "  {} shows {} ({})",
+                        hand.player,
+                        format_board(&hand.cards),
+                        d
+                    )?,
+                    None => writeln!(out, "  {} shows {}", hand.player, format_board(&hand.cards))?,
+                }
+            }
             for winner in &showdown.winners {
                 writeln!(out, "  Player {} wins {} chips", winner, pot)?;
             }
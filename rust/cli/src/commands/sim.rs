@@ -26,10 +26,16 @@
 //! ```
 
 use crate::error::CliError;
-use crate::io_utils::ensure_parent_dir;
+use crate::io_utils::{ensure_parent_dir, read_text_auto};
 use crate::ui;
 use axiomind_ai::create_ai;
-use axiomind_engine::engine::Engine;
+use axiomind_engine::deck::ShuffleSource;
+use axiomind_engine::engine::{Engine, MAX_RUNOUTS};
+use axiomind_engine::errors::GameError;
+use axiomind_engine::logger::HandRecord;
+use axiomind_engine::rules::BettingStructure;
+use axiomind_engine::seed::{SeedPath, SeedTree};
+use axiomind_engine::variant::GameVariant;
 use std::io::Write;
 
 /// Handle the sim command: run large-scale hand simulations.
@@ -41,9 +47,16 @@ use std::io::Write;
 ///
 /// * `hands` - Total number of hands to simulate
 /// * `output` - Path to save hand histories (JSONL format)
-/// * `seed` - Base RNG seed (each hand uses seed + hand_index)
+/// * `seed` - Root of the seed tree; hand `i` is shuffled from the seed of hand `i`
 /// * `level` - Blind level (1-20)
 /// * `resume` - Resume from existing JSONL file (skips completed hands)
+/// * `betting` - Betting structure notation (`nl`, `pl`, `fl:SMALL/BIG[/CAP]`); if omitted,
+///   no-limit for Hold'em and pot-limit for Omaha
+/// * `variant` - Game variant (`holdem`, `omaha` or `short_deck`); Hold'em if omitted
+/// * `runouts` - Times the rest of the board is dealt once nobody can bet (1-4); once if omitted
+/// * `shuffle` - Shuffle source: `chacha20` (seeded, the default), `os`, or the path of a
+///   recorded shuffle file whose orders are dealt one per hand
+/// * `commit_reveal` - Commit to every deck and record the reveal in `meta.fairness`
 /// * `out` - Output stream for normal messages
 /// * `err` - Output stream for error messages
 ///
@@ -56,12 +69,18 @@ use std::io::Write;
 /// - `AXIOMIND_SIM_FAST`: Enable fast mode (batch writes, minimal output)
 /// - `AXIOMIND_SIM_BREAK_AFTER`: Break after N hands (for testing)
 /// - `AXIOMIND_SIM_SLEEP_MICROS`: Delay between hands in microseconds
+#[allow(clippy::too_many_arguments)]
 pub fn handle_sim_command(
     hands: u64,
     output: Option<String>,
     seed: Option<u64>,
     level: Option<u8>,
     resume: Option<String>,
+    betting: Option<String>,
+    variant: Option<String>,
+    runouts: Option<usize>,
+    shuffle: Option<String>,
+    commit_reveal: bool,
     out: &mut dyn Write,
     err: &mut dyn Write,
 ) -> Result<(), CliError> {
@@ -72,6 +91,36 @@ pub fn handle_sim_command(
     }
 
     let level = level.unwrap_or(1).clamp(1, 20);
+    let variant = match variant.as_deref().map(str::parse::<GameVariant>) {
+        None => GameVariant::default(),
+        Some(Ok(variant)) => variant,
+        Some(Err(e)) => {
+            ui::write_error(err, &e.to_string())?;
+            return Err(CliError::InvalidInput(e.to_string()));
+        }
+    };
+    let betting = match betting.as_deref().map(str::parse::<BettingStructure>) {
+        None => variant.default_betting_structure(),
+        Some(Ok(structure)) => structure,
+        Some(Err(e)) => {
+            ui::write_error(err, &e.to_string())?;
+            return Err(CliError::InvalidInput(e.to_string()));
+        }
+    };
+    let runouts = runouts.unwrap_or(1);
+    if !(1..=MAX_RUNOUTS).contains(&runouts) {
+        let msg = GameError::InvalidRunouts(runouts).to_string();
+        ui::write_error(err, &msg)?;
+        return Err(CliError::InvalidInput(msg));
+    }
+    let shuffle = match shuffle.as_deref().map(parse_shuffle_source) {
+        None | Some(Ok(ShuffleSource::ChaCha20(_))) => None,
+        Some(Ok(source)) => Some(source),
+        Some(Err(msg)) => {
+            ui::write_error(err, &msg)?;
+            return Err(CliError::InvalidInput(msg));
+        }
+    };
     let mut completed = 0usize;
     let mut path = None;
 
@@ -111,8 +160,8 @@ pub fn handle_sim_command(
         writeln!(out, "Resumed from {}", completed)?;
     }
 
-    let base_seed = seed.unwrap_or_else(rand::random);
-    let mut eng = Engine::new(Some(base_seed), level);
+    let seeds = SeedTree::new(seed.unwrap_or_else(rand::random));
+    let mut eng = Engine::new(Some(seeds.root()), level);
     eng.shuffle();
 
     let break_after = std::env::var("AXIOMIND_SIM_BREAK_AFTER")
@@ -139,8 +188,12 @@ pub fn handle_sim_command(
         return sim_run_fast(
             total,
             level,
-            seed,
-            base_seed,
+            variant,
+            betting,
+            runouts,
+            shuffle.as_ref(),
+            commit_reveal,
+            seeds,
             break_after,
             per_hand_delay,
             completed,
@@ -153,12 +206,20 @@ pub fn handle_sim_command(
     #[allow(clippy::mut_range_bound)]
     for i in completed..total {
         // Create a fresh engine per hand to avoid residual hole cards
-        let mut e = Engine::new(Some(base_seed + i as u64), level);
+        let mut e = sim_engine(
+            seeds,
+            i,
+            level,
+            variant,
+            betting,
+            runouts,
+            shuffle.as_ref().map(|source| hand_source(source, i)),
+            commit_reveal,
+        );
         e.shuffle();
         let _ = e.deal_hand();
 
-        // Play the hand to completion
-        let (actions, result, showdown) = play_hand_to_completion(&mut e);
+        play_hand_to_completion(&mut e);
 
         if let Some(p) = &path {
             if let Err(e) = ensure_parent_dir(p) {
@@ -178,19 +239,7 @@ pub fn handle_sim_command(
                 }
             };
 
-            let hand_id = format!("19700101-{:06}", i + 1);
-            let board = e.board().clone();
-            let rec = serde_json::json!({
-                "hand_id": hand_id,
-                "seed": base_seed + i as u64,
-                "level": level,
-                "actions": actions,
-                "board": board,
-                "result": result,
-                "ts": "1970-01-01T00:00:00+00:00".to_string(),
-                "meta": null,
-                "showdown": showdown
-            });
+            let rec = sim_record(&e, i);
 
             let json_str = match serde_json::to_string(&rec) {
                 Ok(s) => s,
@@ -228,36 +277,79 @@ pub fn handle_sim_command(
     Ok(())
 }
 
+/// Parse a `--shuffle` value: a source name, or the path of a recorded shuffle file.
+fn parse_shuffle_source(spec: &str) -> Result<ShuffleSource, String> {
+    match spec.parse::<ShuffleSource>() {
+        Ok(source) => Ok(source),
+        Err(e) if !std::path::Path::new(spec).exists() => Err(e.to_string()),
+        Err(_) => {
+            ShuffleSource::recorded(&read_text_auto(spec)?).map_err(|e| format!("{}: {}", spec, e))
+        }
+    }
+}
+
+/// Shuffle source of the `index`-th hand; recorded orders are dealt one per hand.
+fn hand_source(source: &ShuffleSource, index: usize) -> ShuffleSource {
+    match source {
+        ShuffleSource::Recorded(orders) => {
+            ShuffleSource::Recorded(vec![orders[index % orders.len()].clone()])
+        }
+        other => other.clone(),
+    }
+}
+
+/// Fresh engine for the `index`-th simulated hand, seeded from its place in
+/// `seeds` unless another shuffle source is given.
+#[allow(clippy::too_many_arguments)]
+fn sim_engine(
+    seeds: SeedTree,
+    index: usize,
+    level: u8,
+    variant: GameVariant,
+    betting: BettingStructure,
+    runouts: usize,
+    shuffle: Option<ShuffleSource>,
+    commit_reveal: bool,
+) -> Engine {
+    let mut engine = Engine::new(Some(seeds.root()), level)
+        .with_variant(variant)
+        .expect("seeded decks have no pinned cards")
+        .with_betting_structure(betting)
+        .with_runouts(runouts)
+        .expect("runout count checked by the caller");
+    engine = match shuffle {
+        Some(source) => engine
+            .with_shuffle_source(source)
+            .expect("shuffle source checked by the caller"),
+        None => engine
+            .with_seed_path(seeds, SeedPath::hand(index as u64))
+            .expect("fresh engines shuffle from the seed"),
+    };
+    if commit_reveal {
+        engine = engine
+            .with_commit_reveal()
+            .expect("simulated decks have no pinned cards");
+    }
+    engine
+}
+
 /// Play a hand to completion using baseline AI for both players.
 ///
 /// This module-private helper function simulates a complete poker hand by having
 /// both players use the baseline AI strategy until the hand reaches completion.
+/// The engine settles the hand, so its outcome is available afterwards.
 ///
 /// # Arguments
 ///
 /// * `engine` - Mutable reference to the game engine with dealt cards
-///
-/// # Returns
-///
-/// A tuple containing:
-/// - Action history (Vec of ActionRecords)
-/// - Result string describing the outcome
-/// - Optional showdown information (JSON value with winners)
-fn play_hand_to_completion(
-    engine: &mut Engine,
-) -> (
-    Vec<axiomind_engine::logger::ActionRecord>,
-    String,
-    Option<serde_json::Value>,
-) {
-    use axiomind_engine::cards::Card;
-    use axiomind_engine::hand::{compare_hands, evaluate_hand};
-
+fn play_hand_to_completion(engine: &mut Engine) {
     let ai = create_ai("baseline");
 
-    // Play through the hand
     while let Ok(current_player) = engine.current_player() {
-        let action = ai.get_action(engine, current_player);
+        let Some(observation) = engine.observation(current_player) else {
+            break;
+        };
+        let action = ai.get_action(&observation);
 
         match engine.apply_action(current_player, action) {
             Ok(state) if state.is_hand_complete() => break,
@@ -265,71 +357,21 @@ fn play_hand_to_completion(
             Err(_) => break,
         }
     }
+}
 
-    // Get action history
-    let actions = engine.action_history();
-
-    // Determine winner
-    let (result_string, showdown) = if let Some(folded) = engine.folded_player() {
-        // Someone folded - other player wins
-        let winner = 1 - folded;
-        let pot = engine.pot();
-        (format!("Player {} wins {} (fold)", winner, pot), None)
-    } else if engine.reached_showdown() {
-        // Evaluate hands at showdown
-        let players = engine.players();
-        let board = engine.community_cards();
-
-        // Build 7-card hands for each player
-        let mut player0_cards = Vec::new();
-        let mut player1_cards = Vec::new();
-
-        if let [Some(c1), Some(c2)] = players[0].hole_cards() {
-            player0_cards.push(c1);
-            player0_cards.push(c2);
-        }
-        if let [Some(c1), Some(c2)] = players[1].hole_cards() {
-            player1_cards.push(c1);
-            player1_cards.push(c2);
-        }
-
-        player0_cards.extend_from_slice(&board);
-        player1_cards.extend_from_slice(&board);
-
-        if player0_cards.len() == 7 && player1_cards.len() == 7 {
-            let hand0: [Card; 7] = player0_cards.try_into().unwrap();
-            let hand1: [Card; 7] = player1_cards.try_into().unwrap();
-
-            let strength0 = evaluate_hand(&hand0);
-            let strength1 = evaluate_hand(&hand1);
-
-            let pot = engine.pot();
-            let comparison = compare_hands(&strength0, &strength1);
-
-            use std::cmp::Ordering;
-            let (result_str, showdown_info) = match comparison {
-                Ordering::Greater => (
-                    format!("Player 0 wins {} (showdown)", pot),
-                    Some(serde_json::json!({"winners": [0]})),
-                ),
-                Ordering::Less => (
-                    format!("Player 1 wins {} (showdown)", pot),
-                    Some(serde_json::json!({"winners": [1]})),
-                ),
-                Ordering::Equal => (
-                    format!("Split pot {} (tie)", pot),
-                    Some(serde_json::json!({"winners": [0, 1]})),
-                ),
-            };
-            (result_str, showdown_info)
-        } else {
-            ("Unknown result".to_string(), None)
-        }
-    } else {
-        ("No result".to_string(), None)
-    };
-
-    (actions, result_string, showdown)
+/// Build the hand history record of the `index`-th simulated hand.
+///
+/// Every simulated hand is dealt from fresh stacks, which `meta.stacks_reset`
+/// tells `verify` so it does not carry stacks over from the previous record.
+/// Its seed is the hand seed from the seed tree; hands shuffled from another
+/// source record none.
+fn sim_record(engine: &Engine, index: usize) -> HandRecord {
+    let mut record = engine.hand_record(format!("19700101-{:06}", index + 1), engine.hand_seed());
+    record.ts = Some("1970-01-01T00:00:00+00:00".to_string());
+    if let Some(meta) = record.meta.as_mut().and_then(|m| m.as_object_mut()) {
+        meta.insert("stacks_reset".to_string(), serde_json::Value::Bool(true));
+    }
+    record
 }
 
 /// Run simulation in fast mode with batch writes.
@@ -341,8 +383,12 @@ fn play_hand_to_completion(
 ///
 /// * `total` - Total number of hands to simulate
 /// * `level` - Blind level (1-20)
-/// * `_seed` - Original seed parameter (unused, kept for signature compatibility)
-/// * `base_seed` - Base RNG seed for hand generation
+/// * `variant` - Game variant of every hand
+/// * `betting` - Betting structure of every hand
+/// * `runouts` - Times the rest of the board is dealt once nobody can bet
+/// * `shuffle` - Shuffle source other than the seed, if any
+/// * `commit_reveal` - Whether every deck is committed to
+/// * `seeds` - Seed tree the hands are shuffled from
 /// * `break_after` - Optional break point for early termination
 /// * `per_hand_delay` - Optional delay between hands
 /// * `completed` - Number of hands already completed (from resume)
@@ -357,8 +403,12 @@ fn play_hand_to_completion(
 fn sim_run_fast(
     total: usize,
     level: u8,
-    _seed: Option<u64>,
-    base_seed: u64,
+    variant: GameVariant,
+    betting: BettingStructure,
+    runouts: usize,
+    shuffle: Option<&ShuffleSource>,
+    commit_reveal: bool,
+    seeds: SeedTree,
     break_after: Option<usize>,
     per_hand_delay: Option<std::time::Duration>,
     mut completed: usize,
@@ -390,27 +440,23 @@ fn sim_run_fast(
 
     #[allow(clippy::mut_range_bound)]
     for i in completed..total {
-        let mut engine = Engine::new(Some(base_seed + i as u64), level);
+        let mut engine = sim_engine(
+            seeds,
+            i,
+            level,
+            variant,
+            betting,
+            runouts,
+            shuffle.map(|source| hand_source(source, i)),
+            commit_reveal,
+        );
         engine.shuffle();
         let _ = engine.deal_hand();
 
-        // Play the hand to completion
-        let (actions, result, showdown) = play_hand_to_completion(&mut engine);
+        play_hand_to_completion(&mut engine);
 
         if let Some(w) = writer.as_mut() {
-            let hand_id = format!("19700101-{:06}", i + 1);
-            let board = engine.board().clone();
-            let record = serde_json::json!({
-                "hand_id": hand_id,
-                "seed": base_seed + i as u64,
-                "level": level,
-                "actions": actions,
-                "board": board,
-                "result": result,
-                "ts": "1970-01-01T00:00:00+00:00".to_string(),
-                "meta": null,
-                "showdown": showdown
-            });
+            let record = sim_record(&engine, i);
             if let Err(e) = writeln!(w, "{}", serde_json::to_string(&record).unwrap()) {
                 ui::write_error(err, "Failed to write simulation output")?;
                 return Err(CliError::Io(e));
@@ -461,7 +507,20 @@ mod tests {
         let mut err = Vec::new();
 
         // Test basic execution with minimal hands
-        let result = handle_sim_command(1, None, Some(42), Some(1), None, &mut out, &mut err);
+        let result = handle_sim_command(
+            1,
+            None,
+            Some(42),
+            Some(1),
+            None,
+            None,
+            None,
+            None,
+            None,
+            false,
+            &mut out,
+            &mut err,
+        );
         assert!(result.is_ok());
 
         let output = String::from_utf8(out).unwrap();
@@ -474,7 +533,20 @@ mod tests {
         let mut err = Vec::new();
 
         // Test that seed is respected
-        let result = handle_sim_command(5, None, Some(123), Some(1), None, &mut out, &mut err);
+        let result = handle_sim_command(
+            5,
+            None,
+            Some(123),
+            Some(1),
+            None,
+            None,
+            None,
+            None,
+            None,
+            false,
+            &mut out,
+            &mut err,
+        );
         assert!(result.is_ok());
 
         let output = String::from_utf8(out).unwrap();
@@ -487,7 +559,20 @@ mod tests {
         let mut err = Vec::new();
 
         // Test without explicit seed (should use default)
-        let result = handle_sim_command(5, None, None, Some(1), None, &mut out, &mut err);
+        let result = handle_sim_command(
+            5,
+            None,
+            None,
+            Some(1),
+            None,
+            None,
+            None,
+            None,
+            None,
+            false,
+            &mut out,
+            &mut err,
+        );
         assert!(result.is_ok());
 
         let output = String::from_utf8(out).unwrap();
@@ -500,7 +585,20 @@ mod tests {
         let mut err = Vec::new();
 
         // Test with zero hands (should return error)
-        let result = handle_sim_command(0, None, Some(42), Some(1), None, &mut out, &mut err);
+        let result = handle_sim_command(
+            0,
+            None,
+            Some(42),
+            Some(1),
+            None,
+            None,
+            None,
+            None,
+            None,
+            false,
+            &mut out,
+            &mut err,
+        );
         assert!(result.is_err());
 
         let error_output = String::from_utf8(err).unwrap();
@@ -515,7 +613,20 @@ mod tests {
         let mut err = Vec::new();
 
         // Even without setting env vars, command should work
-        let result = handle_sim_command(1, None, Some(42), Some(1), None, &mut out, &mut err);
+        let result = handle_sim_command(
+            1,
+            None,
+            Some(42),
+            Some(1),
+            None,
+            None,
+            None,
+            None,
+            None,
+            false,
+            &mut out,
+            &mut err,
+        );
         assert!(result.is_ok());
     }
 }
@@ -70,15 +70,10 @@ fn run_stats(input: &str, out: &mut dyn Write, err: &mut dyn Write) -> Result<()
                 }
             };
 
-            let rec: axiomind_engine::logger::HandRecord =
-                match serde_json::from_value(parsed.clone()) {
-                    Ok(v) => v,
-                    Err(_) => {
-                        state.corrupted += 1;
-                        continue;
-                    }
-                };
-
+            let hand_id = parsed
+                .get("hand_id")
+                .and_then(|h| h.as_str())
+                .unwrap_or("unknown");
             if let Some(net_obj) = parsed.get("net_result").and_then(|v| v.as_object()) {
                 let mut sum = 0i64;
                 let mut invalid = false;
@@ -92,7 +87,7 @@ fn run_stats(input: &str, out: &mut dyn Write, err: &mut dyn Write) -> Result<()
                             err,
                             &format!(
                                 "Invalid net_result value for {} at hand {}",
-                                player, rec.hand_id
+                                player, hand_id
                             ),
                         )?;
                     }
@@ -101,7 +96,7 @@ fn run_stats(input: &str, out: &mut dyn Write, err: &mut dyn Write) -> Result<()
                     state.stats_ok = false;
                     ui::write_error(
                         err,
-                        &format!("Chip conservation violated at hand {}", rec.hand_id),
+                        &format!("Chip conservation violated at hand {}", hand_id),
                     )?;
                 }
                 if invalid {
@@ -109,13 +104,32 @@ fn run_stats(input: &str, out: &mut dyn Write, err: &mut dyn Write) -> Result<()
                 }
             }
 
+            let rec: axiomind_engine::logger::HandRecord =
+                match serde_json::from_value(parsed.clone()) {
+                    Ok(v) => v,
+                    Err(_) => {
+                        state.corrupted += 1;
+                        continue;
+                    }
+                };
+
             state.hands += 1;
-            if let Some(r) = rec.result.as_deref() {
-                if r == "p0" {
-                    state.p0 += 1;
-                }
-                if r == "p1" {
-                    state.p1 += 1;
+            // v2 records name the winners through net_result; older ones may
+            // carry the winning seat id as the result
+            let winners: Vec<&str> = match rec.result.as_deref() {
+                Some(r @ ("p0" | "p1")) => vec![r],
+                _ => rec
+                    .net_result
+                    .iter()
+                    .filter(|(_, net)| **net > 0)
+                    .map(|(id, _)| id.as_str())
+                    .collect(),
+            };
+            for winner in winners {
+                match winner {
+                    "p0" => state.p0 += 1,
+                    "p1" => state.p1 += 1,
+                    _ => {}
                 }
             }
         }
@@ -3,20 +3,33 @@
 //! Validates hand history integrity and game rules compliance for JSONL hand history files.
 //! This module performs comprehensive validation checks including:
 //!
-//! - Board completeness (exactly 5 cards)
+//! - Board consistency (0/3/4/5 cards, covering every street with actions; 5 at showdown)
 //! - No duplicate cards across board and hole cards
+//! - Hole card counts, and for short deck the cards themselves, matching the recorded variant
 //! - Chip conservation (net_result must sum to zero)
 //! - Valid hand IDs (format: YYYYMMDD-NNNNNN)
 //! - Betting rules compliance (no illegal reopening after short all-in)
+//! - Bet sizes within the recorded pot-limit or fixed-limit betting structure
 //! - Player roster consistency across hands
 //! - Street progression validation (Preflop → Flop → Turn → River)
 //! - Stack continuity between hands
+//! - Hand seeds matching the seed tree derivation recorded in `meta.seed_derivation`
+//! - With `--fairness`, the shuffle commitment in `meta.fairness`: the revealed seed and
+//!   deck match it, and every card was dealt from its place in that deck
 //!
 //! Errors are collected using the shared `BatchValidationError` pattern for structured reporting.
 
 use crate::error::{BatchValidationError, CliError};
 use crate::io_utils::read_text_auto;
 use crate::validation::validate_dealing_meta;
+use axiomind_engine::cards::Rank;
+use axiomind_engine::deck::ShuffleReveal;
+use axiomind_engine::engine::Engine;
+use axiomind_engine::logger::HandRecord;
+use axiomind_engine::player::{Player, Position};
+use axiomind_engine::rules::{BettingStructure, BlindStructure};
+use axiomind_engine::seed::SeedDerivation;
+use axiomind_engine::variant::GameVariant;
 use std::collections::{HashMap, HashSet};
 use std::io::Write;
 
@@ -32,6 +45,7 @@ type VerifyError = BatchValidationError<usize>;
 /// # Arguments
 ///
 /// * `input` - Path to JSONL file to verify
+/// * `fairness` - Also check every hand's shuffle commitment (`meta.fairness`)
 /// * `out` - Output stream for verification results (stdout)
 /// * `err` - Output stream for error messages (stderr)
 ///
@@ -49,6 +63,7 @@ type VerifyError = BatchValidationError<usize>;
 /// ```
 pub fn handle_verify_command(
     input: String,
+    fairness: bool,
     out: &mut dyn Write,
     err: &mut dyn Write,
 ) -> Result<(), CliError> {
@@ -70,16 +85,6 @@ pub fn handle_verify_command(
 
     for line in content.lines().filter(|l| !l.trim().is_empty()) {
         hands += 1;
-        if game_over {
-            errors.push(VerifyError {
-                item_context: hands as usize,
-                message: format!(
-                    "Hand {} recorded after player elimination (zero stack)",
-                    hands
-                ),
-            });
-            continue;
-        }
 
         // Parse as Value first to validate optional net_result chip conservation
         let v: serde_json::Value = match serde_json::from_str(line) {
@@ -93,6 +98,27 @@ pub fn handle_verify_command(
             }
         };
 
+        // Hands dealt from fresh stacks (e.g. `sim` output) start a new session
+        let stacks_reset = v
+            .get("meta")
+            .and_then(|m| m.get("stacks_reset"))
+            .and_then(|r| r.as_bool())
+            .unwrap_or(false);
+        if stacks_reset {
+            game_over = false;
+            stacks_after_hand.clear();
+        }
+        if game_over {
+            errors.push(VerifyError {
+                item_context: hands as usize,
+                message: format!(
+                    "Hand {} recorded after player elimination (zero stack)",
+                    hands
+                ),
+            });
+            continue;
+        }
+
         // Extract hand_id early for better error reporting
         let _hand_id = v
             .get("hand_id")
@@ -110,6 +136,7 @@ pub fn handle_verify_command(
         }
 
         // Level 2 Validation: Action sequence and street progression
+        let mut last_street_index: Option<usize> = None;
         if let Some(actions) = v.get("actions").and_then(|a| a.as_array()) {
             let mut prev_street: Option<String> = None;
             let streets_order = ["Preflop", "Flop", "Turn", "River"];
@@ -152,12 +179,18 @@ pub fn handle_verify_command(
                         }
                     }
                     prev_street = Some(current_street);
+                    last_street_index = Some(street_index);
                 }
             }
         }
 
         let mut starting_stacks: Option<HashMap<String, i64>> = None;
-        if let Some(players) = v.get("players").and_then(|p| p.as_array()) {
+        // An empty roster means the record does not track players
+        if let Some(players) = v
+            .get("players")
+            .and_then(|p| p.as_array())
+            .filter(|p| !p.is_empty())
+        {
             let mut start_map = HashMap::new();
             for player in players {
                 let Some(id) = player.get("id").and_then(|x| x.as_str()) else {
@@ -244,7 +277,9 @@ pub fn handle_verify_command(
         }
 
         let mut big_blind = MIN_CHIP_UNIT;
-        if let Some(blinds_val) = v.get("blinds") {
+        if let Some(bb) = v.get("bb").and_then(|x| x.as_i64()) {
+            big_blind = bb;
+        } else if let Some(blinds_val) = v.get("blinds") {
             if let Some(bb) = blinds_val.get("bb").and_then(|x| x.as_i64()) {
                 big_blind = bb;
             } else if let Some(arr) = blinds_val.as_array()
@@ -253,6 +288,8 @@ pub fn handle_verify_command(
             {
                 big_blind = bb;
             }
+        } else if let Some(bb) = recorded_big_blind(&v) {
+            big_blind = bb;
         }
         if big_blind < MIN_CHIP_UNIT {
             big_blind = MIN_CHIP_UNIT;
@@ -300,16 +337,53 @@ pub fn handle_verify_command(
 
         match serde_json::from_value::<axiomind_engine::logger::HandRecord>(v.clone()) {
             Ok(rec) => {
-                if rec.board.len() != 5 {
+                // The board is revealed street by street, so its length follows the
+                // furthest street reached; a showdown always has the full board.
+                let board_len = rec.board.len();
+                let reached_showdown = rec.showdown.is_some()
+                    || v.get("end_reason").and_then(|r| r.as_str()) == Some("showdown");
+                let required = if reached_showdown {
+                    5
+                } else {
+                    match last_street_index {
+                        Some(1) => 3,
+                        Some(2) => 4,
+                        Some(3) => 5,
+                        _ => 0,
+                    }
+                };
+                if !matches!(board_len, 0 | 3 | 4 | 5) || board_len < required {
                     errors.push(VerifyError {
                         item_context: hands as usize,
                         message: format!(
-                            "Invalid board length: expected 5 cards but found {}",
-                            rec.board.len()
+                            "Invalid board length: expected at least {} cards but found {}",
+                            required, board_len
                         ),
                     });
                 }
 
+                // A board run more than once lists every runout, the record's board
+                // first; the others share its first cards and take the rest from the deck
+                let runouts: &[Vec<axiomind_engine::cards::Card>] = rec
+                    .showdown
+                    .as_ref()
+                    .map_or(&[], |showdown| &showdown.runouts);
+                if !runouts.is_empty()
+                    && (runouts[0] != rec.board || runouts.iter().any(|board| board.len() != 5))
+                {
+                    errors.push(VerifyError {
+                        item_context: hands as usize,
+                        message: "Runout boards do not match the board".to_string(),
+                    });
+                }
+                let shared = (0..rec.board.len())
+                    .take_while(|&i| runouts.iter().all(|board| board.get(i) == rec.board.get(i)))
+                    .count();
+                let runout_cards = runouts
+                    .iter()
+                    .skip(1)
+                    .flat_map(|board| board.iter().skip(shared));
+
                 let mut seen_cards: HashSet<axiomind_engine::cards::Card> = HashSet::new();
                 let mut duplicate_cards: HashSet<axiomind_engine::cards::Card> = HashSet::new();
                 {
@@ -318,7 +392,7 @@ pub fn handle_verify_command(
                             duplicate_cards.insert(card);
                         }
                     };
-                    for card in &rec.board {
+                    for card in rec.board.iter().chain(runout_cards.clone()) {
                         record_card(*card);
                     }
                     if let Some(players) = v.get("players").and_then(|p| p.as_array()) {
@@ -363,6 +437,62 @@ pub fn handle_verify_command(
                     });
                 }
 
+                let variant = recorded_variant(&v);
+                for player in &rec.players {
+                    if let Some(cards) = &player.hole_cards
+                        && cards.len() != variant.hole_cards()
+                    {
+                        errors.push(VerifyError {
+                            item_context: hands as usize,
+                            message: format!(
+                                "{} has {} hole cards, but {} deals {}",
+                                player.id,
+                                cards.len(),
+                                variant,
+                                variant.hole_cards()
+                            ),
+                        });
+                    }
+                }
+                if variant.uses_short_deck() {
+                    let dealt = rec.board.iter().chain(runout_cards).chain(
+                        rec.players
+                            .iter()
+                            .filter_map(|p| p.hole_cards.as_ref())
+                            .flatten(),
+                    );
+                    for card in dealt.filter(|c| c.rank < Rank::Six) {
+                        errors.push(VerifyError {
+                            item_context: hands as usize,
+                            message: format!("{} is not in the short deck", card),
+                        });
+                    }
+                }
+
+                if let Err(msg) = ensure_seed_derivation(&v, &rec) {
+                    errors.push(VerifyError {
+                        item_context: hands as usize,
+                        message: msg,
+                    });
+                }
+
+                if fairness && let Err(msg) = ensure_fair_shuffle(&v, &rec, variant) {
+                    errors.push(VerifyError {
+                        item_context: hands as usize,
+                        message: msg,
+                    });
+                }
+
+                if let Some(structure) = recorded_betting_structure(&v)
+                    && structure != BettingStructure::NoLimit
+                    && let Err(msg) = ensure_betting_structure(&rec, structure, variant)
+                {
+                    errors.push(VerifyError {
+                        item_context: hands as usize,
+                        message: msg,
+                    });
+                }
+
                 if !valid_id(&rec.hand_id) {
                     errors.push(VerifyError {
                         item_context: hands as usize,
@@ -413,6 +543,151 @@ pub fn handle_verify_command(
     }
 }
 
+/// Module-private helper: Reconstruct the big blind from a blind structure recorded in `meta`.
+///
+/// The level comes from `meta.level`, falling back to the record's top-level `level`.
+/// Returns `None` when the record carries no (valid) structure.
+fn recorded_big_blind(record: &serde_json::Value) -> Option<i64> {
+    let meta = record.get("meta")?;
+    let structure: BlindStructure =
+        serde_json::from_value(meta.get("blind_structure")?.clone()).ok()?;
+    let level = meta
+        .get("level")
+        .or_else(|| record.get("level"))
+        .and_then(|l| l.as_u64())
+        .unwrap_or(1);
+    let level = u8::try_from(level).unwrap_or(u8::MAX);
+    structure.blinds(level).ok().map(|(_, bb)| i64::from(bb))
+}
+
+/// Module-private helper: Read the betting structure recorded in `meta`, if any.
+fn recorded_betting_structure(record: &serde_json::Value) -> Option<BettingStructure> {
+    let structure = record.get("meta")?.get("betting_structure")?;
+    serde_json::from_value(structure.clone()).ok()
+}
+
+/// Module-private helper: Game variant recorded in `meta.variant`; Hold'em when absent.
+fn recorded_variant(record: &serde_json::Value) -> GameVariant {
+    record
+        .get("meta")
+        .and_then(|meta| meta.get("variant"))
+        .and_then(|v| serde_json::from_value(v.clone()).ok())
+        .unwrap_or_default()
+}
+
+/// Module-private helper: Check the seed against `meta.seed_derivation`, when recorded.
+///
+/// The derivation must use a known scheme and yield the record's `seed`.
+fn ensure_seed_derivation(record: &serde_json::Value, rec: &HandRecord) -> Result<(), String> {
+    let Some(derivation) = record
+        .get("meta")
+        .and_then(|meta| meta.get("seed_derivation"))
+    else {
+        return Ok(());
+    };
+    let derivation: SeedDerivation = serde_json::from_value(derivation.clone())
+        .map_err(|e| format!("Invalid seed derivation: {}", e))?;
+    let derived = derivation.hand_seed().map_err(|e| e.to_string())?;
+    match rec.seed {
+        Some(seed) if seed == derived => Ok(()),
+        Some(seed) => Err(format!(
+            "Seed {} does not match {} derived from root {} (match {}, hand {})",
+            seed, derived, derivation.root, derivation.path.match_index, derivation.path.hand
+        )),
+        None => Err("Seed derivation recorded without a seed".to_string()),
+    }
+}
+
+/// Module-private helper: Check the shuffle commitment recorded in `meta.fairness`.
+///
+/// The revealed seed and deck must match the commitment, the deck must be the one
+/// the variant deals from, and every card of the hand must sit where the engine
+/// deals it from that deck.
+fn ensure_fair_shuffle(
+    record: &serde_json::Value,
+    rec: &HandRecord,
+    variant: GameVariant,
+) -> Result<(), String> {
+    let fairness = record.get("meta").and_then(|meta| meta.get("fairness"));
+    let Some(commitment) = fairness
+        .and_then(|f| f.get("commitment"))
+        .and_then(|c| c.as_str())
+    else {
+        return Err("No shuffle commitment recorded".to_string());
+    };
+    let Some(reveal) = fairness.and_then(|f| f.get("reveal")) else {
+        return Err("Shuffle commitment was never revealed".to_string());
+    };
+    let reveal: ShuffleReveal = serde_json::from_value(reveal.clone())
+        .map_err(|e| format!("Invalid shuffle reveal: {}", e))?;
+    let deck_size = if variant.uses_short_deck() { 36 } else { 52 };
+    if reveal.deck.len() != deck_size {
+        return Err(format!(
+            "Revealed deck has {} cards, but {} deals from {}",
+            reveal.deck.len(),
+            variant,
+            deck_size
+        ));
+    }
+    reveal
+        .verify(commitment)
+        .and_then(|()| reveal.check_deal(rec))
+        .map_err(|e| e.to_string())
+}
+
+/// Module-private helper: Check every action against the recorded betting structure.
+///
+/// Pot-limit and fixed-limit sizes depend on the pot and on the raises made so far,
+/// so the actions are replayed through an engine set up with the recorded stacks,
+/// button, blinds and structure, which rejects any bet or raise the structure forbids.
+fn ensure_betting_structure(
+    rec: &HandRecord,
+    structure: BettingStructure,
+    variant: GameVariant,
+) -> Result<(), String> {
+    let blind_structure = rec
+        .meta
+        .as_ref()
+        .and_then(|meta| meta.get("blind_structure"))
+        .and_then(|v| serde_json::from_value::<BlindStructure>(v.clone()).ok())
+        .unwrap_or_default();
+    let mut engine = Engine::new(None, rec.level.unwrap_or(1))
+        .with_seats(rec.players.len())
+        .map_err(|e| e.to_string())?
+        .with_blind_structure(blind_structure)
+        .with_betting_structure(structure)
+        .with_variant(variant)
+        .map_err(|e| e.to_string())?;
+    for (seat, player) in rec.players.iter().enumerate() {
+        engine.players_mut()[seat] = Player::new(seat, player.stack_start, Position::Other);
+    }
+    let button = rec
+        .button
+        .as_deref()
+        .and_then(|id| id.strip_prefix('p'))
+        .and_then(|seat| seat.parse::<usize>().ok())
+        .unwrap_or(0);
+    engine.set_button(button);
+    engine
+        .deal_hand()
+        .map_err(|e| format!("Cannot replay betting: {}", e))?;
+    for (idx, act) in rec.actions.iter().enumerate() {
+        engine
+            .apply_action(act.player_id, act.action.clone())
+            .map_err(|e| {
+                format!(
+                    "Action #{} ({:?} by p{}) breaks the {} betting structure: {}",
+                    idx + 1,
+                    act.action,
+                    act.player_id,
+                    structure,
+                    e
+                )
+            })?;
+    }
+    Ok(())
+}
+
 /// Module-private helper: Validate that no illegal reopening occurs after a short all-in.
 ///
 /// In No-Limit Hold'em, a short all-in (less than a full raise) does not reopen betting
@@ -664,7 +939,7 @@ mod tests {
         let valid_hand = r#"{"hand_id":"20250101-000001","board":[{"rank":"Ace","suit":"Spades"},{"rank":"King","suit":"Hearts"},{"rank":"Queen","suit":"Diamonds"},{"rank":"Jack","suit":"Clubs"},{"rank":"Ten","suit":"Spades"}],"result":"Player 0 wins 100","net_result":{"p0":50,"p1":-50},"players":[{"id":"p0","stack_start":1000,"hole_cards":[{"rank":"Ace","suit":"Hearts"},{"rank":"Ace","suit":"Diamonds"}]},{"id":"p1","stack_start":1000,"hole_cards":[{"rank":"Two","suit":"Clubs"},{"rank":"Three","suit":"Clubs"}]}],"actions":[]}"#;
         std::fs::write("test_data/valid_hands.jsonl", valid_hand).ok();
 
-        let result = handle_verify_command(input, &mut out, &mut err);
+        let result = handle_verify_command(input, false, &mut out, &mut err);
         assert!(result.is_ok());
         let output = String::from_utf8(out).unwrap();
         assert!(output.contains("Verify: OK"));
@@ -679,7 +954,7 @@ mod tests {
         let mut out = Vec::new();
         let mut err = Vec::new();
 
-        let result = handle_verify_command(input, &mut out, &mut err);
+        let result = handle_verify_command(input, false, &mut out, &mut err);
         assert!(result.is_err());
     }
 
@@ -693,7 +968,7 @@ mod tests {
         std::fs::create_dir_all("test_data").ok();
         std::fs::write("test_data/invalid.jsonl", "not valid json\n").ok();
 
-        let result = handle_verify_command(input, &mut out, &mut err);
+        let result = handle_verify_command(input, false, &mut out, &mut err);
         assert!(result.is_err());
         let err_output = String::from_utf8(err).unwrap();
         assert!(err_output.contains("Invalid JSON record"));
@@ -733,6 +1008,38 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_recorded_big_blind_uses_meta_structure() {
+        use axiomind_engine::rules::BlindLevel;
+
+        let structure = BlindStructure {
+            hands_per_level: 10,
+            big_blind_ante: false,
+            levels: vec![
+                BlindLevel {
+                    sb: 100,
+                    bb: 200,
+                    ante: 0,
+                },
+                BlindLevel {
+                    sb: 200,
+                    bb: 400,
+                    ante: 50,
+                },
+            ],
+        };
+        let rec = serde_json::json!({"level": 2, "meta": {"blind_structure": structure}});
+        assert_eq!(recorded_big_blind(&rec), Some(400));
+
+        let rec =
+            serde_json::json!({"level": 2, "meta": {"level": 1, "blind_structure": structure}});
+        assert_eq!(recorded_big_blind(&rec), Some(200));
+
+        // Without a recorded structure the caller keeps its default
+        let rec = serde_json::json!({"level": 2, "meta": {"level": 2}});
+        assert_eq!(recorded_big_blind(&rec), None);
+    }
+
     #[test]
     fn test_valid_hand_id_format() {
         let valid_id = |s: &str| -> bool {
@@ -761,7 +1068,7 @@ mod tests {
         let invalid_hand = r#"{"hand_id":"20250101-000001","board":[{"rank":"Ace","suit":"Spades"},{"rank":"King","suit":"Hearts"},{"rank":"Queen","suit":"Diamonds"},{"rank":"Jack","suit":"Clubs"},{"rank":"Ten","suit":"Spades"}],"result":"Player 0 wins","net_result":{"p0":100,"p1":-50},"players":[{"id":"p0","stack_start":1000},{"id":"p1","stack_start":1000}],"actions":[]}"#;
         std::fs::write("test_data/chip_violation.jsonl", invalid_hand).ok();
 
-        let result = handle_verify_command(input, &mut out, &mut err);
+        let result = handle_verify_command(input, false, &mut out, &mut err);
         assert!(result.is_err());
         let err_output = String::from_utf8(err).unwrap();
         assert!(err_output.contains("Chip conservation violated"));
@@ -769,4 +1076,43 @@ mod tests {
         // Cleanup
         std::fs::remove_file("test_data/chip_violation.jsonl").ok();
     }
+
+    #[test]
+    fn test_board_length_follows_streets_reached() {
+        std::fs::create_dir_all("test_data").ok();
+
+        // Preflop fold: no community cards were ever dealt
+        let folded = r#"{"hand_id":"20250101-000001","board":[],"result":"Player 1 wins 150 (fold)","actions":[{"player_id":0,"street":"Preflop","action":"Fold"}]}"#;
+        std::fs::write("test_data/board_folded.jsonl", folded).ok();
+        let mut out = Vec::new();
+        let mut err = Vec::new();
+        let result = handle_verify_command(
+            "test_data/board_folded.jsonl".into(),
+            false,
+            &mut out,
+            &mut err,
+        );
+        assert!(result.is_ok(), "{}", String::from_utf8_lossy(&err));
+
+        // Turn action recorded but only the flop on the board
+        let short = r#"{"hand_id":"20250101-000001","board":[{"rank":"Ace","suit":"Spades"},{"rank":"King","suit":"Hearts"},{"rank":"Queen","suit":"Diamonds"}],"result":"Player 0 wins 300 (fold)","actions":[{"player_id":0,"street":"Preflop","action":"Call"},{"player_id":1,"street":"Preflop","action":"Check"},{"player_id":1,"street":"Flop","action":"Check"},{"player_id":0,"street":"Flop","action":"Check"},{"player_id":1,"street":"Turn","action":"Fold"}]}"#;
+        std::fs::write("test_data/board_short.jsonl", short).ok();
+        let mut out = Vec::new();
+        let mut err = Vec::new();
+        let result = handle_verify_command(
+            "test_data/board_short.jsonl".into(),
+            false,
+            &mut out,
+            &mut err,
+        );
+        assert!(result.is_err());
+        assert!(
+            String::from_utf8(err)
+                .unwrap()
+                .contains("Invalid board length")
+        );
+
+        std::fs::remove_file("test_data/board_folded.jsonl").ok();
+        std::fs::remove_file("test_data/board_short.jsonl").ok();
+    }
 }
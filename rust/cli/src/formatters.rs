@@ -28,6 +28,7 @@
 //! ```
 
 use axiomind_engine::cards::{Card, Rank, Suit};
+use axiomind_engine::rules::LegalActions;
 
 /// Check if the terminal supports Unicode card symbols by detecting modern terminal environments.
 ///
@@ -78,13 +79,7 @@ pub fn format_suit(suit: &Suit) -> String {
         }
         .to_string()
     } else {
-        match suit {
-            Suit::Hearts => "h",
-            Suit::Diamonds => "d",
-            Suit::Clubs => "c",
-            Suit::Spades => "s",
-        }
-        .to_string()
+        suit.to_string()
     }
 }
 
@@ -98,22 +93,7 @@ pub fn format_suit(suit: &Suit) -> String {
 ///
 /// Single-character string representation of the rank
 pub fn format_rank(rank: &Rank) -> String {
-    match rank {
-        Rank::Two => "2",
-        Rank::Three => "3",
-        Rank::Four => "4",
-        Rank::Five => "5",
-        Rank::Six => "6",
-        Rank::Seven => "7",
-        Rank::Eight => "8",
-        Rank::Nine => "9",
-        Rank::Ten => "T",
-        Rank::Jack => "J",
-        Rank::Queen => "Q",
-        Rank::King => "K",
-        Rank::Ace => "A",
-    }
-    .to_string()
+    rank.to_string()
 }
 
 /// Format a Card as a string combining rank and suit.
@@ -205,6 +185,58 @@ pub fn format_action(action: &axiomind_engine::player::PlayerAction) -> String {
     }
 }
 
+/// Format the legal actions as a prompt hint, in the syntax `play` accepts.
+///
+/// Raise sizes are shown as the amount added on top of the call, which is what
+/// `raise <amount>` expects.
+///
+/// # Arguments
+///
+/// * `legal` - Actions the engine allows the player on turn
+///
+/// # Returns
+///
+/// A slash-separated list like "fold/call 50/raise 100-19900/allin".
+///
+/// # Example
+///
+/// ```rust
+/// use axiomind_engine::rules::legal_actions;
+/// # use axiomind_cli::formatters::format_legal_actions;
+///
+/// let legal = legal_actions(19_950, 50, 100, 100);
+/// assert_eq!(format_legal_actions(&legal), "fold/call 50/raise 100-19900/allin");
+///
+/// let legal = legal_actions(19_900, 0, 0, 100);
+/// assert_eq!(format_legal_actions(&legal), "fold/check/bet 100-19900/allin");
+/// ```
+pub fn format_legal_actions(legal: &LegalActions) -> String {
+    let mut parts = Vec::new();
+    if legal.fold {
+        parts.push("fold".to_string());
+    }
+    if legal.check {
+        parts.push("check".to_string());
+    }
+    if let Some(amount) = legal.call {
+        parts.push(format!("call {}", amount));
+    }
+    if let Some(range) = legal.bet {
+        parts.push(format!("bet {}-{}", range.min, range.max));
+    }
+    if let Some(range) = legal.raise_to {
+        parts.push(format!(
+            "raise {}-{}",
+            range.min - legal.current_bet,
+            range.max - legal.current_bet
+        ));
+    }
+    if legal.all_in.is_some() {
+        parts.push("allin".to_string());
+    }
+    parts.join("/")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -42,9 +42,9 @@ use cli::{AxiomindCli, Commands};
 // Import utility functions from extracted modules
 use commands::{
     handle_bench_command, handle_cfg_command, handle_dataset_command, handle_deal_command,
-    handle_doctor_command, handle_eval_command, handle_export_command, handle_play_command,
-    handle_replay_command, handle_rng_command, handle_sim_command, handle_stats_command,
-    handle_verify_command,
+    handle_doctor_command, handle_equity_command, handle_eval_command, handle_export_command,
+    handle_play_command, handle_replay_command, handle_rng_command, handle_sim_command,
+    handle_stats_command, handle_verify_command,
 };
 
 // Re-exports
@@ -99,8 +99,8 @@ fn handle_parse_error(e: clap::Error, out: &mut dyn Write, err: &mut dyn Write)
         _ => {
             // For parse errors, show clap's error message plus a helpful commands list
             const COMMANDS: &[&str] = &[
-                "play", "replay", "stats", "verify", "deal", "bench", "sim", "eval", "export",
-                "dataset", "cfg", "doctor", "rng",
+                "play", "replay", "stats", "verify", "deal", "bench", "sim", "eval", "equity",
+                "export", "dataset", "cfg", "doctor", "rng",
             ];
 
             let _ = writeln!(err, "{}", e);
@@ -126,16 +126,28 @@ fn execute_command(cmd: Commands, out: &mut dyn Write, err: &mut dyn Write) -> i
             hands,
             seed,
             level,
+            output,
+            variant,
         } => {
             let stdin = std::io::stdin();
             let mut stdin_lock = stdin.lock();
-            handle_play_command(vs, hands, seed, level, out, err, &mut stdin_lock)
+            handle_play_command(
+                vs,
+                hands,
+                seed,
+                level,
+                output,
+                variant,
+                out,
+                err,
+                &mut stdin_lock,
+            )
         }
         Commands::Replay { input, speed } => handle_replay_command(input, speed, out, err),
         Commands::Stats { input } => handle_stats_command(input, out, err),
-        Commands::Verify { input } => {
+        Commands::Verify { input, fairness } => {
             if let Some(path) = input {
-                handle_verify_command(path, out, err)
+                handle_verify_command(path, fairness, out, err)
             } else {
                 let _ = ui::write_error(err, "input required");
                 return 2;
@@ -148,8 +160,22 @@ fn execute_command(cmd: Commands, out: &mut dyn Write, err: &mut dyn Write) -> i
             hands,
             seed,
         } => handle_eval_command(&ai_a, &ai_b, hands, seed, out),
+        Commands::Equity {
+            hands,
+            board,
+            dead,
+            samples,
+            seed,
+        } => handle_equity_command(
+            &hands,
+            board.as_deref(),
+            dead.as_deref(),
+            samples,
+            seed,
+            out,
+        ),
         Commands::Bench => handle_bench_command(out),
-        Commands::Deal { seed } => handle_deal_command(seed, out),
+        Commands::Deal { seed, cards } => handle_deal_command(seed, cards.as_deref(), out),
         Commands::Rng { seed } => handle_rng_command(seed, out),
         Commands::Sim {
             hands,
@@ -157,7 +183,25 @@ fn execute_command(cmd: Commands, out: &mut dyn Write, err: &mut dyn Write) -> i
             seed,
             level,
             resume,
-        } => handle_sim_command(hands, output, seed, level, resume, out, err),
+            betting,
+            variant,
+            runouts,
+            shuffle,
+            commit_reveal,
+        } => handle_sim_command(
+            hands,
+            output,
+            seed,
+            level,
+            resume,
+            betting,
+            variant,
+            runouts,
+            shuffle,
+            commit_reveal,
+            out,
+            err,
+        ),
         Commands::Export {
             input,
             format,
@@ -233,7 +277,7 @@ mod tests {
     fn test_deal_command_dispatch_with_seed() {
         let mut out = Vec::new();
 
-        let result = handle_deal_command(Some(42), &mut out);
+        let result = handle_deal_command(Some(42), None, &mut out);
         assert!(result.is_ok());
 
         let output = String::from_utf8(out).unwrap();
@@ -244,7 +288,7 @@ mod tests {
     fn test_deal_command_dispatch_without_seed() {
         let mut out = Vec::new();
 
-        let result = handle_deal_command(None, &mut out);
+        let result = handle_deal_command(None, None, &mut out);
         assert!(result.is_ok());
     }
 
@@ -354,6 +398,8 @@ mod tests {
             Some(1),
             Some(42),
             Some(1),
+            None,
+            None,
             &mut out,
             &mut err,
             &mut stdin,
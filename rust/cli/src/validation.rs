@@ -11,6 +11,7 @@
 //! Validation functions return structured `Result` types or custom enums
 //! (like `ParseResult`) to provide clear error messages to users.
 
+use axiomind_engine::variant::GameVariant;
 use std::collections::{HashMap, HashSet};
 
 /// Result type for parsing user input into player actions.
@@ -234,7 +235,12 @@ pub fn validate_dealing_meta(
             ));
         }
     }
-    let rounds = 2; // Texas Hold'em: two hole cards per player
+    // One round per hole card: two in Texas Hold'em, four in Omaha
+    let rounds = meta
+        .get("variant")
+        .and_then(|v| serde_json::from_value::<GameVariant>(v.clone()).ok())
+        .unwrap_or_default()
+        .hole_cards();
     if let Some(seq_val) = meta.get("deal_sequence") {
         let seq = seq_val.as_array().ok_or_else(|| {
             format!(
@@ -322,7 +328,7 @@ pub fn validate_dealing_meta(
         }
         let player_count_i64 = player_count as i64;
         if player_count_i64 >= 2 {
-            let hole_cards = player_count_i64 * 2;
+            let hole_cards = player_count_i64 * rounds as i64;
             let expected = vec![
                 hole_cards + 1,
                 hole_cards + 1 + 3 + 1,
@@ -18,7 +18,7 @@ fn test_commands_enum_synchronization() {
     // This should match the Commands enum variants that are fully implemented
     let implemented_commands: HashSet<&str> = [
         "play", "replay", "sim", "eval", "stats", "verify", "deal", "bench", "rng", "cfg",
-        "doctor", "export", "dataset",
+        "doctor", "export", "dataset", "equity",
     ]
     .iter()
     .copied()
@@ -11,6 +11,32 @@ fn deal_prints_board_and_holes() {
     assert!(s.contains("Board:"));
 }
 
+#[test]
+fn deal_pins_requested_cards() {
+    let mut out: Vec<u8> = Vec::new();
+    let mut err: Vec<u8> = Vec::new();
+    let code = run(
+        [
+            "axiomind",
+            "deal",
+            "--seed",
+            "3",
+            "--cards",
+            "AsKs ???? | ??????Qs",
+        ],
+        &mut out,
+        &mut err,
+    );
+    assert_eq!(code, 0);
+    let s = String::from_utf8_lossy(&out);
+    assert!(s.contains("Hole P1: AceSpades KingSpades"));
+    assert!(s.contains("QueenSpades"));
+
+    let mut out: Vec<u8> = Vec::new();
+    let code = run(["axiomind", "deal", "--cards", "AsAs"], &mut out, &mut err);
+    assert_ne!(code, 0);
+}
+
 #[test]
 fn rng_prints_sample() {
     let mut out: Vec<u8> = Vec::new();
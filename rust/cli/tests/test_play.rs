@@ -64,7 +64,7 @@ fn ai_mode_displays_placeholder_warning() {
 }
 
 #[test]
-fn ai_mode_tags_output_with_demo_mode() {
+fn ai_mode_prints_each_ai_action() {
     let mut out: Vec<u8> = Vec::new();
     let mut err: Vec<u8> = Vec::new();
     let code = run(
@@ -77,10 +77,11 @@ fn ai_mode_tags_output_with_demo_mode() {
     assert_eq!(code, 0);
     let stdout = String::from_utf8_lossy(&out);
     assert!(
-        stdout.contains("ai: check [DEMO MODE]"),
-        "Expected [DEMO MODE] tag in AI action output, got: {}",
+        stdout.lines().any(|l| l.starts_with("AI ")) && stdout.contains("Hand complete."),
+        "Expected the AI actions of a completed hand, got: {}",
         stdout
     );
+    assert!(!stdout.contains("[DEMO MODE]"), "{}", stdout);
 }
 
 #[test]
@@ -1,4 +1,5 @@
 use axiomind_cli::run;
+use axiomind_engine::logger::HandRecord;
 
 #[test]
 fn ai_session_counts_and_level_printed() {
@@ -16,3 +17,53 @@ fn ai_session_counts_and_level_printed() {
     assert!(stdout.contains("Level: 2"));
     assert!(stdout.contains("Session hands=3"));
 }
+
+#[test]
+fn ai_session_writes_v2_history_that_verifies() {
+    let path = std::env::temp_dir().join(format!("play_session_{}.jsonl", std::process::id()));
+    let path_str = path.to_string_lossy().into_owned();
+    let mut out: Vec<u8> = Vec::new();
+    let mut err: Vec<u8> = Vec::new();
+    let code = run(
+        [
+            "axiomind", "play", "--vs", "ai", "--hands", "4", "--seed", "5", "--output", &path_str,
+        ],
+        &mut out,
+        &mut err,
+    );
+    assert_eq!(code, 0, "stderr={}", String::from_utf8_lossy(&err));
+
+    let records: Vec<HandRecord> = std::fs::read_to_string(&path)
+        .unwrap()
+        .lines()
+        .map(|l| serde_json::from_str(l).expect("record"))
+        .collect();
+    assert_eq!(records.len(), 4);
+    for (i, rec) in records.iter().enumerate() {
+        assert_eq!(rec.schema_version, 2);
+        assert_eq!(rec.players.len(), 2);
+        assert!(rec.end_reason.is_some());
+        // Buttons alternate and stacks carry over between hands
+        assert_eq!(
+            rec.button.as_deref(),
+            Some(if i % 2 == 0 { "p0" } else { "p1" })
+        );
+        if let Some(prev) = i.checked_sub(1).map(|j| &records[j]) {
+            for (seat, player) in rec.players.iter().enumerate() {
+                let prev_end =
+                    i64::from(prev.players[seat].stack_start) + prev.net_result[&player.id];
+                assert_eq!(i64::from(player.stack_start), prev_end);
+            }
+        }
+    }
+
+    out.clear();
+    err.clear();
+    let code = run(
+        ["axiomind", "verify", "--input", &path_str],
+        &mut out,
+        &mut err,
+    );
+    assert_eq!(code, 0, "verify stderr={}", String::from_utf8_lossy(&err));
+    let _ = std::fs::remove_file(&path);
+}
@@ -25,6 +25,7 @@ fn replay_displays_single_hand() {
             player_id: 0,
             street: Street::Preflop,
             action: A::Bet(50),
+            amount: None,
         }],
         board: vec![Card {
             suit: S::Clubs,
@@ -34,6 +35,7 @@ fn replay_displays_single_hand() {
         ts: None,
         meta: None,
         showdown: None,
+        ..Default::default()
     };
     fs::write(&path, serde_json::to_string(&rec).unwrap() + "\n").unwrap();
 
@@ -70,6 +72,7 @@ fn replay_displays_speed_parameter_warning() {
             player_id: 0,
             street: Street::Preflop,
             action: A::Bet(50),
+            amount: None,
         }],
         board: vec![Card {
             suit: S::Clubs,
@@ -79,6 +82,7 @@ fn replay_displays_speed_parameter_warning() {
         ts: None,
         meta: None,
         showdown: None,
+        ..Default::default()
     };
     fs::write(&path, serde_json::to_string(&rec).unwrap() + "\n").unwrap();
 
@@ -116,21 +120,25 @@ fn replay_displays_board_cards_by_street() {
                 player_id: 0,
                 street: Street::Preflop,
                 action: A::Bet(100),
+                amount: None,
             },
             ActionRecord {
                 player_id: 1,
                 street: Street::Preflop,
                 action: A::Call,
+                amount: None,
             },
             ActionRecord {
                 player_id: 0,
                 street: Street::Flop,
                 action: A::Bet(200),
+                amount: None,
             },
             ActionRecord {
                 player_id: 1,
                 street: Street::Flop,
                 action: A::Call,
+                amount: None,
             },
         ],
         board: vec![
@@ -151,6 +159,7 @@ fn replay_displays_board_cards_by_street() {
         ts: None,
         meta: None,
         showdown: None,
+        ..Default::default()
     };
     fs::write(&path, serde_json::to_string(&rec).unwrap() + "\n").unwrap();
 
@@ -177,3 +186,85 @@ fn replay_displays_board_cards_by_street() {
     assert!(stdout.contains("Player 1: call"), "Should show call");
     assert!(stdout.contains("Player 0: bet 200"), "Should show flop bet");
 }
+
+#[test]
+fn replay_uses_recorded_blind_structure() {
+    use axiomind_engine::engine::Engine;
+    use axiomind_engine::rules::BlindStructure;
+
+    let structure = BlindStructure::from_toml_str(
+        "big_blind_ante = true\n[[levels]]\nsb = 25\nbb = 50\nante = 50\n",
+    )
+    .unwrap();
+    let eng = Engine::new(Some(5), 1).with_blind_structure(structure);
+
+    let path = tmp_jsonl("replay_blind_structure");
+    let rec = HandRecord {
+        hand_id: "20250102-000001".into(),
+        seed: Some(5),
+        actions: vec![ActionRecord {
+            player_id: 0,
+            street: Street::Preflop,
+            action: A::Fold,
+            amount: None,
+        }],
+        board: vec![],
+        result: Some("p1".into()),
+        ts: None,
+        meta: Some(eng.record_meta()),
+        showdown: None,
+        ..Default::default()
+    };
+    fs::write(&path, serde_json::to_string(&rec).unwrap() + "\n").unwrap();
+
+    let mut out: Vec<u8> = Vec::new();
+    let mut err: Vec<u8> = Vec::new();
+    let code = run(
+        [
+            "axiomind",
+            "replay",
+            "--input",
+            path.to_string_lossy().as_ref(),
+        ],
+        &mut out,
+        &mut err,
+    );
+    assert_eq!(code, 0);
+    let stdout = String::from_utf8_lossy(&out);
+    assert!(stdout.contains("Blinds: SB=25 BB=50"), "{}", stdout);
+    assert!(stdout.contains("Ante: 50 (big blind)"), "{}", stdout);
+}
+
+#[test]
+fn replay_shows_every_runout() {
+    use axiomind_cli::formatters::format_board;
+    use axiomind_engine::engine::Engine;
+
+    let mut eng = Engine::new(Some(3), 1).with_runouts(2).unwrap();
+    eng.deal_hand().unwrap();
+    eng.apply_action(0, A::AllIn).unwrap();
+    eng.apply_action(1, A::Call).unwrap();
+    let rec = eng.hand_record("19700101-000001", Some(3));
+    let boards = eng.runout_boards().to_vec();
+
+    let path = tmp_jsonl("replay_runouts");
+    fs::write(&path, serde_json::to_string(&rec).unwrap() + "\n").unwrap();
+    let mut out: Vec<u8> = Vec::new();
+    let mut err: Vec<u8> = Vec::new();
+    let code = run(
+        [
+            "axiomind",
+            "replay",
+            "--input",
+            path.to_string_lossy().as_ref(),
+        ],
+        &mut out,
+        &mut err,
+    );
+    assert_eq!(code, 0);
+    let stdout = String::from_utf8_lossy(&out);
+    for (run, board) in boards.iter().enumerate() {
+        let line = format!("Run {}: {}", run + 1, format_board(board));
+        assert!(stdout.contains(&line), "missing {line:?} in {stdout}");
+    }
+}
@@ -39,3 +39,403 @@ fn sim_runs_n_hands_and_writes_file() {
     let lines = contents.lines().filter(|l| !l.trim().is_empty()).count();
     assert_eq!(lines, 5);
 }
+
+#[test]
+fn sim_records_the_betting_structure_and_verifies() {
+    let path = out_path("sim_fixed_limit");
+    let _ = fs::remove_file(&path);
+    let mut out: Vec<u8> = Vec::new();
+    let mut err: Vec<u8> = Vec::new();
+    let code = run(
+        [
+            "axiomind",
+            "sim",
+            "--hands",
+            "20",
+            "--seed",
+            "3",
+            "--betting",
+            "fl:100/200",
+            "--output",
+            path.to_string_lossy().as_ref(),
+        ],
+        &mut out,
+        &mut err,
+    );
+    assert_eq!(code, 0);
+    let contents = fs::read_to_string(&path).unwrap();
+    for line in contents.lines() {
+        let record: serde_json::Value = serde_json::from_str(line).unwrap();
+        let structure = &record["meta"]["betting_structure"];
+        assert_eq!(structure["kind"], "fixed_limit");
+        assert_eq!(structure["raise_cap"], 4);
+    }
+
+    let mut out: Vec<u8> = Vec::new();
+    let code = run(
+        [
+            "axiomind",
+            "verify",
+            "--input",
+            path.to_string_lossy().as_ref(),
+        ],
+        &mut out,
+        &mut err,
+    );
+    assert_eq!(code, 0, "{}", String::from_utf8_lossy(&err));
+    assert!(String::from_utf8_lossy(&out).contains("Verify: OK (hands=20)"));
+}
+
+#[test]
+fn sim_deals_pot_limit_omaha_and_verifies() {
+    let path = out_path("sim_omaha");
+    let _ = fs::remove_file(&path);
+    let mut out: Vec<u8> = Vec::new();
+    let mut err: Vec<u8> = Vec::new();
+    let code = run(
+        [
+            "axiomind",
+            "sim",
+            "--hands",
+            "20",
+            "--seed",
+            "5",
+            "--variant",
+            "omaha",
+            "--output",
+            path.to_string_lossy().as_ref(),
+        ],
+        &mut out,
+        &mut err,
+    );
+    assert_eq!(code, 0);
+    let contents = fs::read_to_string(&path).unwrap();
+    for line in contents.lines() {
+        let record: serde_json::Value = serde_json::from_str(line).unwrap();
+        assert_eq!(record["meta"]["variant"], "omaha");
+        assert_eq!(record["meta"]["betting_structure"]["kind"], "pot_limit");
+        for player in record["players"].as_array().unwrap() {
+            assert_eq!(player["hole_cards"].as_array().unwrap().len(), 4);
+        }
+    }
+
+    let mut out: Vec<u8> = Vec::new();
+    let code = run(
+        [
+            "axiomind",
+            "verify",
+            "--input",
+            path.to_string_lossy().as_ref(),
+        ],
+        &mut out,
+        &mut err,
+    );
+    assert_eq!(code, 0, "{}", String::from_utf8_lossy(&err));
+    assert!(String::from_utf8_lossy(&out).contains("Verify: OK (hands=20)"));
+}
+
+#[test]
+fn sim_deals_short_deck_and_verifies() {
+    let path = out_path("sim_short_deck");
+    let _ = fs::remove_file(&path);
+    let mut out: Vec<u8> = Vec::new();
+    let mut err: Vec<u8> = Vec::new();
+    let code = run(
+        [
+            "axiomind",
+            "sim",
+            "--hands",
+            "20",
+            "--seed",
+            "5",
+            "--variant",
+            "6+",
+            "--output",
+            path.to_string_lossy().as_ref(),
+        ],
+        &mut out,
+        &mut err,
+    );
+    assert_eq!(code, 0);
+    let contents = fs::read_to_string(&path).unwrap();
+    for line in contents.lines() {
+        let record: serde_json::Value = serde_json::from_str(line).unwrap();
+        assert_eq!(record["meta"]["variant"], "short_deck");
+        assert_eq!(record["meta"]["betting_structure"]["kind"], "no_limit");
+        let board = record["board"].as_array().unwrap();
+        let holes = record["players"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .flat_map(|p| p["hole_cards"].as_array().unwrap());
+        for card in board.iter().chain(holes) {
+            let rank = card["rank"].as_str().unwrap();
+            assert!(!["Two", "Three", "Four", "Five"].contains(&rank), "{card}");
+        }
+    }
+
+    let mut out: Vec<u8> = Vec::new();
+    let code = run(
+        [
+            "axiomind",
+            "verify",
+            "--input",
+            path.to_string_lossy().as_ref(),
+        ],
+        &mut out,
+        &mut err,
+    );
+    assert_eq!(code, 0, "{}", String::from_utf8_lossy(&err));
+    assert!(String::from_utf8_lossy(&out).contains("Verify: OK (hands=20)"));
+}
+
+#[test]
+fn sim_rejects_an_invalid_runout_count() {
+    let mut out: Vec<u8> = Vec::new();
+    let mut err: Vec<u8> = Vec::new();
+    let code = run(
+        ["axiomind", "sim", "--hands", "1", "--runouts", "5"],
+        &mut out,
+        &mut err,
+    );
+    assert_eq!(code, 2);
+    assert!(String::from_utf8_lossy(&err).contains("Invalid runout count: 5"));
+
+    let path = out_path("sim_runouts");
+    let _ = fs::remove_file(&path);
+    let code = run(
+        [
+            "axiomind",
+            "sim",
+            "--hands",
+            "5",
+            "--seed",
+            "3",
+            "--runouts",
+            "2",
+            "--output",
+            path.to_string_lossy().as_ref(),
+        ],
+        &mut out,
+        &mut err,
+    );
+    assert_eq!(code, 0, "{}", String::from_utf8_lossy(&err));
+    assert_eq!(fs::read_to_string(&path).unwrap().lines().count(), 5);
+}
+
+#[test]
+fn sim_commits_to_every_deck_when_asked() {
+    let path = out_path("sim_commit_reveal");
+    let _ = fs::remove_file(&path);
+    let mut out: Vec<u8> = Vec::new();
+    let mut err: Vec<u8> = Vec::new();
+    let code = run(
+        [
+            "axiomind",
+            "sim",
+            "--hands",
+            "3",
+            "--seed",
+            "5",
+            "--commit-reveal",
+            "--output",
+            path.to_string_lossy().as_ref(),
+        ],
+        &mut out,
+        &mut err,
+    );
+    assert_eq!(code, 0, "{}", String::from_utf8_lossy(&err));
+    for line in fs::read_to_string(&path).unwrap().lines() {
+        let record: axiomind_engine::logger::HandRecord = serde_json::from_str(line).unwrap();
+        let fairness = &record.meta.as_ref().unwrap()["fairness"];
+        let reveal: axiomind_engine::deck::ShuffleReveal =
+            serde_json::from_value(fairness["reveal"].clone()).unwrap();
+        reveal
+            .verify(fairness["commitment"].as_str().unwrap())
+            .unwrap();
+        reveal.check_deal(&record).unwrap();
+        assert!(record.seed.is_some());
+    }
+    let _ = fs::remove_file(&path);
+}
+
+#[test]
+fn sim_deals_from_a_recorded_shuffle_file() {
+    use axiomind_engine::cards::{format_cards, full_deck};
+
+    let shuffles = out_path("sim_shuffles").with_extension("txt");
+    let mut reversed = full_deck();
+    reversed.reverse();
+    fs::write(
+        &shuffles,
+        format!(
+            "# reversed, then standard\n{}\n{}\n",
+            format_cards(&reversed),
+            format_cards(&full_deck())
+        ),
+    )
+    .unwrap();
+    let path = out_path("sim_recorded");
+    let _ = fs::remove_file(&path);
+    let mut out: Vec<u8> = Vec::new();
+    let mut err: Vec<u8> = Vec::new();
+    let code = run(
+        [
+            "axiomind",
+            "sim",
+            "--hands",
+            "3",
+            "--shuffle",
+            shuffles.to_string_lossy().as_ref(),
+            "--output",
+            path.to_string_lossy().as_ref(),
+        ],
+        &mut out,
+        &mut err,
+    );
+    assert_eq!(code, 0, "{}", String::from_utf8_lossy(&err));
+    let first_cards: Vec<String> = fs::read_to_string(&path)
+        .unwrap()
+        .lines()
+        .map(|line| {
+            let record: axiomind_engine::logger::HandRecord = serde_json::from_str(line).unwrap();
+            assert_eq!(record.seed, None);
+            assert_eq!(record.meta.as_ref().unwrap()["shuffle_source"], "recorded");
+            record.players[0].hole_cards.as_ref().unwrap()[0].to_string()
+        })
+        .collect();
+    // Orders are dealt one per hand and start over after the last
+    assert_eq!(first_cards, ["As", "2c", "As"]);
+
+    let _ = fs::remove_file(&shuffles);
+    let _ = fs::remove_file(&path);
+}
+
+#[test]
+fn sim_rejects_unknown_shuffle_sources() {
+    let mut out: Vec<u8> = Vec::new();
+    let mut err: Vec<u8> = Vec::new();
+    let code = run(
+        ["axiomind", "sim", "--hands", "1", "--shuffle", "mersenne"],
+        &mut out,
+        &mut err,
+    );
+    assert_eq!(code, 2);
+    assert!(String::from_utf8_lossy(&err).contains("Invalid shuffle source"));
+
+    let bad = out_path("sim_bad_shuffles").with_extension("txt");
+    fs::write(&bad, "AhKd\n").unwrap();
+    let mut err: Vec<u8> = Vec::new();
+    let code = run(
+        [
+            "axiomind",
+            "sim",
+            "--hands",
+            "1",
+            "--shuffle",
+            bad.to_string_lossy().as_ref(),
+        ],
+        &mut out,
+        &mut err,
+    );
+    assert_eq!(code, 2);
+    assert!(String::from_utf8_lossy(&err).contains("line 1"));
+    let _ = fs::remove_file(&bad);
+
+    // The OS source needs no file
+    let code = run(
+        ["axiomind", "sim", "--hands", "2", "--shuffle", "os"],
+        &mut out,
+        &mut err,
+    );
+    assert_eq!(code, 0);
+}
+
+#[test]
+fn sim_derives_every_hand_seed_from_the_root() {
+    use axiomind_engine::seed::{SeedPath, SeedTree};
+
+    let path = out_path("sim_seed_tree");
+    let _ = fs::remove_file(&path);
+    let mut out: Vec<u8> = Vec::new();
+    let mut err: Vec<u8> = Vec::new();
+    let code = run(
+        [
+            "axiomind",
+            "sim",
+            "--hands",
+            "4",
+            "--seed",
+            "11",
+            "--output",
+            path.to_string_lossy().as_ref(),
+        ],
+        &mut out,
+        &mut err,
+    );
+    assert_eq!(code, 0, "{}", String::from_utf8_lossy(&err));
+    let tree = SeedTree::new(11);
+    let mut lines: Vec<serde_json::Value> = fs::read_to_string(&path)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+    for (i, record) in lines.iter().enumerate() {
+        let derivation = &record["meta"]["seed_derivation"];
+        assert_eq!(derivation["scheme"], "splitmix64/v1");
+        assert_eq!(derivation["root"], 11);
+        assert_eq!(derivation["hand"], i);
+        assert_eq!(record["seed"], tree.hand_seed(SeedPath::hand(i as u64)));
+    }
+
+    let verify = |records: &[serde_json::Value]| {
+        let text: String = records.iter().map(|r| format!("{}\n", r)).collect();
+        fs::write(&path, text).unwrap();
+        let mut out: Vec<u8> = Vec::new();
+        let mut err: Vec<u8> = Vec::new();
+        let code = run(
+            [
+                "axiomind",
+                "verify",
+                "--input",
+                path.to_string_lossy().as_ref(),
+            ],
+            &mut out,
+            &mut err,
+        );
+        (code, String::from_utf8_lossy(&err).into_owned())
+    };
+    let (code, stderr) = verify(&lines);
+    assert_eq!(code, 0, "{stderr}");
+
+    // Replay shows where each hand sits in the tree
+    fs::write(&path, format!("{}\n", lines[2])).unwrap();
+    let mut out: Vec<u8> = Vec::new();
+    let mut err: Vec<u8> = Vec::new();
+    let code = run(
+        [
+            "axiomind",
+            "replay",
+            "--input",
+            path.to_string_lossy().as_ref(),
+        ],
+        &mut out,
+        &mut err,
+    );
+    assert_eq!(code, 0, "{}", String::from_utf8_lossy(&err));
+    assert!(
+        String::from_utf8_lossy(&out)
+            .contains("Seed tree: root 11, match 0, hand 2 (splitmix64/v1)")
+    );
+
+    // A seed that does not follow from the recorded derivation is caught
+    lines[1]["seed"] = serde_json::json!(12);
+    let (code, stderr) = verify(&lines);
+    assert_ne!(code, 0);
+    assert!(
+        stderr.contains("Hand 2: Seed 12 does not match"),
+        "{stderr}"
+    );
+
+    let _ = fs::remove_file(&path);
+}
@@ -25,6 +25,7 @@ fn stats_outputs_summary_json() {
             player_id: 0,
             street: Street::Preflop,
             action: A::Bet(10),
+            amount: None,
         }],
         board: vec![Card {
             suit: S::Clubs,
@@ -34,6 +35,7 @@ fn stats_outputs_summary_json() {
         ts: None,
         meta: None,
         showdown: None,
+        ..Default::default()
     };
     let r2 = HandRecord {
         hand_id: "20250102-000002".into(),
@@ -29,6 +29,7 @@ fn verify_checks_records() {
             player_id: 0,
             street: Street::River,
             action: A::Check,
+            amount: None,
         }],
         board: vec![
             Card {
@@ -56,6 +57,7 @@ fn verify_checks_records() {
         ts: None,
         meta: None,
         showdown: None,
+        ..Default::default()
     };
     let mut s = String::new();
     s.push_str(&serde_json::to_string(&rec).unwrap());
@@ -86,7 +88,10 @@ fn doctor_reports_ok() {
         .lock()
         .expect("doctor lock");
 
-    let base = PathBuf::from("target").join(format!("doctor_ok_{}", std::process::id()));
+    let base = std::env::current_dir()
+        .unwrap()
+        .join("target")
+        .join(format!("doctor_ok_{}", std::process::id()));
     let sqlite_dir = base.join("sqlite");
     let data_dir = base.join("data");
     fs::create_dir_all(&sqlite_dir).unwrap();
@@ -88,3 +88,284 @@ fn verify_invalid_hand_id() {
     let stderr = String::from_utf8_lossy(&err);
     assert!(stderr.to_lowercase().contains("hand_id"));
 }
+
+#[test]
+fn verify_checks_bets_against_the_recorded_betting_structure() {
+    use axiomind_engine::engine::Engine;
+    use axiomind_engine::player::PlayerAction;
+    use axiomind_engine::rules::BettingStructure;
+
+    // A raise to 1,050 is fine under no-limit, but pot-limit stops at 300
+    let mut eng = Engine::new(Some(1), 1);
+    eng.deal_hand().unwrap();
+    eng.apply_action(0, PlayerAction::Raise(950)).unwrap();
+    eng.apply_action(1, PlayerAction::Fold).unwrap();
+    let mut record = serde_json::to_value(eng.hand_record("19700101-000001", Some(1))).unwrap();
+
+    let verify = |record: &serde_json::Value, name: &str| {
+        let path = tmp_file(name);
+        fs::write(&path, format!("{}\n", record)).unwrap();
+        let mut out = Vec::new();
+        let mut err = Vec::new();
+        let code = run(
+            [
+                "axiomind",
+                "verify",
+                "--input",
+                path.to_string_lossy().as_ref(),
+            ],
+            &mut out,
+            &mut err,
+        );
+        (code, String::from_utf8_lossy(&err).into_owned())
+    };
+    assert_eq!(verify(&record, "structure_nl").0, 0);
+
+    record["meta"]["betting_structure"] = serde_json::to_value(BettingStructure::PotLimit).unwrap();
+    let (code, stderr) = verify(&record, "structure_pl");
+    assert_ne!(code, 0);
+    assert!(
+        stderr.contains("breaks the pl betting structure"),
+        "{stderr}"
+    );
+    assert!(stderr.contains("exceeds the limit of 200"), "{stderr}");
+}
+
+#[test]
+fn sim_rejects_unknown_betting_structure() {
+    let mut out = Vec::new();
+    let mut err = Vec::new();
+    let code = run(
+        ["axiomind", "sim", "--hands", "1", "--betting", "spread"],
+        &mut out,
+        &mut err,
+    );
+    assert_ne!(code, 0);
+    let stderr = String::from_utf8_lossy(&err);
+    assert!(stderr.contains("Invalid betting structure"));
+}
+
+#[test]
+fn verify_checks_hole_cards_against_the_recorded_variant() {
+    use axiomind_engine::engine::Engine;
+    use axiomind_engine::player::PlayerAction;
+
+    let mut eng = Engine::new(Some(1), 1);
+    eng.deal_hand().unwrap();
+    eng.apply_action(0, PlayerAction::Fold).unwrap();
+    let mut record = serde_json::to_value(eng.hand_record("19700101-000001", Some(1))).unwrap();
+    record["meta"]["variant"] = serde_json::json!("omaha");
+
+    let path = tmp_file("variant_omaha");
+    fs::write(&path, format!("{}\n", record)).unwrap();
+    let mut out = Vec::new();
+    let mut err = Vec::new();
+    let code = run(
+        [
+            "axiomind",
+            "verify",
+            "--input",
+            path.to_string_lossy().as_ref(),
+        ],
+        &mut out,
+        &mut err,
+    );
+    assert_ne!(code, 0);
+    let stderr = String::from_utf8_lossy(&err);
+    assert!(
+        stderr.contains("p0 has 2 hole cards, but omaha deals 4"),
+        "{stderr}"
+    );
+}
+
+#[test]
+fn verify_accepts_a_hand_run_out_after_an_all_in() {
+    use axiomind_engine::engine::Engine;
+    use axiomind_engine::player::PlayerAction;
+
+    let mut eng = Engine::new(Some(3), 1);
+    eng.deal_hand().unwrap();
+    eng.apply_action(0, PlayerAction::AllIn).unwrap();
+    eng.apply_action(1, PlayerAction::Call).unwrap();
+    let record = eng.hand_record("19700101-000001", Some(3));
+    assert_eq!(record.board.len(), 5);
+    assert_eq!(record.actions.len(), 2);
+
+    let path = tmp_file("all_in_runout");
+    fs::write(
+        &path,
+        format!("{}\n", serde_json::to_string(&record).unwrap()),
+    )
+    .unwrap();
+    let mut out = Vec::new();
+    let mut err = Vec::new();
+    let code = run(
+        [
+            "axiomind",
+            "verify",
+            "--input",
+            path.to_string_lossy().as_ref(),
+        ],
+        &mut out,
+        &mut err,
+    );
+    assert_eq!(code, 0, "{}", String::from_utf8_lossy(&err));
+    assert!(String::from_utf8_lossy(&out).contains("Verify: OK (hands=1)"));
+}
+
+#[test]
+fn verify_checks_every_runout_for_duplicate_cards() {
+    use axiomind_engine::engine::Engine;
+    use axiomind_engine::player::PlayerAction;
+
+    let mut eng = Engine::new(Some(3), 1).with_runouts(2).unwrap();
+    eng.deal_hand().unwrap();
+    eng.apply_action(0, PlayerAction::AllIn).unwrap();
+    eng.apply_action(1, PlayerAction::Call).unwrap();
+    let mut record = eng.hand_record("19700101-000001", Some(3));
+    assert_eq!(record.showdown.as_ref().unwrap().runouts.len(), 2);
+
+    let verify = |record: &axiomind_engine::logger::HandRecord, name: &str| {
+        let path = tmp_file(name);
+        fs::write(
+            &path,
+            format!("{}\n", serde_json::to_string(record).unwrap()),
+        )
+        .unwrap();
+        let mut out = Vec::new();
+        let mut err = Vec::new();
+        let code = run(
+            [
+                "axiomind",
+                "verify",
+                "--input",
+                path.to_string_lossy().as_ref(),
+            ],
+            &mut out,
+            &mut err,
+        );
+        (code, String::from_utf8_lossy(&err).into_owned())
+    };
+    let (code, stderr) = verify(&record, "run_twice");
+    assert_eq!(code, 0, "{stderr}");
+
+    // A second board repeating a hole card is caught
+    let hole = record.players[0].hole_cards.as_ref().unwrap()[0];
+    record.showdown.as_mut().unwrap().runouts[1][4] = hole;
+    let (code, stderr) = verify(&record, "run_twice_duplicate");
+    assert_ne!(code, 0);
+    assert!(stderr.contains("Duplicate card(s) detected"), "{stderr}");
+}
+
+#[test]
+fn verify_rejects_low_cards_in_a_short_deck_record() {
+    use axiomind_engine::engine::Engine;
+    use axiomind_engine::player::PlayerAction;
+    use axiomind_engine::variant::GameVariant;
+
+    let mut eng = Engine::new(Some(1), 1)
+        .with_variant(GameVariant::ShortDeck)
+        .unwrap();
+    eng.deal_hand().unwrap();
+    eng.apply_action(0, PlayerAction::Fold).unwrap();
+    let mut record = serde_json::to_value(eng.hand_record("19700101-000001", Some(1))).unwrap();
+    record["players"][0]["hole_cards"][0] = serde_json::json!({"suit": "Clubs", "rank": "Two"});
+
+    let path = tmp_file("variant_short_deck");
+    fs::write(&path, format!("{}\n", record)).unwrap();
+    let mut out = Vec::new();
+    let mut err = Vec::new();
+    let code = run(
+        [
+            "axiomind",
+            "verify",
+            "--input",
+            path.to_string_lossy().as_ref(),
+        ],
+        &mut out,
+        &mut err,
+    );
+    assert_ne!(code, 0);
+    let stderr = String::from_utf8_lossy(&err);
+    assert!(stderr.contains("2c is not in the short deck"), "{stderr}");
+}
+
+#[test]
+fn verify_fairness_checks_the_revealed_deck() {
+    use axiomind_engine::engine::Engine;
+    use axiomind_engine::player::PlayerAction;
+
+    let mut eng = Engine::new(Some(6), 1)
+        .with_runouts(2)
+        .unwrap()
+        .with_commit_reveal()
+        .unwrap();
+    eng.deal_hand().unwrap();
+    eng.apply_action(0, PlayerAction::AllIn).unwrap();
+    eng.apply_action(1, PlayerAction::Call).unwrap();
+    let record = serde_json::to_value(eng.hand_record("19700101-000001", Some(6))).unwrap();
+
+    let verify = |record: &serde_json::Value, name: &str| {
+        let path = tmp_file(name);
+        fs::write(&path, format!("{}\n", record)).unwrap();
+        let mut out = Vec::new();
+        let mut err = Vec::new();
+        let code = run(
+            [
+                "axiomind",
+                "verify",
+                "--input",
+                path.to_string_lossy().as_ref(),
+                "--fairness",
+            ],
+            &mut out,
+            &mut err,
+        );
+        (code, String::from_utf8_lossy(&err).into_owned())
+    };
+    let (code, stderr) = verify(&record, "fairness_ok");
+    assert_eq!(code, 0, "{stderr}");
+
+    // Hole cards handed to the other seat no longer follow the deck
+    let mut swapped = record.clone();
+    let first = swapped["players"][0]["hole_cards"][0].clone();
+    swapped["players"][0]["hole_cards"][0] = swapped["players"][1]["hole_cards"][0].clone();
+    swapped["players"][1]["hole_cards"][0] = first;
+    let (code, stderr) = verify(&swapped, "fairness_swapped");
+    assert_ne!(code, 0);
+    assert!(
+        stderr.contains("Shuffle check failed: p0 hole card 1"),
+        "{stderr}"
+    );
+
+    // A deck changed after the commitment is caught
+    let mut altered = record.clone();
+    let deck = altered["meta"]["fairness"]["reveal"]["deck"]
+        .as_array_mut()
+        .unwrap();
+    deck.swap(10, 11);
+    let (code, stderr) = verify(&altered, "fairness_altered");
+    assert_ne!(code, 0);
+    assert!(stderr.contains("do not match the commitment"), "{stderr}");
+
+    let mut unrevealed = record.clone();
+    unrevealed["meta"]["fairness"]
+        .as_object_mut()
+        .unwrap()
+        .remove("reveal");
+    let (code, stderr) = verify(&unrevealed, "fairness_unrevealed");
+    assert_ne!(code, 0);
+    assert!(stderr.contains("never revealed"), "{stderr}");
+
+    let mut uncommitted = record;
+    uncommitted["meta"]
+        .as_object_mut()
+        .unwrap()
+        .remove("fairness");
+    let (code, stderr) = verify(&uncommitted, "fairness_missing");
+    assert_ne!(code, 0);
+    assert!(
+        stderr.contains("No shuffle commitment recorded"),
+        "{stderr}"
+    );
+}
@@ -1,4 +1,7 @@
+use crate::errors::CardParseError;
 use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
 
 /// Represents one of the four suits in a standard 52-card deck.
 /// Used as a component of [`Card`] to fully define a playing card.
@@ -47,6 +50,7 @@ pub enum Rank {
 }
 
 impl Rank {
+    #[deprecated(note = "maps invalid values to Ace; use `Rank::try_from` instead")]
     pub fn from_u8(v: u8) -> Rank {
         match v {
             2 => Rank::Two,
@@ -76,6 +80,251 @@ pub struct Card {
     pub rank: Rank,
 }
 
+impl TryFrom<u8> for Rank {
+    type Error = CardParseError;
+
+    /// Convert a numeric rank (2 through 14, Ace high) into a [`Rank`].
+    fn try_from(v: u8) -> Result<Self, Self::Error> {
+        all_ranks()
+            .into_iter()
+            .find(|r| *r as u8 == v)
+            .ok_or(CardParseError::InvalidRankValue(v))
+    }
+}
+
+/// Formats as the lowercase suit letter: `c`, `d`, `h` or `s`.
+impl fmt::Display for Suit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Suit::Clubs => "c",
+            Suit::Diamonds => "d",
+            Suit::Hearts => "h",
+            Suit::Spades => "s",
+        };
+        f.write_str(c)
+    }
+}
+
+/// Parses a suit letter in either case, or one of the symbols ♣ ♦ ♥ ♠.
+impl FromStr for Suit {
+    type Err = CardParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "c" | "C" | "♣" => Ok(Suit::Clubs),
+            "d" | "D" | "♦" => Ok(Suit::Diamonds),
+            "h" | "H" | "♥" => Ok(Suit::Hearts),
+            "s" | "S" | "♠" => Ok(Suit::Spades),
+            _ => Err(CardParseError::InvalidSuit(s.to_string())),
+        }
+    }
+}
+
+/// Formats as a single character: `2`-`9`, `T`, `J`, `Q`, `K` or `A`.
+impl fmt::Display for Rank {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Rank::Two => "2",
+            Rank::Three => "3",
+            Rank::Four => "4",
+            Rank::Five => "5",
+            Rank::Six => "6",
+            Rank::Seven => "7",
+            Rank::Eight => "8",
+            Rank::Nine => "9",
+            Rank::Ten => "T",
+            Rank::Jack => "J",
+            Rank::Queen => "Q",
+            Rank::King => "K",
+            Rank::Ace => "A",
+        };
+        f.write_str(c)
+    }
+}
+
+/// Parses a rank character in either case; `10` is accepted for Ten.
+impl FromStr for Rank {
+    type Err = CardParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "2" => Ok(Rank::Two),
+            "3" => Ok(Rank::Three),
+            "4" => Ok(Rank::Four),
+            "5" => Ok(Rank::Five),
+            "6" => Ok(Rank::Six),
+            "7" => Ok(Rank::Seven),
+            "8" => Ok(Rank::Eight),
+            "9" => Ok(Rank::Nine),
+            "T" | "t" | "10" => Ok(Rank::Ten),
+            "J" | "j" => Ok(Rank::Jack),
+            "Q" | "q" => Ok(Rank::Queen),
+            "K" | "k" => Ok(Rank::King),
+            "A" | "a" => Ok(Rank::Ace),
+            _ => Err(CardParseError::InvalidRank(s.to_string())),
+        }
+    }
+}
+
+/// Formats in standard notation, rank then suit: `Ah`, `Td`, `2c`.
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.rank, self.suit)
+    }
+}
+
+/// Parses standard notation such as `Ah`, `td`, `10c` or `K♠`.
+impl FromStr for Card {
+    type Err = CardParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let Some((split, _)) = s.char_indices().last().filter(|(i, _)| *i > 0) else {
+            return Err(CardParseError::InvalidCard(s.to_string()));
+        };
+        let (rank, suit) = s.split_at(split);
+        Ok(Card {
+            rank: rank.parse()?,
+            suit: suit.parse()?,
+        })
+    }
+}
+
+/// Parse several cards written back to back (`AhKd7c`) or separated by
+/// whitespace or commas (`Ah Kd, 7c`).
+///
+/// Fails on the first malformed card, or if the same card appears twice.
+///
+/// # Example
+///
+/// ```
+/// use axiomind_engine::cards::{parse_cards, Card, Rank, Suit};
+///
+/// let flop = parse_cards("AhKd7c").unwrap();
+/// assert_eq!(flop[0], Card { rank: Rank::Ace, suit: Suit::Hearts });
+/// assert_eq!(flop.len(), 3);
+/// assert!(parse_cards("Ah Ah").is_err());
+/// ```
+pub fn parse_cards(s: &str) -> Result<Vec<Card>, CardParseError> {
+    let mut chars = s
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != ',')
+        .peekable();
+    let mut cards: Vec<Card> = Vec::new();
+    while let Some(first) = chars.next() {
+        let mut token = String::from(first);
+        if first == '1' && chars.peek() == Some(&'0') {
+            token.extend(chars.next());
+        }
+        let Some(suit) = chars.next() else {
+            return Err(CardParseError::InvalidCard(token));
+        };
+        token.push(suit);
+        let card: Card = token.parse()?;
+        if cards.contains(&card) {
+            return Err(CardParseError::DuplicateCard(card));
+        }
+        cards.push(card);
+    }
+    Ok(cards)
+}
+
+/// Format cards back to back in standard notation, e.g. `AhKd7c`.
+pub fn format_cards(cards: &[Card]) -> String {
+    cards.iter().map(Card::to_string).collect()
+}
+
+/// Opt-in compact serde representation of cards as notation strings.
+///
+/// Use with `#[serde(with = "...")]` on a field. Serialization writes `"Ah"`;
+/// deserialization accepts either `"Ah"` or the default
+/// `{"suit":"Hearts","rank":"Ace"}` form, so existing data still loads.
+///
+/// ```
+/// use axiomind_engine::cards::Card;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Board {
+///     #[serde(with = "axiomind_engine::cards::compact::vec")]
+///     cards: Vec<Card>,
+/// }
+///
+/// let board: Board = serde_json::from_str(r#"{"cards":["Ah","Kd"]}"#).unwrap();
+/// assert_eq!(serde_json::to_string(&board).unwrap(), r#"{"cards":["Ah","Kd"]}"#);
+/// ```
+pub mod compact {
+    use super::Card;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum CardRepr {
+        Notation(String),
+        Verbose(Card),
+    }
+
+    impl CardRepr {
+        fn into_card<E: serde::de::Error>(self) -> Result<Card, E> {
+            match self {
+                CardRepr::Notation(s) => s.parse().map_err(E::custom),
+                CardRepr::Verbose(card) => Ok(card),
+            }
+        }
+    }
+
+    pub fn serialize<S: Serializer>(card: &Card, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(card)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Card, D::Error> {
+        CardRepr::deserialize(deserializer)?.into_card()
+    }
+
+    /// Compact form for `Vec<Card>` fields.
+    pub mod vec {
+        use super::{Card, CardRepr};
+        use serde::{Deserialize, Deserializer, Serializer};
+
+        pub fn serialize<S: Serializer>(cards: &[Card], serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_seq(cards.iter().map(Card::to_string))
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Vec<Card>, D::Error> {
+            Vec::<CardRepr>::deserialize(deserializer)?
+                .into_iter()
+                .map(CardRepr::into_card)
+                .collect()
+        }
+    }
+
+    /// Compact form for `Option<Vec<Card>>` fields.
+    pub mod option_vec {
+        use super::{Card, CardRepr};
+        use serde::{Deserialize, Deserializer, Serializer};
+
+        pub fn serialize<S: Serializer>(
+            cards: &Option<Vec<Card>>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            match cards {
+                Some(cards) => super::vec::serialize(cards, serializer),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<Vec<Card>>, D::Error> {
+            Option::<Vec<CardRepr>>::deserialize(deserializer)?
+                .map(|cards| cards.into_iter().map(CardRepr::into_card).collect())
+                .transpose()
+        }
+    }
+}
+
 pub fn all_suits() -> [Suit; 4] {
     [Suit::Clubs, Suit::Diamonds, Suit::Hearts, Suit::Spades]
 }
@@ -107,3 +356,11 @@ pub fn full_deck() -> Vec<Card> {
     }
     v
 }
+
+/// The 36-card short deck: the [`full_deck`] without Twos through Fives.
+pub fn short_deck() -> Vec<Card> {
+    full_deck()
+        .into_iter()
+        .filter(|c| c.rank >= Rank::Six)
+        .collect()
+}
@@ -1,10 +1,19 @@
-use rand::SeedableRng;
+use std::str::FromStr;
+
+use rand::rngs::OsRng;
 use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng, TryRngCore};
 use rand_chacha::ChaCha20Rng;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 
-use crate::cards::{Card, full_deck};
+use crate::cards::{Card, Rank, compact, format_cards, full_deck, parse_cards, short_deck};
+use crate::errors::GameError;
+use crate::logger::HandRecord;
+use crate::variant::GameVariant;
 
-/// Manages a standard 52-card deck with deterministic shuffling using seeded RNG.
+/// Manages a standard 52-card deck (or the 36-card short deck) with
+/// deterministic shuffling using seeded RNG.
 /// Provides card dealing, burning, and shuffling operations for poker hands.
 ///
 /// # Examples
@@ -28,12 +37,158 @@ use crate::cards::{Card, full_deck};
 /// ```
 #[derive(Debug)]
 pub struct Deck {
-    /// Vector of all 52 cards in current order
+    /// Vector of all 52 (or 36) cards in current order
     cards: Vec<Card>,
     /// Current position in the deck (index of next card to deal)
     position: usize,
     /// Deterministic RNG for reproducible shuffling
     rng: ChaCha20Rng,
+    /// Cards fixed at the leading deck positions; `None` slots are shuffled
+    pinned: Vec<Option<Card>>,
+    /// Whether the cards not pinned follow in standard order, unshuffled
+    ordered: bool,
+    /// Whether Twos through Fives are left out
+    short: bool,
+    /// Where shuffles come from
+    entropy: Entropy,
+    /// Whether every shuffle is committed to before dealing
+    commit_reveal: bool,
+    /// Secret behind the latest committed shuffle
+    reveal: Option<ShuffleReveal>,
+}
+
+/// Where a [`Deck`] gets its order from, see [`Deck::from_source`].
+///
+/// # Example
+///
+/// ```
+/// use axiomind_engine::deck::{ShuffleKind, ShuffleSource};
+///
+/// let source: ShuffleSource = "os".parse().unwrap();
+/// assert_eq!(source.kind(), ShuffleKind::Os);
+/// assert_eq!("chacha20".parse::<ShuffleSource>().unwrap(), ShuffleSource::ChaCha20(0));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShuffleSource {
+    /// ChaCha20 seeded from a `u64`; the same seed always deals the same hands
+    ChaCha20(u64),
+    /// A fresh seed from the operating system for every shuffle
+    Os,
+    /// Deck orders played back in turn, one per shuffle
+    Recorded(Vec<Vec<Card>>),
+}
+
+impl ShuffleSource {
+    /// Deck orders from a recorded shuffle file.
+    ///
+    /// Each line lists a whole deck back to back in standard notation
+    /// (`AhKd7c...`), all 52 cards or the 36 of the short deck. Blank lines
+    /// and lines starting with `#` are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GameError::InvalidShuffleSource` for a malformed line, an
+    /// incomplete deck, or a file without any order.
+    pub fn recorded(text: &str) -> Result<Self, GameError> {
+        let mut orders = Vec::new();
+        for (number, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let order = parse_cards(line).map_err(|e| {
+                GameError::InvalidShuffleSource(format!("line {}: {}", number + 1, e))
+            })?;
+            if deck_for(&order).is_none() {
+                return Err(GameError::InvalidShuffleSource(format!(
+                    "line {}: {} cards, a deck has 52 (36 short)",
+                    number + 1,
+                    order.len()
+                )));
+            }
+            orders.push(order);
+        }
+        if orders.is_empty() {
+            return Err(GameError::InvalidShuffleSource(
+                "no deck orders recorded".to_string(),
+            ));
+        }
+        Ok(ShuffleSource::Recorded(orders))
+    }
+
+    /// Which kind of source this is.
+    pub fn kind(&self) -> ShuffleKind {
+        match self {
+            ShuffleSource::ChaCha20(_) => ShuffleKind::ChaCha20,
+            ShuffleSource::Os => ShuffleKind::Os,
+            ShuffleSource::Recorded(_) => ShuffleKind::Recorded,
+        }
+    }
+}
+
+impl FromStr for ShuffleSource {
+    type Err = GameError;
+
+    /// `chacha20` (seed 0), `chacha20:SEED` or `os`. Recorded orders come
+    /// from [`ShuffleSource::recorded`].
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim().to_ascii_lowercase();
+        match s.split_once(':') {
+            None if s == "chacha20" => Ok(ShuffleSource::ChaCha20(0)),
+            None if s == "os" => Ok(ShuffleSource::Os),
+            Some(("chacha20", seed)) => seed
+                .parse()
+                .map(ShuffleSource::ChaCha20)
+                .map_err(|_| GameError::InvalidShuffleSource(format!("bad seed {:?}", seed))),
+            _ => Err(GameError::InvalidShuffleSource(format!(
+                "{:?} (expected chacha20, chacha20:SEED or os)",
+                s
+            ))),
+        }
+    }
+}
+
+/// Kind of [`ShuffleSource`] a deck was dealt from, as recorded in hand metadata.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ShuffleKind {
+    #[serde(rename = "chacha20")]
+    ChaCha20,
+    Os,
+    Recorded,
+}
+
+impl std::fmt::Display for ShuffleKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ShuffleKind::ChaCha20 => "chacha20",
+            ShuffleKind::Os => "os",
+            ShuffleKind::Recorded => "recorded",
+        })
+    }
+}
+
+/// Shuffle state beyond the seeded RNG.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum Entropy {
+    #[default]
+    Seeded,
+    Os,
+    Recorded {
+        orders: Vec<Vec<Card>>,
+        next: usize,
+    },
+}
+
+impl Entropy {
+    fn kind(&self) -> ShuffleKind {
+        match self {
+            Entropy::Seeded => ShuffleKind::ChaCha20,
+            Entropy::Os => ShuffleKind::Os,
+            Entropy::Recorded { .. } => ShuffleKind::Recorded,
+        }
+    }
 }
 
 impl Deck {
@@ -44,12 +199,264 @@ impl Deck {
             cards: full_deck(),
             position: 0,
             rng,
+            pinned: Vec::new(),
+            ordered: false,
+            short: false,
+            entropy: Entropy::Seeded,
+            commit_reveal: false,
+            reveal: None,
+        }
+    }
+
+    /// A deck shuffled from `source`.
+    ///
+    /// OS decks draw a fresh 32-byte seed for every shuffle. Recorded decks
+    /// deal their orders in turn and start over after the last; a 52-card
+    /// order used as a short deck drops Twos through Fives.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GameError::InvalidShuffleSource` for a recorded source
+    /// without orders.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use axiomind_engine::cards::{format_cards, full_deck};
+    /// use axiomind_engine::deck::{Deck, ShuffleSource};
+    ///
+    /// let mut order = full_deck();
+    /// order.reverse();
+    /// let source = ShuffleSource::recorded(&format_cards(&order)).unwrap();
+    /// let mut deck = Deck::from_source(source).unwrap();
+    /// deck.shuffle();
+    /// assert_eq!(deck.deal_card().unwrap().to_string(), "As");
+    /// ```
+    pub fn from_source(source: ShuffleSource) -> Result<Self, GameError> {
+        let (seed, entropy) = match source {
+            ShuffleSource::ChaCha20(seed) => (seed, Entropy::Seeded),
+            ShuffleSource::Os => (0, Entropy::Os),
+            ShuffleSource::Recorded(orders) if orders.is_empty() => {
+                return Err(GameError::InvalidShuffleSource(
+                    "no deck orders recorded".to_string(),
+                ));
+            }
+            ShuffleSource::Recorded(orders) => (0, Entropy::Recorded { orders, next: 0 }),
+        };
+        let mut deck = Self::new_with_seed(seed);
+        if entropy != Entropy::Seeded {
+            // Recorded decks still salt their commitments from fresh entropy
+            deck.rng = ChaCha20Rng::from_seed(os_seed());
         }
+        deck.entropy = entropy;
+        Ok(deck)
+    }
+
+    /// Commit to every shuffle before any card is dealt.
+    ///
+    /// Each shuffle starts from a fresh 32-byte seed, drawn from the seeded
+    /// RNG (so a seed still deals the same hands) or from the OS. The
+    /// [`ShuffleReveal`] of the latest shuffle holds the seed and the order;
+    /// its [`commitment`](ShuffleReveal::commitment) can be published before
+    /// the hand and the reveal itself once the hand is over.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GameError::InvalidDeck` for a deck with pinned positions,
+    /// whose order does not follow from the seed alone.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use axiomind_engine::deck::Deck;
+    ///
+    /// let mut deck = Deck::new_with_seed(7).with_commit_reveal().unwrap();
+    /// deck.shuffle();
+    /// let reveal = deck.reveal().unwrap().clone();
+    /// let commitment = reveal.commitment();
+    /// assert_eq!(deck.deal_card(), Some(reveal.deck[0]));
+    /// assert!(reveal.verify(&commitment).is_ok());
+    /// ```
+    pub fn with_commit_reveal(mut self) -> Result<Self, GameError> {
+        if !self.pinned.is_empty() {
+            return Err(GameError::InvalidDeck(
+                "a deck with pinned cards cannot commit to its shuffles".to_string(),
+            ));
+        }
+        self.commit_reveal = true;
+        Ok(self)
+    }
+
+    /// Whether every shuffle is committed to.
+    pub fn commits(&self) -> bool {
+        self.commit_reveal
+    }
+
+    /// Seed and order of the latest committed shuffle.
+    pub fn reveal(&self) -> Option<&ShuffleReveal> {
+        self.reveal.as_ref()
+    }
+
+    /// Which kind of source the deck shuffles from.
+    pub fn source_kind(&self) -> ShuffleKind {
+        self.entropy.kind()
+    }
+
+    /// A seeded 36-card deck, Six through Ace, for short-deck play.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use axiomind_engine::cards::Rank;
+    /// use axiomind_engine::deck::Deck;
+    ///
+    /// let mut deck = Deck::short_with_seed(42);
+    /// deck.shuffle();
+    /// assert_eq!(deck.remaining(), 36);
+    /// assert!(std::iter::from_fn(|| deck.deal_card()).all(|c| c.rank >= Rank::Six));
+    /// ```
+    pub fn short_with_seed(seed: u64) -> Self {
+        let mut deck = Self::new_with_seed(seed);
+        deck.set_short(true)
+            .expect("a fresh deck has no pinned cards");
+        deck
+    }
+
+    /// Whether this is the 36-card short deck.
+    pub fn is_short(&self) -> bool {
+        self.short
+    }
+
+    /// Switch between the full and the short deck, keeping the RNG.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GameError::NotInShortDeck` for a pinned card below a Six, or
+    /// `GameError::InvalidDeck` for more pinned positions than 36.
+    pub(crate) fn set_short(&mut self, short: bool) -> Result<(), GameError> {
+        if short {
+            if let Some(&card) = self.pinned.iter().flatten().find(|c| c.rank < Rank::Six) {
+                return Err(GameError::NotInShortDeck(card));
+            }
+            if self.pinned.len() > 36 {
+                return Err(GameError::InvalidDeck(format!(
+                    "{} positions pinned, a short deck has 36",
+                    self.pinned.len()
+                )));
+            }
+        }
+        self.short = short;
+        self.reset();
+        Ok(())
+    }
+
+    /// Every card of the deck in standard order.
+    fn all_cards(&self) -> Vec<Card> {
+        if self.short {
+            short_deck()
+        } else {
+            full_deck()
+        }
+    }
+
+    /// A deck dealt in exactly this order, e.g. to reproduce a reported hand.
+    ///
+    /// Cards not listed follow in standard order, without Twos through
+    /// Fives in a short deck. Shuffling restores the preset order, so every
+    /// hand dealt from the deck is the same.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GameError::DuplicateCard` if a card is listed twice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use axiomind_engine::cards::parse_cards;
+    /// use axiomind_engine::deck::Deck;
+    ///
+    /// let mut deck = Deck::from_order(parse_cards("AhAd KcKs").unwrap()).unwrap();
+    /// deck.shuffle();
+    /// assert_eq!(deck.deal_card().unwrap().to_string(), "Ah");
+    /// assert_eq!(deck.remaining(), 51);
+    /// ```
+    pub fn from_order(cards: Vec<Card>) -> Result<Self, GameError> {
+        let mut deck = Self::with_pinned(0, cards.into_iter().map(Some).collect())?;
+        deck.ordered = true;
+        deck.shuffle();
+        Ok(deck)
+    }
+
+    /// A seeded deck with some positions fixed.
+    ///
+    /// Position `i` always holds `pinned[i]` when it is `Some`; every other
+    /// position is filled from a seeded shuffle of the remaining cards, and
+    /// each [`shuffle`](Deck::shuffle) refills them. The deck is shuffled
+    /// once on construction. [`DealSpec`] maps hole and board cards to
+    /// positions.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GameError::DuplicateCard` if a card is pinned twice, or
+    /// `GameError::InvalidDeck` for more than 52 positions.
+    pub fn with_pinned(seed: u64, pinned: Vec<Option<Card>>) -> Result<Self, GameError> {
+        let mut seen = Vec::new();
+        for &card in pinned.iter().flatten() {
+            if seen.contains(&card) {
+                return Err(GameError::DuplicateCard(card));
+            }
+            seen.push(card);
+        }
+        if pinned.len() > 52 {
+            return Err(GameError::InvalidDeck(format!(
+                "{} positions pinned, a deck has 52",
+                pinned.len()
+            )));
+        }
+        let mut deck = Self::new_with_seed(seed);
+        deck.pinned = pinned;
+        deck.shuffle();
+        Ok(deck)
     }
 
     pub fn shuffle(&mut self) {
-        self.cards = full_deck();
-        self.cards.shuffle(&mut self.rng);
+        // Committed and OS shuffles each start from a fresh seed
+        let seed = match self.entropy {
+            Entropy::Os => Some(os_seed()),
+            _ if self.commit_reveal => Some(self.rng.random::<[u8; 32]>()),
+            _ => None,
+        };
+        let all = self.all_cards();
+        if let Entropy::Recorded { orders, next } = &mut self.entropy {
+            let order = &orders[*next % orders.len()];
+            *next += 1;
+            self.cards = order.iter().filter(|c| all.contains(c)).copied().collect();
+            self.cards
+                .extend(all.into_iter().filter(|c| !order.contains(c)));
+        } else {
+            self.cards = all;
+            if !self.pinned.is_empty() {
+                self.cards.retain(|c| !self.pinned.contains(&Some(*c)));
+            }
+            match seed {
+                _ if self.ordered => {}
+                Some(seed) => self.cards.shuffle(&mut ChaCha20Rng::from_seed(seed)),
+                None => self.cards.shuffle(&mut self.rng),
+            }
+            // Free cards fill the open slots in shuffled order, the rest follow
+            let mut free = std::mem::take(&mut self.cards).into_iter();
+            for slot in &self.pinned {
+                self.cards.extend(slot.or_else(|| free.next()));
+            }
+            self.cards.extend(free);
+        }
+        self.reveal = seed
+            .filter(|_| self.commit_reveal)
+            .map(|seed| ShuffleReveal {
+                source: self.entropy.kind(),
+                seed: to_hex(&seed),
+                deck: self.cards.clone(),
+            });
         self.position = 0;
     }
 
@@ -67,8 +474,29 @@ impl Deck {
         let _ = self.deal_card();
     }
 
+    /// Index of the next card to deal.
+    pub(crate) fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Put dealt cards back so dealing resumes from `position`.
+    pub(crate) fn set_position(&mut self, position: usize) {
+        self.position = position.min(self.cards.len());
+    }
+
+    /// Restart the seeded RNG from `seed`, as if the deck had been created
+    /// with it.
+    pub(crate) fn reseed(&mut self, seed: u64) {
+        self.rng = ChaCha20Rng::seed_from_u64(seed);
+    }
+
+    /// Cards not dealt yet, in deal order.
+    pub(crate) fn undealt(&self) -> &[Card] {
+        &self.cards[self.position.min(self.cards.len())..]
+    }
+
     pub fn reset(&mut self) {
-        self.cards = full_deck();
+        self.cards = self.all_cards();
         self.position = 0;
     }
 
@@ -76,4 +504,466 @@ impl Deck {
     pub fn remaining(&self) -> usize {
         self.cards.len().saturating_sub(self.position)
     }
+
+    /// Capture the card order, the deal position and the RNG state.
+    ///
+    /// This gives away a committed shuffle before it is revealed; use
+    /// [`Deck::sealed_snapshot`] while the deck is in play.
+    pub fn snapshot(&self) -> DeckSnapshot {
+        DeckSnapshot {
+            cards: self.cards.clone(),
+            position: self.position,
+            rng_seed: self.rng.get_seed(),
+            rng_stream: self.rng.get_stream(),
+            rng_word_pos: self.rng.get_word_pos(),
+            pinned: self.pinned.clone(),
+            ordered: self.ordered,
+            short: self.short,
+            entropy: self.entropy.clone(),
+            commit_reveal: self.commit_reveal,
+            reveal: self.reveal.clone(),
+            sealed: false,
+        }
+    }
+
+    /// A snapshot without anything that gives the deck order away: the
+    /// cards, the RNG state, recorded orders and the committed shuffle's
+    /// reveal are left out. Sealed snapshots cannot be restored.
+    pub fn sealed_snapshot(&self) -> DeckSnapshot {
+        let entropy = match &self.entropy {
+            Entropy::Recorded { next, .. } => Entropy::Recorded {
+                orders: Vec::new(),
+                next: *next,
+            },
+            entropy => entropy.clone(),
+        };
+        DeckSnapshot {
+            cards: Vec::new(),
+            position: self.position,
+            rng_seed: [0; 32],
+            rng_stream: 0,
+            rng_word_pos: 0,
+            pinned: Vec::new(),
+            ordered: false,
+            short: self.short,
+            entropy,
+            commit_reveal: self.commit_reveal,
+            reveal: None,
+            sealed: true,
+        }
+    }
+
+    /// Rebuild a deck from a snapshot. Later shuffles produce exactly the
+    /// orders the original deck would have produced.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GameError::InvalidSnapshot` for a [sealed](Deck::sealed_snapshot)
+    /// snapshot.
+    pub fn restore(snapshot: DeckSnapshot) -> Result<Self, GameError> {
+        if snapshot.sealed {
+            return Err(GameError::InvalidSnapshot(
+                "the deck is sealed until the hand is over".to_string(),
+            ));
+        }
+        let mut rng = ChaCha20Rng::from_seed(snapshot.rng_seed);
+        rng.set_stream(snapshot.rng_stream);
+        rng.set_word_pos(snapshot.rng_word_pos);
+        Ok(Self {
+            cards: snapshot.cards,
+            position: snapshot.position,
+            rng,
+            pinned: snapshot.pinned,
+            ordered: snapshot.ordered,
+            short: snapshot.short,
+            entropy: snapshot.entropy,
+            commit_reveal: snapshot.commit_reveal,
+            reveal: snapshot.reveal,
+        })
+    }
+}
+
+/// Seed and order behind a committed shuffle, see [`Deck::with_commit_reveal`].
+///
+/// The commitment is the SHA-256 of `SEED:DECK`, the hex seed and the deck
+/// in standard notation, so it can be checked with any SHA-256 tool.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShuffleReveal {
+    /// Kind of source the deck was shuffled from
+    pub source: ShuffleKind,
+    /// 32-byte shuffle seed in hex
+    pub seed: String,
+    /// Deck order, first card dealt first
+    #[serde(with = "compact::vec")]
+    pub deck: Vec<Card>,
+}
+
+impl ShuffleReveal {
+    /// Hex SHA-256 commitment to the seed and the deck order.
+    pub fn commitment(&self) -> String {
+        let digest = Sha256::digest(format!("{}:{}", self.seed, format_cards(&self.deck)));
+        to_hex(&digest)
+    }
+
+    /// Check that this reveal matches `commitment` and, unless the order was
+    /// recorded, that the seed shuffles the full (or short) deck into it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GameError::ShuffleMismatch` describing the first check that fails.
+    pub fn verify(&self, commitment: &str) -> Result<(), GameError> {
+        if !self.commitment().eq_ignore_ascii_case(commitment.trim()) {
+            return Err(GameError::ShuffleMismatch(
+                "seed and deck do not match the commitment".to_string(),
+            ));
+        }
+        let Some(mut cards) = deck_for(&self.deck) else {
+            return Err(GameError::ShuffleMismatch(format!(
+                "revealed deck has {} distinct cards, a deck has 52 (36 short)",
+                self.deck.len()
+            )));
+        };
+        if self.source == ShuffleKind::Recorded {
+            return Ok(());
+        }
+        let seed = from_hex(&self.seed).ok_or_else(|| {
+            GameError::ShuffleMismatch(format!("seed {:?} is not 32 bytes of hex", self.seed))
+        })?;
+        cards.shuffle(&mut ChaCha20Rng::from_seed(seed));
+        if cards != self.deck {
+            return Err(GameError::ShuffleMismatch(
+                "seed does not shuffle into the revealed deck".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Check that `record` was dealt from this deck in the engine's order:
+    /// hole cards one per seat per round, then burn and flop, burn and turn,
+    /// burn and river, then a burn and the missing streets of each further runout.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GameError::ShuffleMismatch` naming the first card dealt out of order.
+    pub fn check_deal(&self, record: &HandRecord) -> Result<(), GameError> {
+        let check = |position: usize, card: Card, what: String| match self.deck.get(position) {
+            Some(&expected) if expected == card => Ok(()),
+            expected => Err(GameError::ShuffleMismatch(format!(
+                "{} is {}, but deck position {} holds {}",
+                what,
+                card,
+                position + 1,
+                expected.map_or("nothing".to_string(), |c| c.to_string())
+            ))),
+        };
+        // Seats that started without chips sat the hand out and were not dealt
+        let in_hand: Vec<_> = record
+            .players
+            .iter()
+            .filter(|p| p.stack_start > 0)
+            .collect();
+        let seats = in_hand.len();
+        let mut rounds = 0;
+        for (seat, player) in in_hand.into_iter().enumerate() {
+            for (round, &card) in player.hole_cards.iter().flatten().enumerate() {
+                check(
+                    round * seats + seat,
+                    card,
+                    format!("{} hole card {}", player.id, round + 1),
+                )?;
+                rounds = rounds.max(round + 1);
+            }
+        }
+
+        let mut position = rounds * seats;
+        let mut dealt = 0;
+        for count in [3, 1, 1] {
+            if dealt >= record.board.len() {
+                break;
+            }
+            position += 1;
+            for _ in 0..count {
+                if let Some(&card) = record.board.get(dealt) {
+                    check(position, card, format!("board card {}", dealt + 1))?;
+                }
+                position += 1;
+                dealt += 1;
+            }
+        }
+
+        let runouts = record.showdown.as_ref().map_or(&[][..], |s| &s.runouts[..]);
+        let shared = (0..record.board.len())
+            .take_while(|&i| {
+                runouts
+                    .iter()
+                    .all(|board| board.get(i) == record.board.get(i))
+            })
+            .count();
+        for (run, board) in runouts.iter().enumerate().skip(1) {
+            let mut dealt = shared;
+            while dealt < board.len() {
+                let count = if dealt == 0 { 3 } else { 1 };
+                position += 1;
+                for card in board.iter().skip(dealt).take(count) {
+                    check(
+                        position,
+                        *card,
+                        format!("run {} card {}", run + 1, dealt + 1),
+                    )?;
+                    position += 1;
+                    dealt += 1;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The full or short deck in standard order if `order` is a whole one.
+fn deck_for(order: &[Card]) -> Option<Vec<Card>> {
+    let deck = match order.len() {
+        52 => full_deck(),
+        36 => short_deck(),
+        _ => return None,
+    };
+    deck.iter().all(|c| order.contains(c)).then_some(deck)
+}
+
+/// 32 bytes from the operating system's random number generator.
+fn os_seed() -> [u8; 32] {
+    let mut seed = [0u8; 32];
+    OsRng
+        .try_fill_bytes(&mut seed)
+        .expect("operating system random number generator failed");
+    seed
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn from_hex(s: &str) -> Option<[u8; 32]> {
+    if s.len() != 64 || !s.is_ascii() {
+        return None;
+    }
+    let mut bytes = [0u8; 32];
+    for (i, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&s[2 * i..2 * i + 2], 16).ok()?;
+    }
+    Some(bytes)
+}
+
+/// Cards to fix when dealing a hand: hole cards per seat and board cards.
+///
+/// Open slots are filled from the seed. The text form lists each seat's two
+/// or four hole cards, then the board after a `|`, with `??` for an open
+/// card: `"AhAd KcKs | Kd7h2c"`, `"Ah?? ???? | ??????8s"`. Seats beyond the
+/// ones listed, and hole cards beyond the ones a seat lists, are dealt at
+/// random.
+///
+/// # Example
+///
+/// ```
+/// use axiomind_engine::cards::format_cards;
+/// use axiomind_engine::deck::DealSpec;
+/// use axiomind_engine::engine::Engine;
+/// use axiomind_engine::variant::GameVariant;
+///
+/// // Set over set on a king-high flop
+/// let spec: DealSpec = "7h7d KcKs | Kd7c2s".parse().unwrap();
+/// let deck = spec.deck(3, 2, GameVariant::Holdem).unwrap();
+/// let mut engine = Engine::new(None, 1).with_deck(deck).unwrap();
+/// engine.deal_hand().unwrap();
+/// engine.run_out_board().unwrap();
+///
+/// assert_eq!(engine.players()[1].hole_cards()[0].unwrap().to_string(), "Kc");
+/// assert_eq!(format_cards(&engine.board()[..3]), "Kd7c2s");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DealSpec {
+    /// Hole cards by seat, two or four each
+    pub hole_cards: Vec<Vec<Option<Card>>>,
+    /// Flop, turn and river in order, at most five
+    pub board: Vec<Option<Card>>,
+}
+
+impl DealSpec {
+    /// Deck positions for a table of `seats` dealt `hole_cards` cards each,
+    /// in the engine's deal order: one card to each seat in turn for every
+    /// hole card, then burn and flop, burn and turn, burn and river.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GameError::InvalidDeck` for more seats than `seats`, a seat
+    /// with more than `hole_cards` cards or more than five board cards.
+    pub fn positions(
+        &self,
+        seats: usize,
+        hole_cards: usize,
+    ) -> Result<Vec<Option<Card>>, GameError> {
+        if self.hole_cards.len() > seats {
+            return Err(GameError::InvalidDeck(format!(
+                "{} seats specified for a table of {}",
+                self.hole_cards.len(),
+                seats
+            )));
+        }
+        if let Some(hole) = self.hole_cards.iter().find(|h| h.len() > hole_cards) {
+            return Err(GameError::InvalidDeck(format!(
+                "{} hole cards specified, each seat is dealt {}",
+                hole.len(),
+                hole_cards
+            )));
+        }
+        if self.board.len() > 5 {
+            return Err(GameError::InvalidDeck(format!(
+                "board has {} cards, at most 5 allowed",
+                self.board.len()
+            )));
+        }
+        let dealt = hole_cards * seats;
+        let mut slots = vec![None; dealt + 8];
+        for (seat, hole) in self.hole_cards.iter().enumerate() {
+            for (round, &card) in hole.iter().enumerate() {
+                slots[round * seats + seat] = card;
+            }
+        }
+        for (i, &card) in self.board.iter().enumerate() {
+            // Burns sit before the flop, the turn and the river
+            let offset = match i {
+                0..=2 => 1 + i,
+                3 => 5,
+                _ => 7,
+            };
+            slots[dealt + offset] = card;
+        }
+        while slots.last() == Some(&None) {
+            slots.pop();
+        }
+        Ok(slots)
+    }
+
+    /// A deck dealing these cards to a table of `seats` playing `variant`,
+    /// with open slots filled from `seed`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GameError::InvalidDeck` if the spec does not fit the table,
+    /// or `GameError::NotInShortDeck` for a card below a Six in short deck.
+    pub fn deck(&self, seed: u64, seats: usize, variant: GameVariant) -> Result<Deck, GameError> {
+        let mut deck = Deck::with_pinned(seed, self.positions(seats, variant.hole_cards())?)?;
+        if variant.uses_short_deck() {
+            deck.set_short(true)?;
+        }
+        Ok(deck)
+    }
+}
+
+impl FromStr for DealSpec {
+    type Err = GameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (holes, board) = s.split_once('|').unwrap_or((s, ""));
+        let hole_cards = holes
+            .split_whitespace()
+            .map(|seat| {
+                let slots = parse_slots(seat)?;
+                if slots.len() != 2 && slots.len() != 4 {
+                    return Err(GameError::InvalidDeck(format!(
+                        "seat {:?} must name 2 or 4 cards",
+                        seat
+                    )));
+                }
+                Ok(slots)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let spec = DealSpec {
+            hole_cards,
+            board: parse_slots(board)?,
+        };
+        // Reject duplicates and an overlong board up front
+        let hole_cards = spec.hole_cards.iter().map(Vec::len).max().unwrap_or(2);
+        spec.positions(spec.hole_cards.len().max(2), hole_cards)
+            .and_then(|slots| Deck::with_pinned(0, slots))?;
+        Ok(spec)
+    }
+}
+
+/// Parse cards written back to back, where `??` is an open slot.
+fn parse_slots(s: &str) -> Result<Vec<Option<Card>>, GameError> {
+    let mut chars = s.chars().filter(|c| !c.is_whitespace()).peekable();
+    let mut slots = Vec::new();
+    while let Some(first) = chars.next() {
+        let mut token = String::from(first);
+        if first == '1' && chars.peek() == Some(&'0') {
+            token.extend(chars.next());
+        }
+        token.extend(chars.next());
+        if token == "??" {
+            slots.push(None);
+        } else {
+            let card = token
+                .parse::<Card>()
+                .map_err(|e| GameError::InvalidDeck(e.to_string()))?;
+            slots.push(Some(card));
+        }
+    }
+    Ok(slots)
+}
+
+/// Serializable state of a [`Deck`], including where its RNG stream stands.
+///
+/// # Examples
+///
+/// ```
+/// use axiomind_engine::deck::Deck;
+///
+/// let mut deck = Deck::new_with_seed(7);
+/// deck.shuffle();
+/// deck.deal_card();
+///
+/// let mut copy = Deck::restore(deck.snapshot()).unwrap();
+/// assert_eq!(copy.deal_card(), deck.deal_card());
+///
+/// deck.shuffle();
+/// copy.shuffle();
+/// assert_eq!(copy.deal_card(), deck.deal_card());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeckSnapshot {
+    /// Cards in current order
+    cards: Vec<Card>,
+    /// Index of the next card to deal
+    position: usize,
+    /// ChaCha20 seed
+    rng_seed: [u8; 32],
+    /// ChaCha20 stream number
+    rng_stream: u64,
+    /// Words of the stream already consumed
+    rng_word_pos: u128,
+    /// Fixed positions of a preset deck
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pinned: Vec<Option<Card>>,
+    /// Whether the cards not pinned follow in standard order
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    ordered: bool,
+    /// Whether this is the 36-card short deck
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    short: bool,
+    /// Where shuffles come from
+    #[serde(default, skip_serializing_if = "is_seeded")]
+    entropy: Entropy,
+    /// Whether every shuffle is committed to
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    commit_reveal: bool,
+    /// Secret behind the latest committed shuffle
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    reveal: Option<ShuffleReveal>,
+    /// Whether the deck order was left out, see [`Deck::sealed_snapshot`]
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    sealed: bool,
+}
+
+fn is_seeded(entropy: &Entropy) -> bool {
+    *entropy == Entropy::Seeded
 }
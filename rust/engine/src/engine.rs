@@ -1,11 +1,38 @@
 use crate::cards::Card;
-use crate::deck::Deck;
+use crate::deck::{Deck, DeckSnapshot, ShuffleKind, ShuffleReveal, ShuffleSource};
 use crate::errors::GameError;
-use crate::logger::{ActionRecord, Street};
+use crate::events::{EngineEvent, EngineObserver, ForcedBet};
+use crate::hand::HandStrength;
+use crate::logger::{
+    ActionRecord, HandRecord, PlayerRecord, Street, describe_outcome, net_result, seat_id,
+    showdown_info,
+};
+use crate::observation::PlayerObservation;
 use crate::player::{Player, PlayerAction, Position, STARTING_STACK};
-use crate::rules::{ValidatedAction, validate_action};
+use crate::pot::{PotManager, split_pot};
+use crate::rules::{
+    BettingSpot, BettingStructure, BlindLevel, BlindStructure, LegalActions, STANDARD_BLINDS,
+    ValidatedAction,
+};
+use crate::seed::{DEFAULT_ROOT, SeedDerivation, SeedPath, SeedTree};
+use crate::variant::GameVariant;
+use rand::SeedableRng;
+use rand::seq::SliceRandom;
+use rand_chacha::ChaCha20Rng;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 
-/// Returns the small blind and big blind amounts for a given level.
+/// Fewest players an [`Engine`] can seat (heads-up).
+pub const MIN_SEATS: usize = 2;
+
+/// Most players an [`Engine`] can seat.
+pub const MAX_SEATS: usize = 9;
+
+/// Most times [`Engine::with_runouts`] can deal the rest of the board.
+pub const MAX_RUNOUTS: usize = 4;
+
+/// Returns the small blind and big blind amounts for a given level of the
+/// default blind structure (see [`BlindStructure`] for custom schedules).
 ///
 /// # Supported Levels
 /// - Levels 1-20: Predefined blind amounts with progressive increases
@@ -39,121 +66,107 @@ use crate::rules::{ValidatedAction, validate_action};
 /// assert!(blinds_for_level(0).is_err());
 /// ```
 pub fn blinds_for_level(level: u8) -> Result<(u32, u32), GameError> {
-    match level {
-        0 => Err(GameError::InvalidLevel { level, minimum: 1 }),
-        1 => Ok((50, 100)),
-        2 => Ok((75, 150)),
-        3 => Ok((100, 200)),
-        4 => Ok((125, 250)),
-        5 => Ok((150, 300)),
-        6 => Ok((200, 400)),
-        7 => Ok((250, 500)),
-        8 => Ok((300, 600)),
-        9 => Ok((400, 800)),
-        10 => Ok((500, 1000)),
-        11 => Ok((600, 1200)),
-        12 => Ok((800, 1600)),
-        13 => Ok((1000, 2000)),
-        14 => Ok((1200, 2400)),
-        15 => Ok((1500, 3000)),
-        16 => Ok((2000, 4000)),
-        17 => Ok((2500, 5000)),
-        18 => Ok((3000, 6000)),
-        19 => Ok((3500, 7000)),
-        20 => Ok((4000, 8000)),
-        // Levels 21 and above are treated as level 20 (maximum blind level)
-        _ => Ok((4000, 8000)),
+    if level == 0 {
+        return Err(GameError::InvalidLevel { level, minimum: 1 });
     }
+    // Levels 21 and above are treated as level 20 (maximum blind level)
+    let idx = usize::from(level).min(STANDARD_BLINDS.len()) - 1;
+    Ok(STANDARD_BLINDS[idx])
 }
 
 /// Represents the state of a single betting round within a poker hand.
 /// Tracks contributions, current bet level, and player states for one street.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 struct BettingRound {
     /// Current betting street (Preflop, Flop, Turn, or River)
     street: Street,
     /// Amount each player has contributed in this betting round
-    contributions: [u32; 2],
+    contributions: Vec<u32>,
     /// Current bet level that must be matched
     current_bet: u32,
     /// Minimum raise amount (typically the size of the last raise or big blind)
     min_raise: u32,
-    /// Number of actions taken in this round (used to determine if betting is complete)
+    /// Number of actions taken in this round
     actions_this_round: usize,
-    /// Whether each player has folded
-    folded: [bool; 2],
+    /// Whether each player has folded (carried over from earlier streets)
+    folded: Vec<bool>,
     /// Whether each player is all-in
-    all_in: [bool; 2],
+    all_in: Vec<bool>,
+    /// Whether each player has acted in this round
+    acted: Vec<bool>,
+    /// Seat whose turn it is
+    to_act: usize,
+    /// Bets and raises made in this round; preflop the big blind counts as the first
+    #[serde(default)]
+    bets: u32,
 }
 
 impl BettingRound {
-    /// Create a new betting round for the specified street.
-    /// For preflop, blinds are automatically posted.
-    fn new(street: Street, level: u8, button_position: usize) -> Result<Self, GameError> {
-        let (sb, bb) = blinds_for_level(level)?;
-
-        // In heads-up poker:
-        // - Button (small blind) acts first preflop
-        // - Button acts last postflop
-        // button_position: 0 = player 0 is button, 1 = player 1 is button
-
-        let (contributions, current_bet, actions_this_round) = if street == Street::Preflop {
-            // Post blinds: button posts small blind, other player posts big blind
-            let mut contrib = [0u32; 2];
-            contrib[button_position] = sb;
-            contrib[1 - button_position] = bb;
-            (contrib, bb, 0) // BB is the current bet, no actions yet
-        } else {
-            ([0, 0], 0, 0)
-        };
-
-        Ok(Self {
+    /// Create a new betting round for the specified street with nothing bet yet.
+    /// Preflop blinds are filled in by [`HandState::new`].
+    fn new(street: Street, big_blind: u32, folded: Vec<bool>) -> Self {
+        let seats = folded.len();
+        Self {
             street,
-            contributions,
-            current_bet,
-            min_raise: bb, // Minimum raise is always at least the big blind
-            actions_this_round,
-            folded: [false, false],
-            all_in: [false, false],
-        })
+            contributions: vec![0; seats],
+            current_bet: 0,
+            min_raise: big_blind, // Minimum raise is always at least the big blind
+            actions_this_round: 0,
+            folded,
+            all_in: vec![false; seats],
+            acted: vec![false; seats],
+            to_act: 0,
+            bets: 0,
+        }
     }
 
-    /// Check if betting is complete for this round.
-    /// Betting is complete when:
-    /// - One player has folded, OR
-    /// - Both players are all-in, OR
-    /// - Both players have acted and contributions are equal (or one is all-in with less)
-    fn is_complete(&self, active_player_count: usize) -> bool {
-        // If someone folded, round is complete
-        if self.folded[0] || self.folded[1] {
-            return true;
-        }
+    fn seats(&self) -> usize {
+        self.folded.len()
+    }
 
-        // If both players are all-in, round is complete
-        if self.all_in[0] && self.all_in[1] {
-            return true;
-        }
+    /// Number of players who have not folded
+    fn live_count(&self) -> usize {
+        self.folded.iter().filter(|&&f| !f).count()
+    }
 
-        // If only one active player (other is all-in but not folded), check if active player acted
-        if active_player_count == 1 && self.actions_this_round > 0 {
-            return true;
-        }
+    /// Whether `seat` still has to act before the round can close: it has chips
+    /// behind and has either not acted yet or faces a bet it has not matched.
+    fn needs_action(&self, seat: usize) -> bool {
+        !self.folded[seat]
+            && !self.all_in[seat]
+            && (!self.acted[seat] || self.contributions[seat] < self.current_bet)
+    }
 
-        // Both players need to have acted at least once
-        if self.actions_this_round < 2 {
-            return false;
-        }
+    /// First seat from `start` onwards, clockwise, that still has to act
+    fn next_to_act(&self, start: usize) -> Option<usize> {
+        let seats = self.seats();
+        (0..seats)
+            .map(|offset| (start + offset) % seats)
+            .find(|&seat| self.needs_action(seat))
+    }
 
-        // Contributions must be equal, or one player must be all-in with less
-        if self.contributions[0] == self.contributions[1] {
-            return true;
-        }
+    /// Put the action on the first seat from `start` that has to act. If
+    /// nobody has to, `start` is asked anyway so the round still gets an action.
+    fn open_action(&mut self, start: usize) {
+        self.to_act = self.next_to_act(start).unwrap_or(start);
+    }
 
-        // If contributions differ, the player with less must be all-in
-        if self.contributions[0] < self.contributions[1] {
-            self.all_in[0]
-        } else {
-            self.all_in[1]
+    /// Check if betting is complete for this round.
+    /// Betting is complete when all but one player has folded, or when every
+    /// player still in the hand has acted and matched the current bet (or is all-in).
+    fn is_complete(&self) -> bool {
+        self.live_count() <= 1 || self.next_to_act(0).is_none()
+    }
+
+    /// Check if no more betting is possible this hand: at most one player
+    /// still in the hand has chips behind, and they owe nothing.
+    fn is_closed(&self) -> bool {
+        let mut can_bet =
+            (0..self.seats()).filter(|&seat| !self.folded[seat] && !self.all_in[seat]);
+        match (can_bet.next(), can_bet.next()) {
+            (None, _) => true,
+            (Some(seat), None) => self.contributions[seat] >= self.current_bet,
+            _ => false,
         }
     }
 
@@ -164,43 +177,90 @@ impl BettingRound {
     }
 }
 
+/// First seat from `start` onwards, clockwise, that is dealt into the hand.
+fn next_in_hand(start: usize, in_hand: &[bool]) -> usize {
+    let seats = in_hand.len();
+    (0..seats)
+        .map(|offset| (start + offset) % seats)
+        .find(|&seat| in_hand[seat])
+        .unwrap_or(start % seats)
+}
+
+/// Seats posting the small and big blind with the button on `button`.
+///
+/// Heads-up the button posts the small blind; otherwise the two seats to the
+/// left of the button post the blinds. Seats sitting the hand out are skipped.
+fn blind_seats(button: usize, in_hand: &[bool]) -> (usize, usize) {
+    let sb_seat = if in_hand.iter().filter(|&&seat| seat).count() == 2 {
+        button
+    } else {
+        next_in_hand(button + 1, in_hand)
+    };
+    (sb_seat, next_in_hand(sb_seat + 1, in_hand))
+}
+
 /// Represents the complete state of a poker hand in progress.
 /// Tracks all betting rounds, actions, and determines when the hand is complete.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct HandState {
     /// Current betting round
     betting_round: BettingRound,
     /// Complete history of all actions taken in this hand
     action_history: Vec<ActionRecord>,
     /// Total contributions from each player across all streets
-    total_contributions: [u32; 2],
-    /// Blind level for this hand
-    level: u8,
-    /// Button position (0 or 1)
+    total_contributions: Vec<u32>,
+    /// Antes posted by each player; dead money that belongs to the main pot
+    antes: Vec<u32>,
+    /// Big blind for this hand, the minimum bet on every street
+    big_blind: u32,
+    /// Button position (seat index)
     button_position: usize,
     /// Whether the hand has reached a terminal state
     is_complete: bool,
+    /// Settlement of the hand, filled in once the hand completes
+    outcome: Option<HandOutcome>,
+    /// Every board when the rest of the board was dealt more than once
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    runouts: Vec<Vec<Card>>,
 }
 
 impl HandState {
-    /// Create a new hand state, initializing with preflop betting round
-    fn new(level: u8, button_position: usize) -> Result<Self, GameError> {
-        let betting_round = BettingRound::new(Street::Preflop, level, button_position)?;
-
-        // Initialize total contributions with posted blinds
-        let (sb, bb) = blinds_for_level(level)?;
-        let mut total_contributions = [0u32; 2];
-        total_contributions[button_position] = sb;
-        total_contributions[1 - button_position] = bb;
+    /// Create a new hand state, initializing with preflop betting round.
+    ///
+    /// `posted` holds the blinds each seat actually put in, which is less than the
+    /// full blind when a short stack posts all-in; `antes` is dead money already
+    /// in the pot; `all_in` marks seats with nothing left behind. Seats not
+    /// `in_hand` start the hand folded.
+    fn new(
+        big_blind: u32,
+        button_position: usize,
+        posted: Vec<u32>,
+        antes: Vec<u32>,
+        all_in: Vec<bool>,
+        in_hand: &[bool],
+    ) -> Self {
+        let seats = posted.len();
+        let folded = in_hand.iter().map(|&seat| !seat).collect();
+        let mut betting_round = BettingRound::new(Street::Preflop, big_blind, folded);
+        betting_round.contributions = posted.clone();
+        betting_round.current_bet = posted.iter().copied().max().unwrap_or(0);
+        betting_round.all_in = all_in;
+        betting_round.bets = 1;
+        // Preflop the seat after the big blind opens; heads-up that is the button
+        let (_, bb_seat) = blind_seats(button_position, in_hand);
+        betting_round.open_action((bb_seat + 1) % seats);
 
-        Ok(Self {
+        Self {
             betting_round,
             action_history: Vec::new(),
-            total_contributions,
-            level,
+            total_contributions: posted,
+            antes,
+            big_blind,
             button_position,
             is_complete: false,
-        })
+            outcome: None,
+            runouts: Vec::new(),
+        }
     }
 
     /// Check if this hand has reached a terminal state
@@ -215,7 +275,7 @@ impl HandState {
 
     /// Get total pot size
     pub fn pot(&self) -> u32 {
-        self.total_contributions[0] + self.total_contributions[1]
+        self.total_contributions.iter().sum::<u32>() + self.antes.iter().sum::<u32>()
     }
 
     /// Advance to the next betting street
@@ -231,25 +291,140 @@ impl HandState {
             }
         };
 
-        self.betting_round = BettingRound::new(next_street, self.level, self.button_position)?;
+        let folded = std::mem::take(&mut self.betting_round.folded);
+        let all_in = std::mem::take(&mut self.betting_round.all_in);
+        let seats = folded.len();
+        self.betting_round = BettingRound::new(next_street, self.big_blind, folded);
+        // Players who are all-in stay all-in for the rest of the hand
+        self.betting_round.all_in = all_in;
+        // After the flop the first seat left of the button opens
+        self.betting_round
+            .open_action((self.button_position + 1) % seats);
         Ok(())
     }
 
     fn current_actor(&self) -> usize {
-        let first_to_act = match self.betting_round.street {
-            Street::Preflop => self.button_position,
-            _ => 1 - self.button_position,
-        };
-        if self.betting_round.actions_this_round.is_multiple_of(2) {
-            first_to_act
-        } else {
-            1 - first_to_act
-        }
+        self.betting_round.to_act
+    }
+}
+
+/// How a hand came to an end.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EndReason {
+    /// All but one player folded
+    Fold,
+    /// Two or more players reached showdown
+    Showdown,
+}
+
+/// A contested pot and the seat(s) it was awarded to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PotAward {
+    /// Chips in the pot
+    pub amount: u32,
+    /// Seats sharing the pot, ordered by odd-chip priority
+    pub winners: Vec<usize>,
+    /// Runout whose board decided this share, counting from 0; always 0
+    /// unless the board was dealt more than once
+    #[serde(default)]
+    pub runout: usize,
+}
+
+/// Result of settling a completed hand.
+///
+/// Produced automatically by [`Engine::apply_action`] when a hand completes and
+/// available through [`Engine::hand_outcome`]. Stacks have already been credited
+/// when this value exists, so `net` always sums to zero.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HandOutcome {
+    /// Whether the hand ended by fold or at showdown
+    pub end_reason: EndReason,
+    /// Seats that won (a share of) the main pot
+    pub winners: Vec<usize>,
+    /// Contested pots, main pot first
+    pub pots: Vec<PotAward>,
+    /// Uncalled portion of a bet returned to its owner, as `(seat, amount)`
+    pub uncalled_bet: Option<(usize, u32)>,
+    /// Chips paid back to each seat, including any uncalled bet
+    pub payouts: Vec<u32>,
+    /// Net chip change for each seat over the whole hand
+    pub net: Vec<i64>,
+}
+
+/// Serializable checkpoint of an [`Engine`], taken with [`Engine::snapshot`].
+///
+/// Captures everything the engine needs to carry on: the deck order, deal
+/// position and RNG state, both players, the board, the hand in progress and
+/// the button. An engine rebuilt with [`Engine::restore`] behaves exactly like
+/// the original from that point on, including the shuffles of later hands.
+///
+/// # Examples
+///
+/// ```
+/// use axiomind_engine::engine::{Engine, EngineSnapshot};
+/// use axiomind_engine::player::PlayerAction;
+///
+/// let mut engine = Engine::new(Some(42), 1);
+/// engine.deal_hand().unwrap();
+/// engine.apply_action(0, PlayerAction::Call).unwrap();
+///
+/// let json = serde_json::to_string(&engine.snapshot()).unwrap();
+/// let snapshot: EngineSnapshot = serde_json::from_str(&json).unwrap();
+/// let mut restored = Engine::restore(snapshot).unwrap();
+///
+/// engine.apply_action(1, PlayerAction::Check).unwrap();
+/// restored.apply_action(1, PlayerAction::Check).unwrap();
+/// assert_eq!(restored.board(), engine.board());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EngineSnapshot {
+    deck: DeckSnapshot,
+    players: Vec<Player>,
+    level: u8,
+    blind_structure: BlindStructure,
+    #[serde(default)]
+    betting_structure: BettingStructure,
+    #[serde(default)]
+    variant: GameVariant,
+    #[serde(default = "single_runout")]
+    runouts: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    seed_derivation: Option<SeedDerivation>,
+    board: Vec<Card>,
+    hand_state: Option<HandState>,
+    button_position: usize,
+}
+
+fn single_runout() -> usize {
+    1
+}
+
+/// State needed to take back one action, captured just before it was applied.
+///
+/// Community cards only ever grow and come off the deck in order during a
+/// hand, so the board length and deck position are enough to put them back.
+#[derive(Debug, Clone)]
+struct UndoEntry {
+    players: Vec<Player>,
+    board_len: usize,
+    deck_position: usize,
+    hand_state: HandState,
+}
+
+/// Observers added with [`Engine::add_observer`].
+#[derive(Default)]
+struct Observers(Vec<Box<dyn EngineObserver>>);
+
+impl std::fmt::Debug for Observers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} observer(s)", self.0.len())
     }
 }
 
-/// Core game engine that orchestrates poker hand execution for heads-up play.
-/// Manages the deck, two players, board cards, and hand dealing logic.
+/// Core game engine that orchestrates poker hand execution.
+/// Manages the deck, the players (heads-up by default, up to nine with
+/// [`Engine::with_seats`]), board cards, and hand dealing logic.
 ///
 /// # Examples
 ///
@@ -262,12 +437,12 @@ impl HandState {
 /// // Shuffle the deck before starting a hand
 /// engine.shuffle();
 ///
-/// // Deal a complete hand (hole cards + flop + turn + river)
+/// // Deal hole cards; the board is revealed as each street opens
 /// match engine.deal_hand() {
 ///     Ok(_) => {
-///         // Hand dealt successfully
-///         assert_eq!(engine.board().len(), 5);
-///         assert!(engine.is_hand_complete());
+///         // Hand dealt successfully, preflop betting starts
+///         assert!(engine.board().is_empty());
+///         assert!(!engine.is_hand_complete());
 ///     }
 ///     Err(e) => println!("Failed to deal hand: {}", e),
 /// }
@@ -276,23 +451,39 @@ impl HandState {
 pub struct Engine {
     /// The deck used for dealing cards
     deck: Deck,
-    /// Array of exactly 2 players (heads-up poker)
-    players: [Player; 2],
+    /// Players in seat order
+    players: Vec<Player>,
     /// Blind level (determines small blind and big blind amounts)
     level: u8,
-    /// Community cards on the board (up to 5 cards: flop, turn, river)
+    /// Forced bets at each level
+    blind_structure: BlindStructure,
+    /// How bets and raises are sized
+    betting_structure: BettingStructure,
+    /// Whether the betting structure was chosen rather than the variant's default
+    betting_structure_chosen: bool,
+    /// Game dealt: hole cards per seat and how hands are made
+    variant: GameVariant,
+    /// Times the rest of the board is dealt once nobody can bet any more
+    runouts: usize,
+    /// Where the current hand's seed comes from in a [`SeedTree`], if it does
+    seed_derivation: Option<SeedDerivation>,
+    /// Community cards revealed so far (up to 5 cards: flop, turn, river)
     board: Vec<Card>,
     /// Current hand state (None if no hand in progress)
     hand_state: Option<HandState>,
-    /// Button position for current/next hand (0 or 1)
+    /// Button position for current/next hand (seat index)
     button_position: usize,
+    /// States before each action of the current hand, most recent last
+    undo_stack: Vec<UndoEntry>,
+    /// Receivers of the event stream
+    observers: Observers,
 }
 
 impl Engine {
     pub fn new(seed: Option<u64>, level: u8) -> Self {
-        let seed = seed.unwrap_or(0xA1A2_A3A4);
+        let seed = seed.unwrap_or(DEFAULT_ROOT);
         let deck = Deck::new_with_seed(seed);
-        let players = [
+        let players = vec![
             Player::new(0, STARTING_STACK, Position::Button),
             Player::new(1, STARTING_STACK, Position::BigBlind),
         ];
@@ -300,16 +491,498 @@ impl Engine {
             deck,
             players,
             level,
+            blind_structure: BlindStructure::default(),
+            betting_structure: BettingStructure::default(),
+            betting_structure_chosen: false,
+            variant: GameVariant::default(),
+            runouts: 1,
+            seed_derivation: None,
             board: Vec::with_capacity(5),
             hand_state: None,
             button_position: 0, // Player 0 starts as button
+            undo_stack: Vec::new(),
+            observers: Observers::default(),
+        }
+    }
+
+    /// Capture the engine state, mid-hand or between hands.
+    ///
+    /// While a committed deck is in play its order stays secret: the deck is
+    /// [sealed](Deck::sealed_snapshot) and the snapshot cannot be restored
+    /// until the hand is over. [`Engine::snapshot_with_secrets`] keeps it.
+    pub fn snapshot(&self) -> EngineSnapshot {
+        let mut snapshot = self.snapshot_with_secrets();
+        if self.shuffle_commitment().is_some() && self.shuffle_reveal().is_none() {
+            snapshot.deck = self.deck.sealed_snapshot();
+        }
+        snapshot
+    }
+
+    /// Capture the complete engine state, including the order of a
+    /// committed deck before it is revealed.
+    pub fn snapshot_with_secrets(&self) -> EngineSnapshot {
+        EngineSnapshot {
+            deck: self.deck.snapshot(),
+            players: self.players.clone(),
+            level: self.level,
+            blind_structure: self.blind_structure.clone(),
+            betting_structure: self.betting_structure,
+            variant: self.variant,
+            runouts: self.runouts,
+            seed_derivation: self.seed_derivation.clone(),
+            board: self.board.clone(),
+            hand_state: self.hand_state.clone(),
+            button_position: self.button_position,
+        }
+    }
+
+    /// Rebuild an engine from a snapshot.
+    ///
+    /// Continuing from the restored engine is bit-identical to continuing the
+    /// engine the snapshot was taken from. The undo history and observers are
+    /// not part of a snapshot, so actions taken before it cannot be undone
+    /// after restoring and observers have to be added again.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GameError::InvalidSnapshot` if the snapshot does not describe a
+    /// valid table (e.g. a hand-edited file with a button on seat 3 of 2) or
+    /// its deck is sealed.
+    pub fn restore(snapshot: EngineSnapshot) -> Result<Self, GameError> {
+        let seats = snapshot.players.len();
+        if !(MIN_SEATS..=MAX_SEATS).contains(&seats) {
+            return Err(GameError::InvalidSnapshot(format!(
+                "table has {} seats",
+                seats
+            )));
+        }
+        if snapshot
+            .hand_state
+            .as_ref()
+            .is_some_and(|hs| hs.total_contributions.len() != seats)
+        {
+            return Err(GameError::InvalidSnapshot(
+                "hand in progress does not match the seats".to_string(),
+            ));
+        }
+        let hand_button = snapshot.hand_state.as_ref().map(|hs| hs.button_position);
+        if snapshot.button_position >= seats || hand_button.is_some_and(|b| b >= seats) {
+            return Err(GameError::InvalidSnapshot(format!(
+                "button must be a seat below {}",
+                seats
+            )));
         }
+        if snapshot.board.len() > 5 {
+            return Err(GameError::InvalidSnapshot(format!(
+                "board has {} cards",
+                snapshot.board.len()
+            )));
+        }
+        if !(1..=MAX_RUNOUTS).contains(&snapshot.runouts) {
+            return Err(GameError::InvalidSnapshot(format!(
+                "board is run {} times",
+                snapshot.runouts
+            )));
+        }
+        if let Some(derivation) = &snapshot.seed_derivation {
+            derivation
+                .tree()
+                .map_err(|e| GameError::InvalidSnapshot(e.to_string()))?;
+        }
+        snapshot.blind_structure.validate()?;
+        snapshot.betting_structure.validate()?;
+        if snapshot
+            .players
+            .iter()
+            .any(|p| p.hole().len() > snapshot.variant.hole_cards())
+        {
+            return Err(GameError::InvalidSnapshot(format!(
+                "a seat holds more than the {} hole cards of {}",
+                snapshot.variant.hole_cards(),
+                snapshot.variant
+            )));
+        }
+        Ok(Self {
+            deck: Deck::restore(snapshot.deck)?,
+            players: snapshot.players,
+            level: snapshot.level,
+            blind_structure: snapshot.blind_structure,
+            betting_structure: snapshot.betting_structure,
+            betting_structure_chosen: true,
+            variant: snapshot.variant,
+            runouts: snapshot.runouts,
+            seed_derivation: snapshot.seed_derivation,
+            board: snapshot.board,
+            hand_state: snapshot.hand_state,
+            button_position: snapshot.button_position,
+            undo_stack: Vec::new(),
+            observers: Observers::default(),
+        })
+    }
+
+    /// Seat `seats` players, each with the default starting stack.
+    ///
+    /// The engine is heads-up unless this is called. Any hand in progress is
+    /// discarded and the button wraps onto the new table.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GameError::InvalidSeatCount` unless `seats` is between
+    /// [`MIN_SEATS`] and [`MAX_SEATS`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use axiomind_engine::engine::Engine;
+    ///
+    /// let mut engine = Engine::new(Some(1), 1).with_seats(6).unwrap();
+    /// engine.deal_hand().unwrap();
+    ///
+    /// // Seats 1 and 2 post the blinds, seat 3 is first to act
+    /// assert_eq!(engine.players().len(), 6);
+    /// assert_eq!(engine.pot(), 150);
+    /// assert_eq!(engine.current_player().unwrap(), 3);
+    /// ```
+    pub fn with_seats(mut self, seats: usize) -> Result<Self, GameError> {
+        if !(MIN_SEATS..=MAX_SEATS).contains(&seats) {
+            return Err(GameError::InvalidSeatCount(seats));
+        }
+        self.players = (0..seats)
+            .map(|seat| Player::new(seat, STARTING_STACK, Position::Other))
+            .collect();
+        self.button_position %= seats;
+        self.hand_state = None;
+        self.undo_stack.clear();
+        self.board.clear();
+        Ok(self)
+    }
+
+    /// Use a custom blind structure instead of the default table.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use axiomind_engine::engine::Engine;
+    /// use axiomind_engine::rules::{BlindLevel, BlindStructure};
+    ///
+    /// let structure = BlindStructure {
+    ///     hands_per_level: 10,
+    ///     big_blind_ante: true,
+    ///     levels: vec![BlindLevel { sb: 100, bb: 200, ante: 200 }],
+    /// };
+    /// let mut engine = Engine::new(Some(1), 1).with_blind_structure(structure);
+    /// engine.deal_hand().unwrap();
+    ///
+    /// // Small blind, big blind and the big blind's ante
+    /// assert_eq!(engine.pot(), 500);
+    /// assert_eq!(engine.to_call(0), Some(100));
+    /// ```
+    pub fn with_blind_structure(mut self, structure: BlindStructure) -> Self {
+        self.blind_structure = structure;
+        self
     }
 
-    pub fn players(&self) -> &[Player; 2] {
+    /// Deal from `deck` instead of the seeded deck.
+    ///
+    /// Use [`Deck::from_order`] or [`DealSpec`](crate::deck::DealSpec) to
+    /// reproduce a specific spot; preset positions survive the reshuffle at
+    /// the start of every hand. A short-deck engine deals `deck` short, and
+    /// commit-reveal stays on if enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GameError::NotInShortDeck` when the variant uses the short
+    /// deck and `deck` pins a card below a Six, or `GameError::InvalidDeck`
+    /// for a deck with pinned cards when commit-reveal is on.
+    pub fn with_deck(mut self, mut deck: Deck) -> Result<Self, GameError> {
+        if self.variant.uses_short_deck() && !deck.is_short() {
+            deck.set_short(true)?;
+        }
+        if self.deck.commits() && !deck.commits() {
+            deck = deck.with_commit_reveal()?;
+        }
+        self.deck = deck;
+        self.seed_derivation = None;
+        Ok(self)
+    }
+
+    /// Shuffle from `source` instead of the seeded ChaCha20 deck.
+    ///
+    /// Keeps the short deck of the variant and commit-reveal if enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GameError::InvalidShuffleSource` for a recorded source
+    /// without orders.
+    pub fn with_shuffle_source(mut self, source: ShuffleSource) -> Result<Self, GameError> {
+        let mut deck = Deck::from_source(source)?;
+        if self.deck.is_short() {
+            deck.set_short(true)?;
+        }
+        if self.deck.commits() {
+            deck = deck.with_commit_reveal()?;
+        }
+        self.deck = deck;
+        self.seed_derivation = None;
+        Ok(self)
+    }
+
+    /// Shuffle the next hand from the seed at `path` in `tree`.
+    ///
+    /// See [`Engine::set_seed_path`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `GameError::InvalidSeedDerivation` unless the deck is shuffled
+    /// by the seeded ChaCha20 RNG.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use axiomind_engine::engine::Engine;
+    /// use axiomind_engine::seed::{SeedPath, SeedTree};
+    ///
+    /// let tree = SeedTree::new(42);
+    /// let mut engine = Engine::new(None, 1)
+    ///     .with_seed_path(tree, SeedPath::hand(7))
+    ///     .unwrap();
+    /// engine.deal_hand().unwrap();
+    ///
+    /// // Hand 7 deals the same cards without playing hands 0 to 6
+    /// let seed = tree.hand_seed(SeedPath::hand(7));
+    /// let mut again = Engine::new(Some(seed), 1);
+    /// again.deal_hand().unwrap();
+    /// assert_eq!(engine.hand_seed(), Some(seed));
+    /// assert_eq!(engine.players()[0].hole(), again.players()[0].hole());
+    /// assert_eq!(engine.record_meta()["seed_derivation"]["hand"], 7);
+    /// ```
+    pub fn with_seed_path(mut self, tree: SeedTree, path: SeedPath) -> Result<Self, GameError> {
+        self.set_seed_path(tree, path)?;
+        Ok(self)
+    }
+
+    /// Reseed the deck from the seed at `path` in `tree`, for the next hand
+    /// dealt.
+    ///
+    /// The hand then shuffles exactly like `Engine::new(Some(seed), ..)` with
+    /// that hand seed, and further boards from
+    /// [`with_runouts`](Engine::with_runouts) shuffle the undealt cards from
+    /// their own runout seeds unless shuffles are committed. Hand records
+    /// carry the path in `meta.seed_derivation`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GameError::InvalidSeedDerivation` unless the deck is shuffled
+    /// by the seeded ChaCha20 RNG.
+    pub fn set_seed_path(&mut self, tree: SeedTree, path: SeedPath) -> Result<(), GameError> {
+        if self.deck.source_kind() != ShuffleKind::ChaCha20 {
+            return Err(GameError::InvalidSeedDerivation(format!(
+                "{} decks are not seeded",
+                self.deck.source_kind()
+            )));
+        }
+        self.deck.reseed(tree.hand_seed(path));
+        self.seed_derivation = Some(SeedDerivation::new(tree, path));
+        Ok(())
+    }
+
+    /// How the current hand's seed was derived, if it came from a seed tree.
+    pub fn seed_derivation(&self) -> Option<&SeedDerivation> {
+        self.seed_derivation.as_ref()
+    }
+
+    /// Seed the current hand was shuffled from, if it came from a seed tree.
+    pub fn hand_seed(&self) -> Option<u64> {
+        self.seed_derivation
+            .as_ref()
+            .and_then(|d| d.hand_seed().ok())
+    }
+
+    /// Commit to every hand's deck before dealing it.
+    ///
+    /// The [`shuffle_commitment`](Engine::shuffle_commitment) can be
+    /// published once a hand is dealt; the seed and order behind it are only
+    /// given out by [`shuffle_reveal`](Engine::shuffle_reveal) once the hand
+    /// is over. Hand records carry both in `meta.fairness`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GameError::InvalidDeck` for a deck with pinned cards.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use axiomind_engine::engine::Engine;
+    /// use axiomind_engine::player::PlayerAction;
+    ///
+    /// let mut engine = Engine::new(Some(42), 1).with_commit_reveal().unwrap();
+    /// engine.deal_hand().unwrap();
+    /// let commitment = engine.shuffle_commitment().unwrap();
+    /// assert!(engine.shuffle_reveal().is_none());
+    ///
+    /// engine.apply_action(0, PlayerAction::Fold).unwrap();
+    /// let reveal = engine.shuffle_reveal().unwrap();
+    /// assert!(reveal.verify(&commitment).is_ok());
+    /// ```
+    pub fn with_commit_reveal(mut self) -> Result<Self, GameError> {
+        self.deck = self.deck.with_commit_reveal()?;
+        Ok(self)
+    }
+
+    /// Commitment to the current hand's deck, when shuffles are committed.
+    pub fn shuffle_commitment(&self) -> Option<String> {
+        self.deck.reveal().map(ShuffleReveal::commitment)
+    }
+
+    /// Seed and order of the current hand's deck, once the hand is over.
+    pub fn shuffle_reveal(&self) -> Option<&ShuffleReveal> {
+        self.deck.reveal().filter(|_| self.is_hand_complete())
+    }
+
+    /// The blind structure in use.
+    pub fn blind_structure(&self) -> &BlindStructure {
+        &self.blind_structure
+    }
+
+    /// Size bets with `structure` instead of the variant's default (no-limit for Hold'em).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use axiomind_engine::engine::Engine;
+    /// use axiomind_engine::rules::{BettingStructure, SizeRange};
+    ///
+    /// let mut engine =
+    ///     Engine::new(Some(1), 1).with_betting_structure(BettingStructure::PotLimit);
+    /// engine.deal_hand().unwrap();
+    ///
+    /// // Call 50, then raise by the 200 in the pot: a raise to 300
+    /// let legal = engine.legal_actions(0);
+    /// assert_eq!(legal.raise_to, Some(SizeRange { min: 200, max: 300 }));
+    /// assert_eq!(legal.all_in, None);
+    /// ```
+    pub fn with_betting_structure(mut self, structure: BettingStructure) -> Self {
+        self.betting_structure = structure;
+        self.betting_structure_chosen = true;
+        self
+    }
+
+    /// The betting structure in use.
+    pub fn betting_structure(&self) -> BettingStructure {
+        self.betting_structure
+    }
+
+    /// Deal `variant` instead of Texas Hold'em from the next hand on.
+    ///
+    /// Bets are sized with the variant's default structure (pot-limit for
+    /// Omaha) unless [`Engine::with_betting_structure`] chose one. Short deck
+    /// swaps the seeded deck for the 36-card one, keeping its seed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GameError::NotInShortDeck` for short deck when the deck pins
+    /// a card below a Six, which would otherwise be dealt.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use axiomind_engine::engine::Engine;
+    /// use axiomind_engine::rules::BettingStructure;
+    /// use axiomind_engine::variant::GameVariant;
+    ///
+    /// let mut engine = Engine::new(Some(1), 1)
+    ///     .with_variant(GameVariant::Omaha)
+    ///     .unwrap();
+    /// assert_eq!(engine.betting_structure(), BettingStructure::PotLimit);
+    /// engine.deal_hand().unwrap();
+    /// assert_eq!(engine.players()[0].hole().len(), 4);
+    /// ```
+    pub fn with_variant(mut self, variant: GameVariant) -> Result<Self, GameError> {
+        if self.deck.is_short() != variant.uses_short_deck() {
+            self.deck.set_short(variant.uses_short_deck())?;
+        }
+        self.variant = variant;
+        if !self.betting_structure_chosen {
+            self.betting_structure = variant.default_betting_structure();
+        }
+        Ok(self)
+    }
+
+    /// The game variant dealt.
+    pub fn variant(&self) -> GameVariant {
+        self.variant
+    }
+
+    /// Deal the rest of the board `runouts` times once nobody can bet any
+    /// more ("run it twice"); every pot is split evenly between the boards
+    /// and each share goes to the best hand on its board.
+    ///
+    /// The board is dealt once unless this is called. Each extra board keeps
+    /// the cards already out and is completed from cards no earlier board
+    /// used. They come straight off the deck after the first board, except
+    /// in hands seeded by [`set_seed_path`](Engine::set_seed_path) without
+    /// commit-reveal, where the undealt cards are reshuffled from each
+    /// board's runout seed; either way a seed yields the same runouts. When
+    /// the cards run short, fewer boards are dealt.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GameError::InvalidRunouts` unless `runouts` is between 1 and
+    /// [`MAX_RUNOUTS`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use axiomind_engine::engine::Engine;
+    /// use axiomind_engine::player::PlayerAction;
+    ///
+    /// let mut engine = Engine::new(Some(3), 1).with_runouts(2).unwrap();
+    /// engine.deal_hand().unwrap();
+    /// engine.apply_action(0, PlayerAction::AllIn).unwrap();
+    /// engine.apply_action(1, PlayerAction::Call).unwrap();
+    ///
+    /// let boards = engine.runout_boards();
+    /// assert_eq!(boards.len(), 2);
+    /// assert_eq!(&boards[0], engine.board());
+    /// assert_eq!(engine.hand_outcome().unwrap().pots.len(), 2);
+    /// ```
+    pub fn with_runouts(mut self, runouts: usize) -> Result<Self, GameError> {
+        if !(1..=MAX_RUNOUTS).contains(&runouts) {
+            return Err(GameError::InvalidRunouts(runouts));
+        }
+        self.runouts = runouts;
+        Ok(self)
+    }
+
+    /// Times the rest of the board is dealt once nobody can bet any more.
+    pub fn runouts(&self) -> usize {
+        self.runouts
+    }
+
+    /// Every board of the current hand if it was run out more than once,
+    /// starting with [`Engine::board`]; empty otherwise.
+    pub fn runout_boards(&self) -> &[Vec<Card>] {
+        self.hand_state.as_ref().map_or(&[], |hs| &hs.runouts)
+    }
+
+    /// Report every [`EngineEvent`] from now on to `observer`.
+    ///
+    /// See [`EventQueue`](crate::events::EventQueue) for an observer that
+    /// buffers events until they are drained.
+    pub fn add_observer(&mut self, observer: impl EngineObserver + 'static) {
+        self.observers.0.push(Box::new(observer));
+    }
+
+    fn emit(&mut self, event: EngineEvent) {
+        for observer in &mut self.observers.0 {
+            observer.on_event(&event);
+        }
+    }
+
+    pub fn players(&self) -> &[Player] {
         &self.players
     }
-    pub fn players_mut(&mut self) -> &mut [Player; 2] {
+    pub fn players_mut(&mut self) -> &mut [Player] {
         &mut self.players
     }
 
@@ -322,13 +995,17 @@ impl Engine {
     }
 
     pub fn deal_hand(&mut self) -> Result<(), String> {
-        // refuse to start a hand if any player's stack is zero
-        if self.players.iter().any(|p| p.stack() == 0) {
-            return Err("Player stack zero".to_string());
+        // Seats with no chips sit the hand out; it takes two to play
+        let in_hand: Vec<bool> = self.players.iter().map(|p| p.stack() > 0).collect();
+        if in_hand.iter().filter(|&&seat| seat).count() < 2 {
+            return Err("Fewer than two players have chips".to_string());
         }
 
         // Always reshuffle to ensure a fresh deck for each hand
         self.deck.shuffle();
+        self.undo_stack.clear();
+
+        let stacks_before = self.players.iter().map(|p| p.stack()).collect();
 
         // Clear previous hand state
         self.board.clear();
@@ -336,65 +1013,293 @@ impl Engine {
             p.clear_cards();
         }
 
-        // Initialize hand state with preflop betting round
-        self.hand_state =
-            Some(HandState::new(self.level, self.button_position).map_err(|e| e.to_string())?);
+        // Seat the button and blinds; heads-up the button posts the small blind.
+        // A button on an empty seat moves on to the next seat in the hand.
+        let seats = self.players.len();
+        let button = next_in_hand(self.button_position, &in_hand);
+        self.button_position = button;
+        let (sb_seat, bb_seat) = blind_seats(button, &in_hand);
+        for (seat, player) in self.players.iter_mut().enumerate() {
+            player.set_position(if !in_hand[seat] {
+                Position::Other
+            } else if seat == button {
+                Position::Button
+            } else if seat == sb_seat {
+                Position::SmallBlind
+            } else if seat == bb_seat {
+                Position::BigBlind
+            } else {
+                Position::Other
+            });
+        }
+
+        // Deduct blinds, then antes, from player stacks. A stack shorter than its
+        // blind posts everything it has and is all-in from the start.
+        let blinds = self
+            .blind_structure
+            .level(self.level)
+            .map_err(|e| e.to_string())?;
+        let mut posted = vec![0u32; seats];
+        let mut forced_bets = Vec::new();
+        for (seat, blind, kind) in [
+            (sb_seat, blinds.sb, ForcedBet::SmallBlind),
+            (bb_seat, blinds.bb, ForcedBet::BigBlind),
+        ] {
+            let amount = blind.min(self.players[seat].stack());
+            self.players[seat].bet(amount)?;
+            posted[seat] = amount;
+            forced_bets.push((seat, kind, amount));
+        }
+        let ante_due = if self.blind_structure.big_blind_ante {
+            let mut due = vec![0u32; seats];
+            due[bb_seat] = blinds.ante;
+            due
+        } else {
+            vec![blinds.ante; seats]
+        };
+        let mut antes = vec![0u32; seats];
+        for (seat, due) in ante_due.into_iter().enumerate() {
+            let amount = due.min(self.players[seat].stack());
+            self.players[seat].bet(amount)?;
+            antes[seat] = amount;
+            if amount > 0 {
+                forced_bets.push((seat, ForcedBet::Ante, amount));
+            }
+        }
+        let all_in = self.players.iter().map(|p| p.stack() == 0).collect();
 
-        // Deduct blinds from player stacks
-        let (sb, bb) = blinds_for_level(self.level).map_err(|e| e.to_string())?;
-        self.players[self.button_position].bet(sb)?;
-        self.players[1 - self.button_position].bet(bb)?;
+        // Initialize hand state with preflop betting round
+        self.hand_state = Some(HandState::new(
+            blinds.bb, button, posted, antes, all_in, &in_hand,
+        ));
 
-        // preflop: 2 cards each
-        for _ in 0..2 {
-            for p in &mut self.players {
+        // preflop: one card to each seat in the hand per round, two rounds in
+        // Hold'em and four in Omaha. Community cards are dealt as each street
+        // opens.
+        for _ in 0..self.variant.hole_cards() {
+            for seat in (0..seats).filter(|&seat| in_hand[seat]) {
                 let c = self
                     .deck
                     .deal_card()
                     .ok_or_else(|| "deck empty".to_string())?;
-                p.give_card(c)?;
+                self.players[seat].give_card_for(self.variant, c)?;
             }
         }
-        // flop
-        self.deck.burn_card();
-        for _ in 0..3 {
-            let c = self
-                .deck
-                .deal_card()
-                .ok_or_else(|| "deck empty".to_string())?;
-            self.board.push(c);
-        }
-        // turn
-        self.deck.burn_card();
-        self.board.push(
-            self.deck
-                .deal_card()
-                .ok_or_else(|| "deck empty".to_string())?,
-        );
-        // river
+
+        self.emit(EngineEvent::HandStarted {
+            button,
+            level: self.level,
+            small_blind: blinds.sb,
+            big_blind: blinds.bb,
+            stacks: stacks_before,
+            variant: self.variant,
+        });
+        for (player_id, kind, amount) in forced_bets {
+            self.emit(EngineEvent::BlindPosted {
+                player_id,
+                kind,
+                amount,
+            });
+        }
+        for player_id in (0..seats).filter(|&seat| in_hand[seat]) {
+            let cards = self.players[player_id].hole().to_vec();
+            self.emit(EngineEvent::HoleCardsDealt { player_id, cards });
+        }
+        // Short stacks posting blinds can leave nobody able to bet
+        if self.betting_closed() {
+            self.close_round().map_err(|e| e.to_string())?;
+            self.settle_hand();
+        }
+        Ok(())
+    }
+
+    /// Burn one card and deal the community cards for a street that just opened.
+    ///
+    /// Cards come off the deck in the same order whether the board is revealed
+    /// street by street or all at once, so a seed always yields the same runout.
+    fn deal_street(&mut self, street: Street) {
+        let count = match street {
+            Street::Preflop => return,
+            Street::Flop => 3,
+            Street::Turn | Street::River => 1,
+        };
         self.deck.burn_card();
-        self.board.push(
-            self.deck
-                .deal_card()
-                .ok_or_else(|| "deck empty".to_string())?,
-        );
+        let dealt_from = self.board.len();
+        for _ in 0..count {
+            if let Some(c) = self.deck.deal_card() {
+                self.board.push(c);
+            }
+        }
+        self.emit(EngineEvent::StreetChanged {
+            street,
+            cards: self.board[dealt_from..].to_vec(),
+            board: self.board.clone(),
+        });
+    }
+
+    /// Deal every remaining street without further betting and finish the hand.
+    ///
+    /// Useful when no more betting is possible or when only the runout matters
+    /// (e.g. inspecting a seeded deal). The hand is settled at showdown.
+    ///
+    /// # Returns
+    ///
+    /// Returns the complete five-card board.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GameError::NoHandInProgress` if no hand has been dealt, or
+    /// `GameError::HandAlreadyComplete` if the hand has already ended.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use axiomind_engine::engine::Engine;
+    ///
+    /// let mut engine = Engine::new(Some(42), 1);
+    /// engine.shuffle();
+    /// engine.deal_hand().unwrap();
+    /// assert!(engine.board().is_empty());
+    ///
+    /// let board = engine.run_out_board().unwrap();
+    /// assert_eq!(board.len(), 5);
+    /// assert!(engine.is_hand_complete());
+    /// ```
+    pub fn run_out_board(&mut self) -> Result<&[Card], GameError> {
+        match self.hand_state.as_ref() {
+            None => return Err(GameError::NoHandInProgress),
+            Some(hs) if hs.is_complete => return Err(GameError::HandAlreadyComplete),
+            Some(_) => {}
+        }
+        while let Some(street) = self.advance_hand_street()? {
+            self.deal_street(street);
+        }
+        self.settle_hand();
+        Ok(&self.board)
+    }
+
+    /// Deal the next street once a betting round has closed.
+    ///
+    /// While no more betting is possible (everyone but at most one player is
+    /// all-in), nobody is asked to act: the remaining streets are dealt one
+    /// after another and the hand goes to showdown.
+    fn close_round(&mut self) -> Result<(), GameError> {
+        if self.runouts > 1 && self.board.len() < 5 && self.betting_closed() {
+            return self.run_out_repeatedly();
+        }
+        while let Some(street) = self.advance_hand_street()? {
+            self.deal_street(street);
+            if !self.betting_closed() {
+                break;
+            }
+        }
         Ok(())
     }
 
+    /// Deal the rest of the board once per runout and finish the hand.
+    ///
+    /// The first board is revealed street by street as usual. Each further
+    /// board shares the cards already out and burns and deals the rest from
+    /// the cards no earlier board used: off the deck, or for a seed path
+    /// those cards sorted and shuffled by the board's runout seed, which
+    /// leaves the deck itself untouched.
+    fn run_out_repeatedly(&mut self) -> Result<(), GameError> {
+        let common = self.board.len();
+        while let Some(street) = self.advance_hand_street()? {
+            self.deal_street(street);
+        }
+        let mut boards = vec![self.board.clone()];
+        // Seeded hands deal each further board from the undealt cards
+        // shuffled by its own runout seed, leaving the deck itself untouched
+        let seeded = self
+            .seed_derivation
+            .as_ref()
+            .filter(|_| !self.deck.commits())
+            .and_then(|d| d.tree().ok().map(|tree| (tree, d.path)));
+        let mut rest = seeded.map(|_| self.deck.undealt().to_vec());
+        for run in 1..self.runouts {
+            let mut board = self.board[..common].to_vec();
+            if let (Some((tree, path)), Some(rest)) = (seeded, rest.as_mut()) {
+                rest.sort();
+                rest.shuffle(&mut ChaCha20Rng::seed_from_u64(tree.runout_seed(path, run)));
+            }
+            while board.len() < 5 {
+                let count = if board.is_empty() { 3 } else { 1 };
+                match rest.as_mut() {
+                    Some(rest) if rest.len() > count => {
+                        board.extend(rest.drain(..=count).skip(1));
+                    }
+                    None if self.deck.remaining() > count => {
+                        self.deck.burn_card();
+                        board.extend((0..count).filter_map(|_| self.deck.deal_card()));
+                    }
+                    _ => break,
+                }
+            }
+            if board.len() < 5 {
+                break;
+            }
+            self.emit(EngineEvent::RunoutDealt {
+                run,
+                board: board.clone(),
+            });
+            boards.push(board);
+        }
+        if boards.len() > 1
+            && let Some(hand_state) = self.hand_state.as_mut()
+        {
+            hand_state.runouts = boards;
+        }
+        Ok(())
+    }
+
+    /// Whether the hand is still running but nobody can bet any more.
+    fn betting_closed(&self) -> bool {
+        self.hand_state
+            .as_ref()
+            .is_some_and(|hs| !hs.is_complete && hs.betting_round.is_closed())
+    }
+
+    /// Move the current hand to its next street.
+    /// Returns the street that opened, or `None` once the hand is complete.
+    fn advance_hand_street(&mut self) -> Result<Option<Street>, GameError> {
+        let hand_state = self
+            .hand_state
+            .as_mut()
+            .ok_or(GameError::NoHandInProgress)?;
+        if hand_state.is_complete {
+            return Ok(None);
+        }
+        hand_state.advance_street()?;
+        Ok((!hand_state.is_complete).then(|| hand_state.current_street()))
+    }
+
     pub fn board(&self) -> &Vec<Card> {
         &self.board
     }
 
+    /// Check whether the current hand has finished (by fold or after river betting).
+    /// Returns `false` if no hand has been dealt.
     pub fn is_hand_complete(&self) -> bool {
-        self.board.len() == 5
+        self.hand_state
+            .as_ref()
+            .is_some_and(|hs| hs.is_hand_complete())
     }
 
     pub fn deck_remaining(&self) -> usize {
         self.deck.remaining()
     }
 
+    /// Seat whose turn it is.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GameError::NoHandInProgress` if no hand has been dealt, or
+    /// `GameError::HandAlreadyComplete` once nobody is left to act, including
+    /// after an all-in was called and the board ran out.
     pub fn current_player(&self) -> Result<usize, GameError> {
         match self.hand_state.as_ref() {
+            Some(hand_state) if hand_state.is_complete => Err(GameError::HandAlreadyComplete),
             Some(hand_state) => Ok(hand_state.current_actor()),
             None => Err(GameError::NoHandInProgress),
         }
@@ -515,6 +1420,57 @@ impl Engine {
             .map(|hs| hs.betting_round.current_bet)
     }
 
+    /// Enumerate the actions `player_id` may take right now.
+    ///
+    /// Nothing is allowed (an empty [`LegalActions`]) when no hand is in
+    /// progress, the hand is over, or it is not this player's turn. Every
+    /// enumerated action is accepted by [`Engine::apply_action`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use axiomind_engine::engine::Engine;
+    /// use axiomind_engine::player::PlayerAction;
+    /// use axiomind_engine::rules::SizeRange;
+    ///
+    /// let mut engine = Engine::new(Some(12345), 1);
+    /// engine.deal_hand().unwrap();
+    ///
+    /// let legal = engine.legal_actions(0);
+    /// assert_eq!(legal.call, Some(50));
+    /// assert_eq!(legal.raise_to, Some(SizeRange { min: 200, max: 20_000 }));
+    /// assert!(engine.legal_actions(1).is_empty());
+    ///
+    /// engine.apply_action(0, PlayerAction::Call).unwrap();
+    /// let legal = engine.legal_actions(1);
+    /// assert!(legal.check);
+    /// assert_eq!(legal.bet, Some(SizeRange { min: 100, max: 19_900 }));
+    /// ```
+    pub fn legal_actions(&self, player_id: usize) -> LegalActions {
+        let Some(hs) = self.hand_state.as_ref() else {
+            return LegalActions::default();
+        };
+        if hs.is_complete || hs.current_actor() != player_id || hs.betting_round.folded[player_id] {
+            return LegalActions::default();
+        }
+        self.betting_structure
+            .legal_actions(&self.betting_spot(hs, player_id))
+    }
+
+    /// Betting state of `player_id` for sizing bets under the betting structure.
+    fn betting_spot(&self, hs: &HandState, player_id: usize) -> BettingSpot {
+        let round = &hs.betting_round;
+        BettingSpot {
+            stack: self.players[player_id].stack(),
+            to_call: round.to_call(player_id),
+            current_bet: round.current_bet,
+            min_raise: round.min_raise,
+            pot: hs.pot(),
+            street: round.street,
+            bets: round.bets,
+        }
+    }
+
     /// Apply a player action to the current hand state.
     /// Validates the action, updates player stacks and betting state, and progresses the hand.
     ///
@@ -563,6 +1519,13 @@ impl Engine {
                 actual: player_id,
             });
         }
+        let undo_entry = self.undo_entry()?;
+        let spot = self
+            .hand_state
+            .as_ref()
+            .map(|hs| self.betting_spot(hs, player_id))
+            .ok_or(GameError::NoHandInProgress)?;
+        let betting_structure = self.betting_structure;
         let hand_state = self
             .hand_state
             .as_mut()
@@ -578,18 +1541,19 @@ impl Engine {
             return Err(GameError::PlayerAlreadyFolded);
         }
 
-        let player_stack = self.players[player_id].stack();
-        let to_call = hand_state.betting_round.to_call(player_id);
-        let min_raise = hand_state.betting_round.min_raise;
+        let to_call = spot.to_call;
 
         // Validate the action
-        let validated_action = validate_action(player_stack, to_call, min_raise, action.clone())?;
+        let validated_action = betting_structure.validate_action(&spot, action.clone())?;
 
         // Apply the validated action
         let amount_contributed = match validated_action {
             ValidatedAction::Fold => {
                 hand_state.betting_round.folded[player_id] = true;
-                hand_state.is_complete = true; // Hand ends immediately on fold
+                // The hand ends as soon as a single player is left
+                if hand_state.betting_round.live_count() == 1 {
+                    hand_state.is_complete = true;
+                }
                 0
             }
             ValidatedAction::Check => 0,
@@ -608,6 +1572,7 @@ impl Engine {
                 hand_state.betting_round.current_bet =
                     hand_state.betting_round.contributions[player_id];
                 hand_state.betting_round.min_raise = amount; // Next raise must be at least this size
+                hand_state.betting_round.bets += 1;
                 amount
             }
             ValidatedAction::Raise(amount) => {
@@ -619,6 +1584,7 @@ impl Engine {
                 hand_state.betting_round.current_bet =
                     hand_state.betting_round.contributions[player_id];
                 hand_state.betting_round.min_raise = amount; // Next raise must be at least this size
+                hand_state.betting_round.bets += 1;
                 total_to_put_in
             }
             ValidatedAction::AllIn(amount) => {
@@ -639,6 +1605,7 @@ impl Engine {
                     // Only update min_raise if this was a full raise
                     if raise_size >= hand_state.betting_round.min_raise {
                         hand_state.betting_round.min_raise = raise_size;
+                        hand_state.betting_round.bets += 1;
                     }
                 }
                 amount
@@ -649,39 +1616,179 @@ impl Engine {
         hand_state.total_contributions[player_id] += amount_contributed;
 
         // Record the action
-        hand_state.action_history.push(ActionRecord {
+        let record = ActionRecord {
             player_id,
             street: hand_state.betting_round.street,
             action,
+            amount: Some(amount_contributed),
+        };
+        hand_state.action_history.push(record.clone());
+
+        // Increment action counter and pass the action on
+        let round = &mut hand_state.betting_round;
+        round.actions_this_round += 1;
+        round.acted[player_id] = true;
+        let round_complete = round.is_complete();
+        if let Some(next) = round.next_to_act(player_id + 1) {
+            round.to_act = next;
+        }
+        let pot = self.pot();
+        self.emit(EngineEvent::ActionApplied {
+            record,
+            amount: amount_contributed,
+            pot,
         });
 
-        // Increment action counter
-        hand_state.betting_round.actions_this_round += 1;
+        // A fold already completed the hand, so there is no street to advance to
+        if round_complete {
+            self.close_round()?;
+        }
 
-        // Check if betting round is complete
-        let active_player_count = hand_state
-            .betting_round
-            .folded
-            .iter()
-            .filter(|&&f| !f)
-            .count();
+        if self.is_hand_complete() {
+            self.settle_hand();
+        }
+
+        self.undo_stack.push(undo_entry);
+        self.hand_state.as_ref().ok_or(GameError::NoHandInProgress)
+    }
 
-        if hand_state.betting_round.is_complete(active_player_count) {
-            // If hand is already complete (fold), don't advance streets
-            if !hand_state.is_complete {
-                hand_state.advance_street()?;
+    /// Take back the most recent action of the current hand.
+    ///
+    /// Stacks, contributions, min-raise and all-in tracking, the street, the
+    /// visible board and the action history all return to exactly what they
+    /// were before the action. Undoing the action that ended a hand also
+    /// reverses its settlement.
+    ///
+    /// # Returns
+    ///
+    /// Returns the action that was taken back.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GameError::NothingToUndo` if no action has been taken since
+    /// the hand was dealt.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use axiomind_engine::engine::Engine;
+    /// use axiomind_engine::player::PlayerAction;
+    ///
+    /// let mut engine = Engine::new(Some(42), 1);
+    /// engine.deal_hand().unwrap();
+    /// engine.apply_action(0, PlayerAction::Call).unwrap();
+    /// engine.apply_action(1, PlayerAction::Check).unwrap();
+    /// assert_eq!(engine.board().len(), 3);
+    ///
+    /// let undone = engine.undo_last_action().unwrap();
+    /// assert_eq!(undone.action, PlayerAction::Check);
+    /// assert!(engine.board().is_empty());
+    /// assert_eq!(engine.current_player().unwrap(), 1);
+    /// ```
+    pub fn undo_last_action(&mut self) -> Result<ActionRecord, GameError> {
+        let undone = self
+            .action_history()
+            .pop()
+            .ok_or(GameError::NothingToUndo)?;
+        let entry = self.undo_stack.pop().ok_or(GameError::NothingToUndo)?;
+        self.apply_undo_entry(entry);
+        self.emit_rewound();
+        Ok(undone)
+    }
+
+    /// Rewind the current hand so that only its first `action_index` actions
+    /// remain, as if the later ones had never been taken.
+    ///
+    /// `rewind_to(0)` goes back to the state right after the deal.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GameError::InvalidRewind` if the hand has fewer than
+    /// `action_index` actions, or if that point is older than the undo history
+    /// (e.g. it precedes a snapshot the engine was restored from).
+    pub fn rewind_to(&mut self, action_index: usize) -> Result<(), GameError> {
+        let actions = self
+            .hand_state
+            .as_ref()
+            .map_or(0, |hs| hs.action_history.len());
+        // Actions replayed from a snapshot have no undo entries
+        let oldest = actions - self.undo_stack.len();
+        if action_index > actions || action_index < oldest {
+            return Err(GameError::InvalidRewind {
+                index: action_index,
+                actions,
+            });
+        }
+        if action_index < actions {
+            self.undo_stack.truncate(action_index - oldest + 1);
+            if let Some(entry) = self.undo_stack.pop() {
+                self.apply_undo_entry(entry);
+                self.emit_rewound();
             }
         }
+        Ok(())
+    }
+
+    fn apply_undo_entry(&mut self, entry: UndoEntry) {
+        self.players = entry.players;
+        self.board.truncate(entry.board_len);
+        self.deck.set_position(entry.deck_position);
+        self.hand_state = Some(entry.hand_state);
+    }
+
+    fn emit_rewound(&mut self) {
+        if let Some(hs) = self.hand_state.as_ref() {
+            let event = EngineEvent::Rewound {
+                action_index: hs.action_history.len(),
+                street: hs.current_street(),
+                board: self.board.clone(),
+            };
+            self.emit(event);
+        }
+    }
 
-        self.hand_state.as_ref().ok_or(GameError::NoHandInProgress)
+    /// Capture what `undo_last_action` needs to reverse the next action.
+    fn undo_entry(&self) -> Result<UndoEntry, GameError> {
+        let hand_state = self.hand_state.clone().ok_or(GameError::NoHandInProgress)?;
+        Ok(UndoEntry {
+            players: self.players.clone(),
+            board_len: self.board.len(),
+            deck_position: self.deck.position(),
+            hand_state,
+        })
     }
 
     pub fn set_level(&mut self, level: u8) {
         self.level = level;
     }
 
+    /// Seat holding the button for the current or next hand.
+    ///
+    /// Heads-up the button also posts the small blind.
+    pub fn button(&self) -> usize {
+        self.button_position
+    }
+
+    /// Move the button to `seat` for the next hand dealt.
+    ///
+    /// Seat numbers wrap around the table, so `set_button(2)` heads-up puts the
+    /// button on seat 0. A hand already in progress keeps its button.
+    pub fn set_button(&mut self, seat: usize) {
+        self.button_position = seat % self.players.len();
+    }
+
     pub fn blinds(&self) -> Result<(u32, u32), GameError> {
-        blinds_for_level(self.level)
+        self.blind_structure.blinds(self.level)
+    }
+
+    /// Blinds and ante at the current level.
+    pub fn blind_level(&self) -> Result<BlindLevel, GameError> {
+        self.blind_structure.level(self.level)
+    }
+
+    /// Current blind level.
+    pub fn level(&self) -> u8 {
+        self.level
     }
 
     /// Get the action history for the current/last hand
@@ -697,24 +1804,381 @@ impl Engine {
         self.board.clone()
     }
 
-    /// Check if hand reached showdown (completed without fold)
+    /// Check if hand reached showdown (completed with two or more players left)
     pub fn reached_showdown(&self) -> bool {
         self.hand_state
             .as_ref()
-            .map(|hs| hs.is_complete && !hs.betting_round.folded[0] && !hs.betting_round.folded[1])
+            .map(|hs| hs.is_complete && hs.betting_round.live_count() > 1)
             .unwrap_or(false)
     }
 
-    /// Get the player who folded, if any
+    /// Get the lowest seat that folded, if any
     pub fn folded_player(&self) -> Option<usize> {
-        self.hand_state.as_ref().and_then(|hs| {
-            if hs.betting_round.folded[0] {
-                Some(0)
-            } else if hs.betting_round.folded[1] {
-                Some(1)
-            } else {
-                None
+        self.hand_state
+            .as_ref()
+            .and_then(|hs| hs.betting_round.folded.iter().position(|&f| f))
+    }
+
+    /// Get the settlement of the current/last hand.
+    ///
+    /// The engine settles a hand as soon as it completes: uncalled bets are
+    /// returned, every contested pot is awarded to the best hand among the
+    /// players eligible for it, and the winners' stacks are credited.
+    ///
+    /// When a pot is split and does not divide evenly, the odd chips go to the
+    /// winners closest to the left of the button, one chip each. Heads-up this
+    /// is the big blind (out of position).
+    ///
+    /// # Returns
+    ///
+    /// Returns `Some(&HandOutcome)` once the hand is complete, or `None` while it
+    /// is still in progress or if no hand has been dealt.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use axiomind_engine::engine::{Engine, EndReason};
+    /// use axiomind_engine::player::PlayerAction;
+    ///
+    /// let mut engine = Engine::new(Some(7), 1);
+    /// engine.shuffle();
+    /// engine.deal_hand().unwrap();
+    ///
+    /// // The button folds its small blind
+    /// engine.apply_action(0, PlayerAction::Fold).unwrap();
+    ///
+    /// let outcome = engine.hand_outcome().unwrap();
+    /// assert_eq!(outcome.end_reason, EndReason::Fold);
+    /// assert_eq!(outcome.winners, vec![1]);
+    /// assert_eq!(outcome.net, vec![-50, 50]);
+    /// assert_eq!(engine.players()[1].stack(), 20_050);
+    /// ```
+    pub fn hand_outcome(&self) -> Option<&HandOutcome> {
+        self.hand_state.as_ref().and_then(|hs| hs.outcome.as_ref())
+    }
+
+    /// Metadata describing how the current hand was set up, for [`HandRecord::meta`].
+    ///
+    /// Records the level, the button seat, the full blind structure and the
+    /// betting structure so that `replay` and `verify` can reconstruct the
+    /// forced bets of custom structures and check bet sizes. Decks not
+    /// shuffled by seeded ChaCha20 add `shuffle_source`, and committed
+    /// shuffles add `fairness` with the commitment and, once the hand is
+    /// over, the reveal.
+    ///
+    /// [`HandRecord::meta`]: crate::logger::HandRecord::meta
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use axiomind_engine::engine::Engine;
+    /// use axiomind_engine::rules::BlindStructure;
+    ///
+    /// let engine = Engine::new(Some(1), 3);
+    /// let meta = engine.record_meta();
+    /// assert_eq!(meta["level"], 3);
+    /// assert_eq!(meta["button_position"], 0);
+    ///
+    /// let structure: BlindStructure =
+    ///     serde_json::from_value(meta["blind_structure"].clone()).unwrap();
+    /// assert_eq!(structure, BlindStructure::default());
+    /// assert_eq!(meta["betting_structure"]["kind"], "no_limit");
+    /// ```
+    pub fn record_meta(&self) -> serde_json::Value {
+        let mut meta = serde_json::json!({
+            "level": self.level,
+            "button_position": self.hand_button(),
+            "blind_structure": self.blind_structure,
+            "betting_structure": self.betting_structure,
+            "variant": self.variant,
+        });
+        if self.deck.source_kind() != ShuffleKind::ChaCha20 {
+            meta["shuffle_source"] = serde_json::json!(self.deck.source_kind());
+        }
+        if let Some(derivation) = &self.seed_derivation {
+            meta["seed_derivation"] = serde_json::json!(derivation);
+        }
+        if let Some(commitment) = self.shuffle_commitment() {
+            let mut fairness = serde_json::json!({ "commitment": commitment });
+            if let Some(reveal) = self.shuffle_reveal() {
+                fairness["reveal"] = serde_json::json!(reveal);
             }
+            meta["fairness"] = fairness;
+        }
+        meta
+    }
+
+    /// Hand history record of the current hand, as written by `sim`, `play`
+    /// and the web history.
+    ///
+    /// The record carries no timestamp; `meta` is [`Engine::record_meta`].
+    /// Result, showdown, net result and end reason are only filled in once the
+    /// hand is complete.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use axiomind_engine::engine::{EndReason, Engine};
+    /// use axiomind_engine::player::PlayerAction;
+    ///
+    /// let mut engine = Engine::new(Some(42), 1);
+    /// engine.deal_hand().unwrap();
+    /// engine.apply_action(0, PlayerAction::Fold).unwrap();
+    ///
+    /// let record = engine.hand_record("19700101-000001", Some(42));
+    /// assert_eq!(record.schema_version, 2);
+    /// assert_eq!(record.players[0].stack_start, 20_000);
+    /// assert_eq!(record.net_result["p0"], -50);
+    /// assert_eq!(record.end_reason, Some(EndReason::Fold));
+    /// ```
+    pub fn hand_record(&self, hand_id: impl Into<String>, seed: Option<u64>) -> HandRecord {
+        let blinds = self.blind_structure.blinds(self.level).ok();
+        let outcome = self.hand_outcome();
+        let players = self
+            .players
+            .iter()
+            .enumerate()
+            .map(|(seat, player)| {
+                // Undo this hand's chip movements to recover the starting stack
+                let stack_start = match (outcome, self.hand_state.as_ref()) {
+                    (Some(outcome), _) => (i64::from(player.stack()) - outcome.net[seat]) as u32,
+                    (None, Some(hs)) => {
+                        player.stack() + hs.total_contributions[seat] + hs.antes[seat]
+                    }
+                    (None, None) => player.stack(),
+                };
+                let hole_cards = (player.hole().len() == self.variant.hole_cards())
+                    .then(|| player.hole().to_vec());
+                PlayerRecord {
+                    id: seat_id(seat),
+                    stack_start,
+                    hole_cards,
+                }
+            })
+            .collect();
+        let mut record = HandRecord {
+            hand_id: hand_id.into(),
+            seed,
+            level: Some(self.level),
+            sb: blinds.map(|(sb, _)| sb),
+            bb: blinds.map(|(_, bb)| bb),
+            button: Some(seat_id(self.hand_button())),
+            players,
+            actions: self.action_history(),
+            board: self.board.clone(),
+            meta: Some(self.record_meta()),
+            ..HandRecord::default()
+        };
+        if let (Some(outcome), Some(hs)) = (outcome, self.hand_state.as_ref()) {
+            record.result = Some(describe_outcome(outcome));
+            if outcome.end_reason == EndReason::Showdown {
+                let shown: Vec<_> = (0..self.players.len())
+                    .filter(|&seat| !hs.betting_round.folded[seat])
+                    .map(|seat| (seat, self.players[seat].hole().to_vec()))
+                    .collect();
+                record.showdown = Some(showdown_info(
+                    outcome,
+                    &shown,
+                    &self.board,
+                    self.variant,
+                    self.runout_boards(),
+                ));
+            }
+            record.net_result = net_result(outcome);
+            record.end_reason = Some(outcome.end_reason);
+        }
+        record
+    }
+
+    /// Build what `player_id` may see of the current hand.
+    ///
+    /// The observation holds only the player's own hole cards and the public
+    /// state of the hand; AI policies receive this instead of the engine.
+    /// Returns `None` if there is no such seat.
+    pub fn observation(&self, player_id: usize) -> Option<PlayerObservation> {
+        let player = self.players.get(player_id)?;
+        Some(PlayerObservation {
+            player_id,
+            hole: player.hole().to_vec(),
+            variant: self.variant,
+            board: self.board.clone(),
+            stacks: self.players.iter().map(|p| p.stack()).collect(),
+            pot: self.pot(),
+            to_call: self.to_call(player_id).unwrap_or(0),
+            legal_actions: self.legal_actions(player_id),
+            street: self.current_street(),
+            action_history: self.action_history(),
+            button: self.hand_button(),
+            level: self.level,
         })
     }
+
+    /// Button seat of the current hand; the engine's button may already have
+    /// moved on for the next hand.
+    fn hand_button(&self) -> usize {
+        self.hand_state
+            .as_ref()
+            .map_or(self.button_position, |hs| hs.button_position)
+    }
+
+    /// Settle a completed hand and pay out its pots. Does nothing if the hand is
+    /// still in progress or has already been settled.
+    fn settle_hand(&mut self) {
+        let Some(hand_state) = self.hand_state.as_ref() else {
+            return;
+        };
+        if !hand_state.is_complete || hand_state.outcome.is_some() {
+            return;
+        }
+        let contributions = hand_state.total_contributions.clone();
+        let antes = hand_state.antes.clone();
+        let folded = hand_state.betting_round.folded.clone();
+        let boards = if hand_state.runouts.is_empty() {
+            vec![self.board.clone()]
+        } else {
+            hand_state.runouts.clone()
+        };
+        let seats = self.players.len();
+
+        // Seats in odd-chip priority order: first seat left of the hand's
+        // button, button last
+        let button = self.hand_button();
+        let order: Vec<usize> = (1..=seats)
+            .map(|offset| (button + offset) % seats)
+            .collect();
+        let live: Vec<usize> = order.iter().copied().filter(|&s| !folded[s]).collect();
+        let end_reason = if live.len() > 1 {
+            EndReason::Showdown
+        } else {
+            EndReason::Fold
+        };
+        let strengths: Vec<Vec<Option<HandStrength>>> = boards
+            .iter()
+            .map(|board| {
+                (0..seats)
+                    .map(|seat| match end_reason {
+                        EndReason::Showdown if !folded[seat] => self.showdown_strength(seat, board),
+                        _ => None,
+                    })
+                    .collect()
+            })
+            .collect();
+        let runouts: Vec<usize> = (0..boards.len()).collect();
+
+        let mut payouts = vec![0u32; seats];
+        let mut pots = Vec::new();
+        let mut uncalled_bet = None;
+        let mut layers = PotManager::from_contributions(&contributions)
+            .pots()
+            .to_vec();
+        // Antes are dead money: nobody has to match them, so they join the main pot
+        if let Some(main) = layers.first_mut() {
+            main.amount += antes.iter().sum::<u32>();
+        }
+        for pot in &layers {
+            if let [seat] = pot.eligible[..] {
+                // Nobody matched this part of the bet: it goes straight back
+                payouts[seat] += pot.amount;
+                uncalled_bet = Some((seat, pot.amount));
+                continue;
+            }
+            let contenders: Vec<usize> = live
+                .iter()
+                .copied()
+                .filter(|seat| pot.eligible.contains(seat))
+                .collect();
+            // Each runout awards an equal share of the pot on its own board
+            for (runout, share) in split_pot(pot.amount, &runouts) {
+                let winners = best_hands(self.variant, &contenders, &strengths[runout]);
+                for (seat, amount) in split_pot(share, &winners) {
+                    payouts[seat] += amount;
+                }
+                pots.push(PotAward {
+                    amount: share,
+                    winners,
+                    runout,
+                });
+            }
+        }
+        // Seats winning any share of the main pot, which is awarded first
+        let mut winners: Vec<usize> = Vec::new();
+        for pot in pots.iter().take(runouts.len()) {
+            for &seat in &pot.winners {
+                if !winners.contains(&seat) {
+                    winners.push(seat);
+                }
+            }
+        }
+
+        for (player, &payout) in self.players.iter_mut().zip(&payouts) {
+            player.add_chips(payout);
+        }
+        let net = payouts
+            .iter()
+            .zip(contributions.iter().zip(&antes))
+            .map(|(&paid, (&put_in, &ante))| i64::from(paid) - i64::from(put_in + ante))
+            .collect();
+
+        let outcome = HandOutcome {
+            end_reason,
+            winners,
+            pots,
+            uncalled_bet,
+            payouts,
+            net,
+        };
+        if let Some(hand_state) = self.hand_state.as_mut() {
+            hand_state.outcome = Some(outcome.clone());
+        }
+
+        if end_reason == EndReason::Showdown {
+            let hands = live
+                .iter()
+                .map(|&seat| (seat, self.players[seat].hole().to_vec()))
+                .collect();
+            self.emit(EngineEvent::Showdown { hands });
+        }
+        if let Some((player_id, amount)) = outcome.uncalled_bet {
+            self.emit(EngineEvent::UncalledBetReturned { player_id, amount });
+        }
+        for pot in &outcome.pots {
+            self.emit(EngineEvent::PotAwarded(pot.clone()));
+        }
+        self.emit(EngineEvent::HandCompleted(outcome));
+    }
+
+    /// Evaluate a seat's best hand from its hole cards and a full board.
+    fn showdown_strength(&self, seat: usize, board: &[Card]) -> Option<HandStrength> {
+        self.variant
+            .showdown_strength(self.players[seat].hole(), board)
+    }
+}
+
+/// Pick the contenders holding the strongest hand, preserving their order.
+/// Contenders without an evaluated hand only win if nobody could be evaluated.
+fn best_hands(
+    variant: GameVariant,
+    contenders: &[usize],
+    strengths: &[Option<HandStrength>],
+) -> Vec<usize> {
+    let mut best: Option<&HandStrength> = None;
+    let mut winners = Vec::new();
+    for &seat in contenders {
+        let Some(strength) = strengths[seat].as_ref() else {
+            continue;
+        };
+        match best.map(|b| variant.compare_hands(strength, b)) {
+            None | Some(Ordering::Greater) => {
+                best = Some(strength);
+                winners = vec![seat];
+            }
+            Some(Ordering::Equal) => winners.push(seat),
+            Some(Ordering::Less) => {}
+        }
+    }
+    if winners.is_empty() {
+        contenders.to_vec()
+    } else {
+        winners
+    }
 }
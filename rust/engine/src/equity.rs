@@ -0,0 +1,322 @@
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::cards::{Card, full_deck};
+use crate::errors::GameError;
+use crate::hand::evaluate_hand_value;
+
+/// Runouts up to which [`EquityCalculator`] enumerates exactly by default.
+///
+/// Every flop and turn qualifies; heads-up preflop (1,712,304 runouts) is sampled.
+pub const DEFAULT_EXACT_LIMIT: u64 = 100_000;
+
+/// Monte Carlo samples drawn by default when enumeration is too expensive.
+pub const DEFAULT_SAMPLES: u64 = 100_000;
+
+/// How one hand fares over all runouts considered.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct HandEquity {
+    /// Fraction of runouts won outright
+    pub win: f64,
+    /// Fraction of runouts split with at least one other hand
+    pub tie: f64,
+    /// Fraction of runouts lost
+    pub lose: f64,
+    /// Expected share of the pot: wins plus the split share of each tie
+    pub equity: f64,
+    /// Standard error of `equity`; zero when every runout was enumerated
+    pub std_error: f64,
+}
+
+/// Result of an [`EquityCalculator`] run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EquityResult {
+    /// One entry per hand, in the order the hands were given
+    pub hands: Vec<HandEquity>,
+    /// Number of runouts evaluated
+    pub runouts: u64,
+    /// Whether every possible runout was enumerated
+    pub exact: bool,
+}
+
+/// Computes win/tie/lose equity for two or more known hands.
+///
+/// The board may be empty or hold up to five cards, and dead cards (mucked or
+/// exposed) are removed from the deck along with the hands and the board.
+/// When the remaining runouts number at most the exact limit they are all
+/// enumerated; otherwise runouts are sampled with a seeded ChaCha20 RNG, so
+/// the same query and seed always give the same answer.
+///
+/// # Examples
+///
+/// ```
+/// use axiomind_engine::cards::parse_cards;
+/// use axiomind_engine::equity::EquityCalculator;
+///
+/// let hands = vec![
+///     parse_cards("AhKh").unwrap().try_into().unwrap(),
+///     parse_cards("QsQd").unwrap().try_into().unwrap(),
+/// ];
+/// let result = EquityCalculator::new(hands)
+///     .with_board(parse_cards("Jh7h2c").unwrap())
+///     .calculate()
+///     .unwrap();
+///
+/// assert!(result.exact);
+/// assert_eq!(result.runouts, 990);
+/// let total: f64 = result.hands.iter().map(|h| h.equity).sum();
+/// assert!((total - 1.0).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone)]
+pub struct EquityCalculator {
+    hands: Vec<[Card; 2]>,
+    board: Vec<Card>,
+    dead: Vec<Card>,
+    samples: u64,
+    seed: u64,
+    exact_limit: u64,
+}
+
+impl EquityCalculator {
+    pub fn new(hands: Vec<[Card; 2]>) -> Self {
+        Self {
+            hands,
+            board: Vec::new(),
+            dead: Vec::new(),
+            samples: DEFAULT_SAMPLES,
+            seed: 0,
+            exact_limit: DEFAULT_EXACT_LIMIT,
+        }
+    }
+
+    /// Community cards already dealt (0 to 5)
+    pub fn with_board(mut self, board: Vec<Card>) -> Self {
+        self.board = board;
+        self
+    }
+
+    /// Cards known to be out of the deck but in no hand
+    pub fn with_dead_cards(mut self, dead: Vec<Card>) -> Self {
+        self.dead = dead;
+        self
+    }
+
+    /// Number of Monte Carlo samples when not enumerating
+    pub fn with_samples(mut self, samples: u64) -> Self {
+        self.samples = samples;
+        self
+    }
+
+    /// Seed of the Monte Carlo RNG (default 0)
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Enumerate exactly when there are at most `limit` runouts
+    pub fn with_exact_limit(mut self, limit: u64) -> Self {
+        self.exact_limit = limit;
+        self
+    }
+
+    /// Number of distinct runouts of the remaining board.
+    pub fn runouts(&self) -> u64 {
+        let missing = 5usize.saturating_sub(self.board.len());
+        binomial(self.stub().len(), missing)
+    }
+
+    /// Run the calculation.
+    ///
+    /// # Errors
+    ///
+    /// [`GameError::InvalidEquityQuery`] for fewer than two hands, more than
+    /// five board cards or zero samples, and [`GameError::DuplicateCard`] if
+    /// a card appears twice among the hands, board and dead cards.
+    pub fn calculate(&self) -> Result<EquityResult, GameError> {
+        if self.hands.len() < 2 {
+            return Err(GameError::InvalidEquityQuery(
+                "at least two hands are required".into(),
+            ));
+        }
+        if self.board.len() > 5 {
+            return Err(GameError::InvalidEquityQuery(format!(
+                "board has {} cards, at most 5 allowed",
+                self.board.len()
+            )));
+        }
+        let mut known: Vec<Card> = Vec::new();
+        for &card in self
+            .hands
+            .iter()
+            .flatten()
+            .chain(&self.board)
+            .chain(&self.dead)
+        {
+            if known.contains(&card) {
+                return Err(GameError::DuplicateCard(card));
+            }
+            known.push(card);
+        }
+
+        let mut stub = self.stub();
+        let missing = 5 - self.board.len();
+        if stub.len() < missing {
+            return Err(GameError::InvalidEquityQuery(
+                "not enough cards left to complete the board".into(),
+            ));
+        }
+        let mut tally = Tally::new(self.hands.len());
+        let runouts = self.runouts();
+        let exact = runouts <= self.exact_limit;
+        if exact {
+            for_each_combination(&stub, missing, |runout| {
+                tally.record(&self.values(runout));
+            });
+        } else {
+            if self.samples == 0 {
+                return Err(GameError::InvalidEquityQuery(
+                    "Monte Carlo needs at least one sample".into(),
+                ));
+            }
+            let mut rng = ChaCha20Rng::seed_from_u64(self.seed);
+            for _ in 0..self.samples {
+                // Partial Fisher-Yates: the first `missing` cards become a uniform draw
+                for i in 0..missing {
+                    let j = rng.random_range(i..stub.len());
+                    stub.swap(i, j);
+                }
+                tally.record(&self.values(&stub[..missing]));
+            }
+        }
+        Ok(tally.finish(exact))
+    }
+
+    /// Cards not in any hand, on the board or dead.
+    fn stub(&self) -> Vec<Card> {
+        full_deck()
+            .into_iter()
+            .filter(|c| {
+                !self.hands.iter().flatten().any(|h| h == c)
+                    && !self.board.contains(c)
+                    && !self.dead.contains(c)
+            })
+            .collect()
+    }
+
+    /// Hand values of every hand on the board completed with `runout`.
+    fn values(&self, runout: &[Card]) -> Vec<u32> {
+        let mut cards = [self.hands[0][0]; 7];
+        for (slot, card) in cards[2..].iter_mut().zip(self.board.iter().chain(runout)) {
+            *slot = *card;
+        }
+        self.hands
+            .iter()
+            .map(|hole| {
+                cards[..2].copy_from_slice(hole);
+                evaluate_hand_value(&cards)
+            })
+            .collect()
+    }
+}
+
+/// Equity of `hands` on `board` with the default settings.
+pub fn equity(hands: &[[Card; 2]], board: &[Card]) -> Result<EquityResult, GameError> {
+    EquityCalculator::new(hands.to_vec())
+        .with_board(board.to_vec())
+        .calculate()
+}
+
+/// Running totals per hand.
+struct Tally {
+    wins: Vec<u64>,
+    ties: Vec<u64>,
+    share: Vec<f64>,
+    share_sq: Vec<f64>,
+    runouts: u64,
+}
+
+impl Tally {
+    fn new(hands: usize) -> Self {
+        Self {
+            wins: vec![0; hands],
+            ties: vec![0; hands],
+            share: vec![0.0; hands],
+            share_sq: vec![0.0; hands],
+            runouts: 0,
+        }
+    }
+
+    fn record(&mut self, values: &[u32]) {
+        let best = values.iter().copied().max().unwrap_or(0);
+        let winners = values.iter().filter(|&&v| v == best).count();
+        let share = 1.0 / winners as f64;
+        for (i, &v) in values.iter().enumerate() {
+            if v != best {
+                continue;
+            }
+            if winners == 1 {
+                self.wins[i] += 1;
+            } else {
+                self.ties[i] += 1;
+            }
+            self.share[i] += share;
+            self.share_sq[i] += share * share;
+        }
+        self.runouts += 1;
+    }
+
+    fn finish(self, exact: bool) -> EquityResult {
+        let n = self.runouts.max(1) as f64;
+        let hands = (0..self.wins.len())
+            .map(|i| {
+                let win = self.wins[i] as f64 / n;
+                let tie = self.ties[i] as f64 / n;
+                let equity = self.share[i] / n;
+                let variance = (self.share_sq[i] / n - equity * equity).max(0.0);
+                HandEquity {
+                    win,
+                    tie,
+                    lose: (1.0 - win - tie).max(0.0),
+                    equity,
+                    std_error: if exact { 0.0 } else { (variance / n).sqrt() },
+                }
+            })
+            .collect();
+        EquityResult {
+            hands,
+            runouts: self.runouts,
+            exact,
+        }
+    }
+}
+
+fn binomial(n: usize, k: usize) -> u64 {
+    if k > n {
+        return 0;
+    }
+    (0..k as u64).fold(1, |acc, i| acc * (n as u64 - i) / (i + 1))
+}
+
+/// Call `f` with every `k`-card combination of `cards`, in lexicographic order.
+fn for_each_combination(cards: &[Card], k: usize, mut f: impl FnMut(&[Card])) {
+    let n = cards.len();
+    if k > n {
+        return;
+    }
+    let mut idx: Vec<usize> = (0..k).collect();
+    let mut picked: Vec<Card> = idx.iter().map(|&i| cards[i]).collect();
+    loop {
+        f(&picked);
+        let Some(i) = (0..k).rev().find(|&i| idx[i] < n - k + i) else {
+            return;
+        };
+        idx[i] += 1;
+        for j in i + 1..k {
+            idx[j] = idx[j - 1] + 1;
+        }
+        for j in i..k {
+            picked[j] = cards[idx[j]];
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use crate::cards::Card;
 use thiserror::Error;
 
 #[derive(Debug, Error, PartialEq, Eq)]
@@ -16,4 +17,72 @@ pub enum GameError {
     NotPlayersTurn { expected: usize, actual: usize },
     #[error("Invalid level: {level}, must be at least {minimum}")]
     InvalidLevel { level: u8, minimum: u8 },
+    #[error("Invalid blind structure: {0}")]
+    InvalidBlindStructure(String),
+    #[error("Hand already in progress")]
+    HandInProgress,
+    #[error("Match is over")]
+    MatchOver,
+    #[error("Invalid snapshot: {0}")]
+    InvalidSnapshot(String),
+    #[error("No action to undo")]
+    NothingToUndo,
+    #[error("Cannot rewind to action {index}: hand has {actions} actions")]
+    InvalidRewind { index: usize, actions: usize },
+    #[error("Cannot evaluate {0} cards: a hand needs 5 to 7")]
+    InvalidHandSize(usize),
+    #[error("Cannot evaluate Omaha with {hole} hole and {board} board cards: needs 2-4 and 3-5")]
+    InvalidOmahaHand { hole: usize, board: usize },
+    #[error("Duplicate card: {0}")]
+    DuplicateCard(Card),
+    #[error("Card {0} is not in the short deck (Six through Ace)")]
+    NotInShortDeck(Card),
+    #[error("Invalid equity query: {0}")]
+    InvalidEquityQuery(String),
+    #[error("Invalid deck: {0}")]
+    InvalidDeck(String),
+    #[error("Invalid seat count: {0} (a table seats 2 to 9 players)")]
+    InvalidSeatCount(usize),
+    #[error("Invalid betting structure: {0}")]
+    InvalidBettingStructure(String),
+    #[error("Bet amount {amount} exceeds the limit of {maximum}")]
+    BetAboveLimit { amount: u32, maximum: u32 },
+    #[error("Betting is capped at {0} bets and raises this street")]
+    RaiseCapReached(u32),
+    #[error("Invalid game variant: {0}")]
+    InvalidVariant(String),
+    #[error("Invalid runout count: {0} (the board can be run 1 to 4 times)")]
+    InvalidRunouts(usize),
+    #[error("Invalid shuffle source: {0}")]
+    InvalidShuffleSource(String),
+    #[error("Shuffle check failed: {0}")]
+    ShuffleMismatch(String),
+    #[error("Invalid seed derivation: {0}")]
+    InvalidSeedDerivation(String),
+}
+
+/// Error from parsing card notation such as `"Ah"` or `"AhKd7c"`.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum CardParseError {
+    #[error("Invalid rank: {0:?}")]
+    InvalidRank(String),
+    #[error("Invalid suit: {0:?}")]
+    InvalidSuit(String),
+    #[error("Invalid card: {0:?}, expected rank then suit (e.g. \"Ah\")")]
+    InvalidCard(String),
+    #[error("Duplicate card: {0}")]
+    DuplicateCard(Card),
+    #[error("Invalid rank value: {0}, must be 2-14")]
+    InvalidRankValue(u8),
+}
+
+/// Error from parsing hand range notation such as `"22+, A2s+, KTo+"`.
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum RangeParseError {
+    #[error("Invalid range token: {0:?}")]
+    InvalidToken(String),
+    #[error("Invalid range span: {0:?}, both ends must be the same kind of hand")]
+    InvalidSpan(String),
+    #[error("Invalid range weight: {0:?}, must be a number from 0 to 1")]
+    InvalidWeight(String),
 }
@@ -0,0 +1,163 @@
+use crate::cards::Card;
+use crate::engine::{HandOutcome, PotAward};
+use crate::logger::{ActionRecord, Street};
+use crate::variant::GameVariant;
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex};
+
+/// Kind of forced bet posted before the cards are dealt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ForcedBet {
+    SmallBlind,
+    BigBlind,
+    Ante,
+}
+
+/// Something that happened at the table, reported by the [`Engine`] as it
+/// happens.
+///
+/// Events of one hand always arrive in table order: `HandStarted`, the forced
+/// bets, the hole cards, then actions interleaved with street changes, any
+/// further runouts, and finally the showdown (if any), the pots and
+/// `HandCompleted`.
+///
+/// [`Engine`]: crate::engine::Engine
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum EngineEvent {
+    /// A new hand has been shuffled and is about to be dealt
+    HandStarted {
+        /// Seat holding the button
+        button: usize,
+        /// Blind level of the hand
+        level: u8,
+        /// Blinds of the level; a short stack may post less
+        small_blind: u32,
+        big_blind: u32,
+        /// Stacks before any forced bets
+        stacks: Vec<u32>,
+        /// Variant being dealt
+        #[serde(default)]
+        variant: GameVariant,
+    },
+    /// A blind or ante went into the pot
+    BlindPosted {
+        player_id: usize,
+        kind: ForcedBet,
+        amount: u32,
+    },
+    /// A player received their hole cards (two in Hold'em, four in Omaha)
+    HoleCardsDealt { player_id: usize, cards: Vec<Card> },
+    /// An action was accepted
+    ActionApplied {
+        record: ActionRecord,
+        /// Chips the action put into the pot
+        amount: u32,
+        /// Pot size after the action
+        pot: u32,
+    },
+    /// A street opened and its community cards were dealt
+    StreetChanged {
+        street: Street,
+        /// Cards dealt for this street
+        cards: Vec<Card>,
+        /// The whole board so far
+        board: Vec<Card>,
+    },
+    /// The rest of the board was dealt again after the first runout
+    RunoutDealt {
+        /// Runout number, counting the first board as 0
+        run: usize,
+        /// The complete board of this runout
+        board: Vec<Card>,
+    },
+    /// Live players turned their hole cards over
+    Showdown { hands: Vec<(usize, Vec<Card>)> },
+    /// A contested pot was paid out
+    PotAwarded(PotAward),
+    /// Nobody matched the last bet, so its excess went back to the bettor
+    UncalledBetReturned { player_id: usize, amount: u32 },
+    /// The hand is over and all chips have been paid out
+    HandCompleted(HandOutcome),
+    /// Actions were taken back with `undo_last_action` or `rewind_to`
+    Rewound {
+        /// Number of actions left in the hand
+        action_index: usize,
+        street: Street,
+        board: Vec<Card>,
+    },
+}
+
+/// Receives every [`EngineEvent`] from an engine it has been added to.
+///
+/// Observers are called synchronously, in the order they were added, while
+/// the engine is mid-update, so they should record or forward the event and
+/// return quickly. Any `FnMut(&EngineEvent)` closure is an observer.
+pub trait EngineObserver: Send {
+    fn on_event(&mut self, event: &EngineEvent);
+}
+
+impl<F> EngineObserver for F
+where
+    F: FnMut(&EngineEvent) + Send,
+{
+    fn on_event(&mut self, event: &EngineEvent) {
+        self(event)
+    }
+}
+
+/// Observer that buffers events so they can be drained after an engine call.
+///
+/// Clones share the same buffer: add one clone to the engine and keep another
+/// to read from.
+///
+/// # Example
+///
+/// ```
+/// use axiomind_engine::engine::Engine;
+/// use axiomind_engine::events::{EngineEvent, EventQueue};
+/// use axiomind_engine::player::PlayerAction;
+///
+/// let queue = EventQueue::new();
+/// let mut engine = Engine::new(Some(42), 1);
+/// engine.add_observer(queue.clone());
+///
+/// engine.deal_hand().unwrap();
+/// let events = queue.drain();
+/// assert!(matches!(events[0], EngineEvent::HandStarted { button: 0, .. }));
+///
+/// engine.apply_action(0, PlayerAction::Fold).unwrap();
+/// let events = queue.drain();
+/// assert!(matches!(events.last(), Some(EngineEvent::HandCompleted(_))));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct EventQueue {
+    events: Arc<Mutex<Vec<EngineEvent>>>,
+}
+
+impl EventQueue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Take every event received since the last drain, oldest first.
+    pub fn drain(&self) -> Vec<EngineEvent> {
+        self.events
+            .lock()
+            .map(|mut events| std::mem::take(&mut *events))
+            .unwrap_or_default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.lock().map(|e| e.is_empty()).unwrap_or(true)
+    }
+}
+
+impl EngineObserver for EventQueue {
+    fn on_event(&mut self, event: &EngineEvent) {
+        if let Ok(mut events) = self.events.lock() {
+            events.push(event.clone());
+        }
+    }
+}
@@ -1,4 +1,10 @@
-use crate::player::{Player, Position};
+use crate::engine::{Engine, HandOutcome};
+use crate::errors::GameError;
+use crate::events::EngineObserver;
+use crate::player::{Player, PlayerAction, Position};
+use crate::rules::{BettingStructure, BlindStructure};
+use crate::seed::{DEFAULT_ROOT, SeedPath, SeedTree};
+use crate::variant::GameVariant;
 
 /// Represents the current state of a poker game including players and button position.
 /// Manages button rotation and player position synchronization for heads-up play.
@@ -55,3 +61,246 @@ impl GameState {
         }
     }
 }
+
+/// A heads-up match played hand after hand until one player has all the chips.
+///
+/// `Match` owns an [`Engine`] and carries everything that persists between
+/// hands: the players' stacks and names, the button, and the blind level. After
+/// each completed hand the button moves to the other seat, and the level goes
+/// up by one every `hands_per_level` hands of the [`BlindStructure`] until its
+/// last level. The match is over as soon as a stack reaches zero.
+///
+/// # Examples
+///
+/// ```
+/// use axiomind_engine::game::Match;
+/// use axiomind_engine::player::PlayerAction;
+///
+/// let mut m = Match::new(Some(42), 1).with_names(["Alice", "Bob"]);
+/// m.start_hand().unwrap();
+/// assert_eq!(m.button(), 0);
+///
+/// // The button folds its small blind
+/// let outcome = m.apply_action(0, PlayerAction::Fold).unwrap().unwrap();
+/// assert_eq!(outcome.winners, vec![1]);
+///
+/// // The button moves for the next hand
+/// assert_eq!(m.hands_played(), 1);
+/// assert_eq!(m.button(), 1);
+/// assert_eq!(m.stacks(), [19_950, 20_050]);
+/// ```
+#[derive(Debug)]
+pub struct Match {
+    /// Engine running the current hand; its players hold the persistent stacks
+    engine: Engine,
+    /// Seeds of every hand of the match
+    seeds: SeedTree,
+    /// Display names for each seat
+    names: [String; 2],
+    /// Blind level of the first hand
+    starting_level: u8,
+    /// Number of completed hands
+    hands_played: u32,
+    /// Whether a hand has been dealt and not yet counted as played
+    hand_in_progress: bool,
+}
+
+impl Match {
+    /// Create a match with default stacks and names, player 0 on the button.
+    ///
+    /// `seed` is the root of a [`SeedTree`]: hand `n` is shuffled from the
+    /// seed of hand `n`, so any hand of the match can be dealt again alone.
+    pub fn new(seed: Option<u64>, starting_level: u8) -> Self {
+        Self {
+            engine: Engine::new(seed, starting_level),
+            seeds: SeedTree::new(seed.unwrap_or(DEFAULT_ROOT)),
+            names: ["P1".to_string(), "P2".to_string()],
+            starting_level,
+            hands_played: 0,
+            hand_in_progress: false,
+        }
+    }
+
+    /// Set each seat's starting stack.
+    pub fn with_stacks(mut self, stacks: [u32; 2]) -> Self {
+        self.engine.players_mut().clone_from_slice(&[
+            Player::new(0, stacks[0], Position::Button),
+            Player::new(1, stacks[1], Position::BigBlind),
+        ]);
+        self
+    }
+
+    /// Set each seat's display name.
+    pub fn with_names(mut self, names: [&str; 2]) -> Self {
+        self.names = names.map(str::to_string);
+        self
+    }
+
+    /// Put the button on `seat` for the first hand.
+    pub fn with_button(mut self, seat: usize) -> Self {
+        self.engine.set_button(seat);
+        self
+    }
+
+    /// Play with a custom blind structure instead of the default one.
+    pub fn with_blind_structure(mut self, structure: BlindStructure) -> Self {
+        self.engine = self.engine.with_blind_structure(structure);
+        self
+    }
+
+    /// Size bets with `structure` instead of the variant's default (no-limit for Hold'em).
+    pub fn with_betting_structure(mut self, structure: BettingStructure) -> Self {
+        self.engine = self.engine.with_betting_structure(structure);
+        self
+    }
+
+    /// Deal `variant` instead of Texas Hold'em, betting its default structure
+    /// unless [`Match::with_betting_structure`] chose one.
+    pub fn with_variant(mut self, variant: GameVariant) -> Self {
+        self.engine = self
+            .engine
+            .with_variant(variant)
+            .expect("match decks have no pinned cards");
+        self
+    }
+
+    /// Commit to every hand's deck before dealing it.
+    ///
+    /// See [`Engine::with_commit_reveal`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `GameError::InvalidDeck` for a deck with pinned cards.
+    pub fn with_commit_reveal(mut self) -> Result<Self, GameError> {
+        self.engine = self.engine.with_commit_reveal()?;
+        Ok(self)
+    }
+
+    /// Change how many hands are played at each level. Zero keeps the starting level.
+    pub fn with_hands_per_level(self, hands: u32) -> Self {
+        let mut structure = self.engine.blind_structure().clone();
+        structure.hands_per_level = hands;
+        self.with_blind_structure(structure)
+    }
+
+    /// The engine running the current hand, for inspecting state and asking AIs for actions.
+    pub fn engine(&self) -> &Engine {
+        &self.engine
+    }
+
+    /// Report the engine's events for every hand of the match to `observer`.
+    pub fn add_observer(&mut self, observer: impl EngineObserver + 'static) {
+        self.engine.add_observer(observer);
+    }
+
+    /// Display name of a seat.
+    pub fn name(&self, seat: usize) -> &str {
+        &self.names[seat]
+    }
+
+    /// Current stacks, by seat.
+    pub fn stacks(&self) -> [u32; 2] {
+        [
+            self.engine.players()[0].stack(),
+            self.engine.players()[1].stack(),
+        ]
+    }
+
+    /// Seat holding the button for the current or next hand.
+    pub fn button(&self) -> usize {
+        self.engine.button()
+    }
+
+    /// Number of hands completed so far.
+    pub fn hands_played(&self) -> u32 {
+        self.hands_played
+    }
+
+    /// Blind level for the current or next hand.
+    pub fn level(&self) -> u8 {
+        let structure = self.engine.blind_structure();
+        let raised = self
+            .hands_played
+            .checked_div(structure.hands_per_level)
+            .unwrap_or(0);
+        // The level stops rising once it reaches the last one in the structure
+        let level = self
+            .starting_level
+            .saturating_add(u8::try_from(raised).unwrap_or(u8::MAX));
+        level.min(structure.level_count().max(self.starting_level))
+    }
+
+    /// Whether a player has run out of chips.
+    pub fn is_over(&self) -> bool {
+        !self.hand_in_progress && self.stacks().contains(&0)
+    }
+
+    /// The seat holding all the chips once the match is over.
+    pub fn winner(&self) -> Option<usize> {
+        if !self.is_over() {
+            return None;
+        }
+        self.stacks().iter().position(|&stack| stack > 0)
+    }
+
+    /// Deal the next hand at the current level with the current button.
+    ///
+    /// A hand where the blinds leave nobody able to bet is run out and
+    /// settled right away; [`Engine::hand_outcome`] then has its result.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GameError::MatchOver` once a player has no chips left, or
+    /// `GameError::HandInProgress` if the previous hand has not finished.
+    pub fn start_hand(&mut self) -> Result<(), GameError> {
+        if self.hand_in_progress {
+            return Err(GameError::HandInProgress);
+        }
+        if self.is_over() {
+            return Err(GameError::MatchOver);
+        }
+        self.engine.set_level(self.level());
+        self.engine
+            .set_seed_path(self.seeds, SeedPath::hand(u64::from(self.hands_played)))?;
+        // Both stacks are positive here, so dealing can only fail on a zero stack
+        self.engine
+            .deal_hand()
+            .map_err(|_| GameError::InsufficientChips)?;
+        self.hand_in_progress = true;
+        if self.engine.is_hand_complete() {
+            self.finish_hand();
+        }
+        Ok(())
+    }
+
+    /// Apply an action to the current hand.
+    ///
+    /// # Returns
+    ///
+    /// Returns the settled [`HandOutcome`] if this action finished the hand, in
+    /// which case the button and level are already set up for the next hand.
+    pub fn apply_action(
+        &mut self,
+        player_id: usize,
+        action: PlayerAction,
+    ) -> Result<Option<&HandOutcome>, GameError> {
+        if !self.hand_in_progress {
+            return Err(GameError::NoHandInProgress);
+        }
+        let complete = self
+            .engine
+            .apply_action(player_id, action)?
+            .is_hand_complete();
+        if complete {
+            self.finish_hand();
+        }
+        Ok(self.engine.hand_outcome().filter(|_| complete))
+    }
+
+    /// Count the completed hand and pass the button.
+    fn finish_hand(&mut self) {
+        self.hand_in_progress = false;
+        self.hands_played += 1;
+        self.engine.set_button(self.engine.button() + 1);
+    }
+}
@@ -1,6 +1,9 @@
 use std::cmp::Ordering;
+use std::fmt;
+use std::sync::OnceLock;
 
-use crate::cards::{Card, Rank, Suit};
+use crate::cards::{Card, Rank, Suit, all_ranks, all_suits, full_deck};
+use crate::errors::GameError;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
 pub enum Category {
@@ -15,6 +18,35 @@ pub enum Category {
     StraightFlush = 8,
 }
 
+impl fmt::Display for Category {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Category::HighCard => "High Card",
+            Category::OnePair => "One Pair",
+            Category::TwoPair => "Two Pair",
+            Category::ThreeOfAKind => "Three of a Kind",
+            Category::Straight => "Straight",
+            Category::Flush => "Flush",
+            Category::FullHouse => "Full House",
+            Category::FourOfAKind => "Four of a Kind",
+            Category::StraightFlush => "Straight Flush",
+        };
+        f.write_str(name)
+    }
+}
+
+impl Category {
+    /// Place of the category in short-deck play, where a flush beats a full
+    /// house; every other category keeps its place.
+    pub fn short_deck_rank(self) -> u8 {
+        match self {
+            Category::Flush => Category::FullHouse as u8,
+            Category::FullHouse => Category::Flush as u8,
+            other => other as u8,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct HandStrength {
     pub category: Category,
@@ -22,6 +54,245 @@ pub struct HandStrength {
     pub kickers: [u8; 5],
 }
 
+impl HandStrength {
+    /// Human-readable name of the hand, e.g. "Flush, Ace-high",
+    /// "Two Pair, Kings and Sevens" or "Full House, Queens full of Twos".
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use axiomind_engine::cards::parse_cards;
+    /// use axiomind_engine::hand::evaluate_best;
+    ///
+    /// let best = evaluate_best(&parse_cards("KhKd7s7c2h").unwrap()).unwrap();
+    /// assert_eq!(best.strength.describe(), "Two Pair, Kings and Sevens");
+    /// ```
+    pub fn describe(&self) -> String {
+        let [k0, k1, ..] = self.kickers;
+        let detail = match self.category {
+            Category::StraightFlush if k0 == 14 => return "Royal Flush".to_string(),
+            Category::HighCard => rank_name(k0).to_string(),
+            Category::OnePair | Category::ThreeOfAKind | Category::FourOfAKind => {
+                rank_plural(k0).to_string()
+            }
+            Category::TwoPair => format!("{} and {}", rank_plural(k0), rank_plural(k1)),
+            Category::FullHouse => format!("{} full of {}", rank_plural(k0), rank_plural(k1)),
+            Category::Straight | Category::Flush | Category::StraightFlush => {
+                format!("{}-high", rank_name(k0))
+            }
+        };
+        format!("{}, {}", self.category, detail)
+    }
+
+    /// Dense rank of the hand among the [`HAND_CLASSES`] distinct five-card
+    /// hands under the standard rankings: 1 is 7-5-4-3-2 offsuit and 7462 a
+    /// royal flush. Higher is stronger and equal indices tie, so the index
+    /// orders hands like [`compare_hands`].
+    ///
+    /// Strengths no evaluator produces fall between two classes and get the
+    /// index of the weaker one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use axiomind_engine::cards::parse_cards;
+    /// use axiomind_engine::hand::{evaluate_best, HandStrength};
+    ///
+    /// let royal = evaluate_best(&parse_cards("AsKsQsJsTs").unwrap()).unwrap();
+    /// assert_eq!(royal.strength.rank_index(), 7462);
+    /// let worst = HandStrength::from_rank_index(1).unwrap();
+    /// assert_eq!(worst.describe(), "High Card, Seven");
+    /// ```
+    pub fn rank_index(&self) -> u16 {
+        let value = strength_value(self);
+        rank_classes().partition_point(|(v, _)| *v <= value) as u16
+    }
+
+    /// The strength with the given [`rank_index`](Self::rank_index), or
+    /// `None` outside 1 to [`HAND_CLASSES`].
+    pub fn from_rank_index(index: u16) -> Option<HandStrength> {
+        let (value, _) = rank_classes().get(usize::from(index).checked_sub(1)?)?;
+        Some(strength_from_value(*value))
+    }
+}
+
+/// Number of distinct five-card hand strengths under the standard rankings.
+pub const HAND_CLASSES: u16 = 7462;
+
+/// The strongest five-card hand found among 5 to 7 cards.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct BestHand {
+    pub strength: HandStrength,
+    /// The five cards making the hand, most significant first: the made
+    /// part (pairs, trips, straight from its top card) before the kickers.
+    pub cards: [Card; 5],
+}
+
+impl BestHand {
+    /// Same as [`HandStrength::describe`].
+    pub fn describe(&self) -> String {
+        self.strength.describe()
+    }
+}
+
+/// Evaluates the best five-card hand from any 5, 6 or 7 cards.
+///
+/// Unlike [`evaluate_hand`], this works on incomplete boards (hole cards
+/// plus a flop or turn) without padding, and reports which five cards make
+/// the hand. Strengths compare with [`compare_hands`] like any other.
+///
+/// # Errors
+///
+/// [`GameError::InvalidHandSize`] for fewer than 5 or more than 7 cards, and
+/// [`GameError::DuplicateCard`] if a card is given twice.
+///
+/// # Examples
+///
+/// ```
+/// use axiomind_engine::cards::parse_cards;
+/// use axiomind_engine::hand::{evaluate_best, Category};
+///
+/// // Hole cards plus a flop
+/// let best = evaluate_best(&parse_cards("Ah5h 4h3h2c Kd").unwrap()).unwrap();
+/// assert_eq!(best.strength.category, Category::Straight);
+/// assert_eq!(best.describe(), "Straight, Five-high");
+/// assert_eq!(best.cards, parse_cards("5h4h3h2cAh").unwrap()[..]);
+/// ```
+pub fn evaluate_best(cards: &[Card]) -> Result<BestHand, GameError> {
+    best_of(cards, lookup_tables())
+}
+
+/// Evaluates the best short-deck hand from any 5, 6 or 7 cards.
+///
+/// Short deck is played with the 36 cards from Six to Ace. The ace plays
+/// low in A-6-7-8-9, the lowest straight, and a flush is harder to make
+/// than a full house, so strengths must be compared with
+/// [`compare_short_deck`].
+///
+/// # Errors
+///
+/// Fails like [`evaluate_best`], and with [`GameError::NotInShortDeck`] for
+/// a card below Six.
+///
+/// # Examples
+///
+/// ```
+/// use axiomind_engine::cards::parse_cards;
+/// use axiomind_engine::hand::evaluate_short_deck;
+///
+/// let best = evaluate_short_deck(&parse_cards("Ah6c 7d8s9h Kc").unwrap()).unwrap();
+/// assert_eq!(best.describe(), "Straight, Nine-high");
+/// assert_eq!(best.cards, parse_cards("9h8s7d6cAh").unwrap()[..]);
+/// ```
+pub fn evaluate_short_deck(cards: &[Card]) -> Result<BestHand, GameError> {
+    if let Some(card) = cards.iter().find(|c| c.rank < Rank::Six) {
+        return Err(GameError::NotInShortDeck(*card));
+    }
+    best_of(cards, short_deck_tables())
+}
+
+/// Best five of `cards` with straights read from `tables`.
+fn best_of(cards: &[Card], tables: &LookupTables) -> Result<BestHand, GameError> {
+    if !(5..=7).contains(&cards.len()) {
+        return Err(GameError::InvalidHandSize(cards.len()));
+    }
+    for (i, card) in cards.iter().enumerate() {
+        if cards[..i].contains(card) {
+            return Err(GameError::DuplicateCard(*card));
+        }
+    }
+
+    let value = value_in(cards, tables);
+    let strength = strength_from_value(value);
+    let n = cards.len();
+    let mut five = [cards[0]; 5];
+    for subset in (0u32..1 << n).filter(|m| m.count_ones() == 5) {
+        let mut picked = (0..n).filter(|i| subset & (1 << i) != 0).map(|i| cards[i]);
+        five = std::array::from_fn(|_| picked.next().unwrap_or(cards[0]));
+        if value_in(&five, tables) == value {
+            break;
+        }
+    }
+
+    let wheel = matches!(
+        strength.category,
+        Category::Straight | Category::StraightFlush
+    ) && strength.kickers[0] == tables.wheel_high;
+    let order = |c: &Card| {
+        let count = five.iter().filter(|o| o.rank == c.rank).count();
+        let rank = if wheel && c.rank == Rank::Ace {
+            1
+        } else {
+            rank_val(c.rank)
+        };
+        (count, rank, c.suit)
+    };
+    let mut sorted = five;
+    sorted.sort_by_key(|c| std::cmp::Reverse(order(c)));
+    Ok(BestHand {
+        strength,
+        cards: sorted,
+    })
+}
+
+/// Evaluates the best Omaha hand: exactly two of the hole cards plus
+/// exactly three board cards.
+///
+/// Four suited hole cards on a board with one card of the suit make no
+/// flush, and four of a kind on the board plays as trips at most.
+///
+/// # Errors
+///
+/// [`GameError::InvalidOmahaHand`] unless there are 2 to 4 hole cards and 3
+/// to 5 board cards, and [`GameError::DuplicateCard`] if a card is given twice.
+///
+/// # Examples
+///
+/// ```
+/// use axiomind_engine::cards::parse_cards;
+/// use axiomind_engine::hand::{evaluate_omaha, Category};
+///
+/// // One heart in hand is not enough for the flush
+/// let hole = parse_cards("AhKsQsJd").unwrap();
+/// let board = parse_cards("9h6h3h2h8c").unwrap();
+/// let best = evaluate_omaha(&hole, &board).unwrap();
+/// assert_eq!(best.strength.category, Category::HighCard);
+/// assert_eq!(best.describe(), "High Card, Ace");
+/// ```
+pub fn evaluate_omaha(hole: &[Card], board: &[Card]) -> Result<BestHand, GameError> {
+    if !(2..=4).contains(&hole.len()) || !(3..=5).contains(&board.len()) {
+        return Err(GameError::InvalidOmahaHand {
+            hole: hole.len(),
+            board: board.len(),
+        });
+    }
+    let all: Vec<Card> = hole.iter().chain(board).copied().collect();
+    for (i, card) in all.iter().enumerate() {
+        if all[..i].contains(card) {
+            return Err(GameError::DuplicateCard(*card));
+        }
+    }
+
+    let mut best: Option<(u32, [Card; 5])> = None;
+    for (i, &a) in hole.iter().enumerate() {
+        for &b in &hole[i + 1..] {
+            for x in 0..board.len() {
+                for y in x + 1..board.len() {
+                    for z in y + 1..board.len() {
+                        let five = [a, b, board[x], board[y], board[z]];
+                        let value = value_of(&five);
+                        if best.is_none_or(|(top, _)| value > top) {
+                            best = Some((value, five));
+                        }
+                    }
+                }
+            }
+        }
+    }
+    let (_, five) = best.expect("at least one two-plus-three combination");
+    evaluate_best(&five)
+}
+
 /// Evaluates the strength of a 7-card poker hand.
 ///
 /// Determines the best 5-card poker hand from the given 7 cards
@@ -104,7 +375,7 @@ pub fn evaluate_hand(cards: &[Card; 7]) -> HandStrength {
     if let Some(s) = flush_suit {
         by_suit[s].sort_unstable();
         by_suit[s].dedup();
-        if let Some(high) = detect_straight_high(&by_suit[s]) {
+        if let Some(high) = detect_straight_high(&by_suit[s], 1) {
             return HandStrength {
                 category: Category::StraightFlush,
                 kickers: [high, 0, 0, 0, 0],
@@ -149,7 +420,7 @@ pub fn evaluate_hand(cards: &[Card; 7]) -> HandStrength {
     }
     uniq.sort_unstable();
     uniq.dedup();
-    if let Some(high) = detect_straight_high(&uniq) {
+    if let Some(high) = detect_straight_high(&uniq, 1) {
         return HandStrength {
             category: Category::Straight,
             kickers: [high, 0, 0, 0, 0],
@@ -179,7 +450,9 @@ pub fn evaluate_hand(cards: &[Card; 7]) -> HandStrength {
         let high = prs[0];
         let low = prs[1];
         let mut k = [high, low, 0, 0, 0];
+        // A third pair can play as the kicker
         let mut rest = singles.clone();
+        rest.extend(prs.iter().skip(2).copied());
         rest.sort_unstable_by(|a, b| b.cmp(a));
         k[2] = *rest.first().unwrap_or(&0);
         return HandStrength {
@@ -269,27 +542,152 @@ pub fn compare_hands(a: &HandStrength, b: &HandStrength) -> Ordering {
     }
 }
 
-/// Evaluates hand strength using optimized bitmasking for performance.
+/// Compares two short-deck hands like [`compare_hands`], except that a
+/// flush beats a full house (see [`Category::short_deck_rank`]).
+///
+/// # Examples
+///
+/// ```
+/// use axiomind_engine::cards::parse_cards;
+/// use axiomind_engine::hand::{compare_hands, compare_short_deck, evaluate_short_deck};
+/// use std::cmp::Ordering;
+///
+/// let flush = evaluate_short_deck(&parse_cards("AhJh8h7h6h").unwrap()).unwrap();
+/// let boat = evaluate_short_deck(&parse_cards("KsKdKc6s6d").unwrap()).unwrap();
+/// assert_eq!(compare_short_deck(&flush.strength, &boat.strength), Ordering::Greater);
+/// assert_eq!(compare_hands(&flush.strength, &boat.strength), Ordering::Less);
+/// ```
+pub fn compare_short_deck(a: &HandStrength, b: &HandStrength) -> Ordering {
+    match a
+        .category
+        .short_deck_rank()
+        .cmp(&b.category.short_deck_rank())
+    {
+        Ordering::Equal => a.kickers.cmp(&b.kickers),
+        ord => ord,
+    }
+}
+
+/// Five cards making the hand with the given
+/// [`rank_index`](HandStrength::rank_index), or `None` outside 1 to
+/// [`HAND_CLASSES`].
+///
+/// The cards are one fixed representative of the class, ordered like
+/// [`evaluate_best`] orders them.
 ///
-/// This is an optimized variant of [`evaluate_hand`] that uses bitmasks for
-/// faster straight and straight flush detection. Returns identical results
-/// to the baseline implementation but with improved performance for
-/// high-throughput scenarios.
+/// # Examples
+///
+/// ```
+/// use axiomind_engine::cards::format_cards;
+/// use axiomind_engine::hand::{canonical_hand, HAND_CLASSES};
+///
+/// let royal = canonical_hand(HAND_CLASSES).unwrap();
+/// assert_eq!(royal.describe(), "Royal Flush");
+/// assert_eq!(format_cards(&canonical_hand(1).unwrap().cards), "7c5c4c3c2d");
+/// assert!(canonical_hand(0).is_none());
+/// ```
+pub fn canonical_hand(index: u16) -> Option<BestHand> {
+    let (_, cards) = rank_classes().get(usize::from(index).checked_sub(1)?)?;
+    best_of(cards, lookup_tables()).ok()
+}
+
+/// How a Hold'em hand stands against every holding an opponent could have
+/// on the same board; see [`rank_on_board`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct BoardRank {
+    /// [`HandStrength::rank_index`] of the hand made with the board
+    pub rank_index: u16,
+    /// Opponent holdings the hand beats
+    pub beats: u32,
+    /// Opponent holdings making an equal hand
+    pub ties: u32,
+    /// Opponent holdings that beat the hand
+    pub beaten_by: u32,
+}
+
+impl BoardRank {
+    /// Number of opponent holdings counted.
+    pub fn holdings(&self) -> u32 {
+        self.beats + self.ties + self.beaten_by
+    }
+
+    /// Share of opponent holdings the hand beats, ties counting half, from
+    /// 0 to 100.
+    pub fn percentile(&self) -> f64 {
+        let holdings = self.holdings().max(1) as f64;
+        (f64::from(self.beats) + f64::from(self.ties) / 2.0) / holdings * 100.0
+    }
+}
+
+/// Ranks `hole` on a board of 3 to 5 cards against every two-card holding
+/// left in the deck, by the hands made right now (no cards to come).
+///
+/// # Errors
+///
+/// [`GameError::InvalidHandSize`] unless the board has 3 to 5 cards, and
+/// [`GameError::DuplicateCard`] if a card is given twice.
+///
+/// # Examples
+///
+/// ```
+/// use axiomind_engine::cards::parse_cards;
+/// use axiomind_engine::hand::rank_on_board;
+///
+/// let hole = parse_cards("7h7d").unwrap().try_into().unwrap();
+/// let rank = rank_on_board(&hole, &parse_cards("As7c2d").unwrap()).unwrap();
+/// // Only a pair of aces makes a better set
+/// assert_eq!(rank.beaten_by, 3);
+/// assert_eq!(rank.holdings(), 1081);
+/// assert!(rank.percentile() > 99.0);
+/// ```
+pub fn rank_on_board(hole: &[Card; 2], board: &[Card]) -> Result<BoardRank, GameError> {
+    let mut cards: Vec<Card> = hole.iter().chain(board).copied().collect();
+    if !(3..=5).contains(&board.len()) {
+        return Err(GameError::InvalidHandSize(cards.len()));
+    }
+    let strength = evaluate_best(&cards)?.strength;
+    let value = strength_value(&strength);
+    let rest: Vec<Card> = full_deck()
+        .into_iter()
+        .filter(|c| !cards.contains(c))
+        .collect();
+
+    let mut rank = BoardRank {
+        rank_index: strength.rank_index(),
+        beats: 0,
+        ties: 0,
+        beaten_by: 0,
+    };
+    for (i, &first) in rest.iter().enumerate() {
+        for &second in &rest[i + 1..] {
+            cards[0] = first;
+            cards[1] = second;
+            match value_of(&cards).cmp(&value) {
+                Ordering::Less => rank.beats += 1,
+                Ordering::Equal => rank.ties += 1,
+                Ordering::Greater => rank.beaten_by += 1,
+            }
+        }
+    }
+    Ok(rank)
+}
+
+/// Evaluates hand strength using precomputed lookup tables.
+///
+/// Returns exactly what [`evaluate_hand`] returns for the same input, but
+/// without allocating: the hand is reduced to one 13-bit rank mask per suit,
+/// and straights and top kickers are read from tables indexed by those masks.
+/// Use this (or [`evaluate_hand_value`]) in simulation and equity loops.
 ///
 /// # Arguments
 ///
-/// * `cards` - Array of exactly 7 cards (2 hole cards + 5 community cards)
+/// * `cards` - Array of exactly 7 distinct cards (2 hole cards + 5 community cards)
 ///
 /// # Returns
 ///
 /// A [`HandStrength`] containing hand category and kickers, equivalent to
 /// what [`evaluate_hand`] would return for the same input.
 ///
-/// # Performance
-///
-/// Approximately 20-30% faster than baseline for straight flush detection
-/// paths. Falls back to baseline logic for other hand types.
-///
 /// # Examples
 ///
 /// ```
@@ -314,38 +712,286 @@ pub fn compare_hands(a: &HandStrength, b: &HandStrength) -> Ordering {
 /// assert_eq!(baseline.kickers, optimized.kickers);
 /// ```
 pub fn evaluate_hand_optimized(cards: &[Card; 7]) -> HandStrength {
-    // leverage the same logic but use bit masks to speed straight/flush checks
-    // Build rank bitset and suit counts
-    let mut _rank_mask: u16 = 0;
-    let mut suit_counts = [0u8; 4];
-    let mut by_suit_mask: [u16; 4] = [0, 0, 0, 0];
-    for &c in cards.iter() {
-        let r = rank_val(c.rank);
-        _rank_mask |= 1u16 << r;
-        let s = suit_index(c.suit);
-        suit_counts[s] += 1;
-        by_suit_mask[s] |= 1u16 << r;
+    strength_from_value(evaluate_hand_value(cards))
+}
+
+/// Evaluates a 7-card hand to a single integer.
+///
+/// Larger values are stronger hands and equal values tie, so comparing two
+/// values gives the same [`Ordering`] as [`compare_hands`] on the
+/// corresponding [`HandStrength`]s. The category sits in bits 20-23 and the
+/// five kickers in 4-bit groups below it, highest first.
+///
+/// # Examples
+///
+/// ```
+/// use axiomind_engine::cards::parse_cards;
+/// use axiomind_engine::hand::evaluate_hand_value;
+///
+/// let flush: [_; 7] = parse_cards("AhJh8h4h2h9c9d").unwrap().try_into().unwrap();
+/// let trips: [_; 7] = parse_cards("AhJh8h4d9s9c9d").unwrap().try_into().unwrap();
+/// assert!(evaluate_hand_value(&flush) > evaluate_hand_value(&trips));
+/// ```
+pub fn evaluate_hand_value(cards: &[Card; 7]) -> u32 {
+    value_of(cards)
+}
+
+/// Table evaluation of up to 7 distinct cards; see [`evaluate_hand_value`].
+fn value_of(cards: &[Card]) -> u32 {
+    value_in(cards, lookup_tables())
+}
+
+/// [`value_of`] with straights read from `tables`.
+fn value_in(cards: &[Card], tables: &LookupTables) -> u32 {
+    let mut suits = [0u16; 4];
+    for c in cards.iter() {
+        suits[suit_index(c.suit)] |= 1 << (rank_val(c.rank) - 2);
     }
 
-    // Straight flush via mask
-    for s in 0..4 {
-        if suit_counts[s] >= 5
-            && let Some(high) = straight_high_from_mask(by_suit_mask[s])
-        {
-            return HandStrength {
-                category: Category::StraightFlush,
-                kickers: [high, 0, 0, 0, 0],
+    // With at most 7 cards a flush rules out quads and full houses
+    for &m in &suits {
+        if m.count_ones() >= 5 {
+            let high = tables.straight_high[m as usize];
+            return if high != 0 {
+                pack(Category::StraightFlush, u32::from(high) << 16)
+            } else {
+                pack(Category::Flush, tables.top(m, 5))
             };
         }
     }
 
-    // Fallback to baseline for the rest (keeps equivalence simple)
-    evaluate_hand(cards)
+    let [c, d, h, s] = suits;
+    let any = c | d | h | s;
+    let two_plus = (c & d) | (c & h) | (c & s) | (d & h) | (d & s) | (h & s);
+    let three_plus = (c & d & h) | (c & d & s) | (c & h & s) | (d & h & s);
+    let quads = c & d & h & s;
+    let trips = three_plus & !quads;
+    let pairs = two_plus & !three_plus;
+    let singles = any & !two_plus;
+
+    if quads != 0 {
+        let kicker = any & !quads;
+        return pack(
+            Category::FourOfAKind,
+            tables.top(quads, 1) | tables.top(kicker, 1) >> 4,
+        );
+    }
+    if trips != 0 {
+        let top_trip = high_bit(trips);
+        let rest = (trips & !top_trip) | pairs;
+        if rest != 0 {
+            return pack(
+                Category::FullHouse,
+                tables.top(top_trip, 1) | tables.top(rest, 1) >> 4,
+            );
+        }
+    }
+    let high = tables.straight_high[any as usize];
+    if high != 0 {
+        return pack(Category::Straight, u32::from(high) << 16);
+    }
+    if trips != 0 {
+        return pack(
+            Category::ThreeOfAKind,
+            tables.top(trips, 1) | tables.top(singles, 2) >> 4,
+        );
+    }
+    if pairs.count_ones() >= 2 {
+        let first = high_bit(pairs);
+        let second = high_bit(pairs & !first);
+        let kicker = any & !(first | second);
+        return pack(
+            Category::TwoPair,
+            tables.top(pairs, 2) | tables.top(kicker, 1) >> 8,
+        );
+    }
+    if pairs != 0 {
+        return pack(
+            Category::OnePair,
+            tables.top(pairs, 1) | tables.top(singles, 3) >> 4,
+        );
+    }
+    pack(Category::HighCard, tables.top(singles, 5))
+}
+
+/// Precomputed per-mask answers, indexed by a 13-bit rank mask (bit 0 = Two).
+struct LookupTables {
+    /// High card of the best straight in the mask, or 0 if none
+    straight_high: Vec<u8>,
+    /// Up to five highest ranks in the mask, packed 4 bits each, highest first
+    top5: Vec<u32>,
+    /// High card of the straight with the ace played low
+    wheel_high: u8,
+}
+
+impl LookupTables {
+    /// Tables for a deck whose lowest straight runs from the ace up to
+    /// `wheel_high`.
+    fn build(wheel_high: u8) -> Self {
+        let ace_low = wheel_high - 4;
+        let size = 1usize << 13;
+        let mut straight_high = Vec::with_capacity(size);
+        let mut top5 = Vec::with_capacity(size);
+        for mask in 0..size as u16 {
+            straight_high.push(straight_high_from_mask(mask << 2, ace_low).unwrap_or(0));
+            let mut packed = 0u32;
+            let mut shift = 16;
+            for r in (2..=14u32).rev() {
+                if mask & (1 << (r - 2)) != 0 {
+                    packed |= r << shift;
+                    if shift == 0 {
+                        break;
+                    }
+                    shift -= 4;
+                }
+            }
+            top5.push(packed);
+        }
+        Self {
+            straight_high,
+            top5,
+            wheel_high,
+        }
+    }
+
+    /// The `n` highest ranks of `mask`, packed like [`evaluate_hand_value`]'s kickers.
+    fn top(&self, mask: u16, n: u32) -> u32 {
+        self.top5[mask as usize] & !((1u32 << (4 * (5 - n))) - 1)
+    }
+}
+
+fn lookup_tables() -> &'static LookupTables {
+    static TABLES: OnceLock<LookupTables> = OnceLock::new();
+    TABLES.get_or_init(|| LookupTables::build(5))
+}
+
+/// Tables for the 36-card deck, where A-6-7-8-9 is the lowest straight.
+fn short_deck_tables() -> &'static LookupTables {
+    static TABLES: OnceLock<LookupTables> = OnceLock::new();
+    TABLES.get_or_init(|| LookupTables::build(9))
+}
+
+/// Value of every five-card hand class, weakest first, with five cards
+/// making it.
+fn rank_classes() -> &'static [(u32, [Card; 5])] {
+    static CLASSES: OnceLock<Vec<(u32, [Card; 5])>> = OnceLock::new();
+    CLASSES.get_or_init(|| {
+        let ranks = all_ranks();
+        let suits = all_suits();
+        let mut classes = Vec::with_capacity(usize::from(HAND_CLASSES));
+        // Every multiset of five ranks, as non-decreasing indices into `ranks`
+        let mut picks = [0usize; 5];
+        loop {
+            // Repeated ranks take a new suit per copy, so only five distinct
+            // ranks all land in one suit
+            let mut copies = [0usize; 13];
+            let cards: [Card; 5] = std::array::from_fn(|i| {
+                let copy = copies[picks[i]];
+                copies[picks[i]] += 1;
+                Card {
+                    suit: suits[copy % 4],
+                    rank: ranks[picks[i]],
+                }
+            });
+            if copies.iter().all(|&n| n <= 1) {
+                let mut offsuit = cards;
+                offsuit[0].suit = suits[1];
+                classes.push((value_of(&cards), cards));
+                classes.push((value_of(&offsuit), offsuit));
+            } else if copies.iter().all(|&n| n <= 4) {
+                classes.push((value_of(&cards), cards));
+            }
+
+            let Some(i) = (0..5).rev().find(|&i| picks[i] < ranks.len() - 1) else {
+                break;
+            };
+            let next = picks[i] + 1;
+            picks[i..].fill(next);
+        }
+        classes.sort_unstable_by_key(|(value, _)| *value);
+        classes
+    })
+}
+
+/// [`evaluate_hand_value`]'s packing of a strength.
+fn strength_value(strength: &HandStrength) -> u32 {
+    let kickers = strength
+        .kickers
+        .iter()
+        .fold(0u32, |packed, &k| packed << 4 | u32::from(k & 0xF));
+    pack(strength.category, kickers)
+}
+
+fn high_bit(mask: u16) -> u16 {
+    if mask == 0 {
+        0
+    } else {
+        1 << (15 - mask.leading_zeros())
+    }
+}
+
+fn pack(category: Category, kickers: u32) -> u32 {
+    (category as u32) << 20 | kickers
+}
+
+fn strength_from_value(value: u32) -> HandStrength {
+    let category = match value >> 20 {
+        0 => Category::HighCard,
+        1 => Category::OnePair,
+        2 => Category::TwoPair,
+        3 => Category::ThreeOfAKind,
+        4 => Category::Straight,
+        5 => Category::Flush,
+        6 => Category::FullHouse,
+        7 => Category::FourOfAKind,
+        _ => Category::StraightFlush,
+    };
+    let mut kickers = [0u8; 5];
+    for (i, k) in kickers.iter_mut().enumerate() {
+        *k = ((value >> (16 - 4 * i)) & 0xF) as u8;
+    }
+    HandStrength { category, kickers }
 }
 
 fn rank_val(r: Rank) -> u8 {
     r as u8
 }
+
+fn rank_name(r: u8) -> &'static str {
+    match r {
+        2 => "Two",
+        3 => "Three",
+        4 => "Four",
+        5 => "Five",
+        6 => "Six",
+        7 => "Seven",
+        8 => "Eight",
+        9 => "Nine",
+        10 => "Ten",
+        11 => "Jack",
+        12 => "Queen",
+        13 => "King",
+        _ => "Ace",
+    }
+}
+
+fn rank_plural(r: u8) -> &'static str {
+    match r {
+        2 => "Twos",
+        3 => "Threes",
+        4 => "Fours",
+        5 => "Fives",
+        6 => "Sixes",
+        7 => "Sevens",
+        8 => "Eights",
+        9 => "Nines",
+        10 => "Tens",
+        11 => "Jacks",
+        12 => "Queens",
+        13 => "Kings",
+        _ => "Aces",
+    }
+}
 fn suit_index(s: Suit) -> usize {
     match s {
         Suit::Clubs => 0,
@@ -355,18 +1001,19 @@ fn suit_index(s: Suit) -> usize {
     }
 }
 
-fn detect_straight_high(sorted_unique_ranks: &[u8]) -> Option<u8> {
+/// Highest straight among the ranks, with the Ace also playing as `ace_low`
+/// (1 in a full deck, 5 in a short deck where it completes A-6-7-8-9).
+fn detect_straight_high(sorted_unique_ranks: &[u8], ace_low: u8) -> Option<u8> {
     if sorted_unique_ranks.is_empty() {
         return None;
     }
     // Ensure ascending order
-    let mut v = sorted_unique_ranks.to_vec();
-    v.sort_unstable();
-    // Ace-low straight support: treat Ace as 1 additionally
-    let mut w = v.clone();
-    if v.binary_search(&14).is_ok() {
-        w.insert(0, 1);
+    let mut w = sorted_unique_ranks.to_vec();
+    // Ace-low straight support: treat Ace as `ace_low` additionally
+    if w.contains(&14) {
+        w.push(ace_low);
     }
+    w.sort_unstable();
 
     let mut run = 1;
     let mut best_high = 0u8;
@@ -384,26 +1031,27 @@ fn detect_straight_high(sorted_unique_ranks: &[u8]) -> Option<u8> {
     if best_high == 0 {
         None
     } else {
-        Some(if best_high == 5 { 5 } else { best_high })
+        Some(best_high)
     }
 }
 
-fn straight_high_from_mask(mask: u16) -> Option<u8> {
-    // Treat Ace as 14 and optionally as 1
+/// [`detect_straight_high`] on a mask with bit `r` set for rank `r`.
+fn straight_high_from_mask(mask: u16, ace_low: u8) -> Option<u8> {
+    // Treat Ace as 14 and optionally as `ace_low`
     let mut m = mask;
     // add Ace-low if Ace present
     if (m & (1 << 14)) != 0 {
-        m |= 1 << 1;
+        m |= 1 << ace_low;
     }
-    // Sliding 5-bit window from Ace(14) down to 5
-    for high in (5..=14).rev() {
+    // Sliding 5-bit window from Ace(14) down to the lowest straight
+    for high in (u16::from(ace_low) + 4..=14).rev() {
         let window = (1u16 << (high - 4))
             | (1 << (high - 3))
             | (1 << (high - 2))
             | (1 << (high - 1))
             | (1 << high);
         if (m & window) == window {
-            return Some(if high == 5 { 5 } else { high as u8 });
+            return Some(high as u8);
         }
     }
     None
@@ -417,6 +1065,11 @@ pub fn evaluate_many_optimized(cards: &[Card; 7], n: usize) -> Vec<HandStrength>
     v
 }
 
+/// Evaluates a batch of 7-card hands with [`evaluate_hand_optimized`].
+pub fn evaluate_batch_optimized(hands: &[[Card; 7]]) -> Vec<HandStrength> {
+    hands.iter().map(evaluate_hand_optimized).collect()
+}
+
 fn detect_quads(rank_counts: &[u8; 15]) -> Option<(u8, u8)> {
     let mut quad = 0u8;
     let mut kicker = 0u8;
@@ -1,20 +1,29 @@
 //! # axiomind-engine: Poker Game Engine Core
 //!
-//! A deterministic Texas Hold'em poker engine for head-to-head (HU) play.
+//! A deterministic Texas Hold'em poker engine for head-to-head (HU) play,
+//! with tables of up to nine seats available through `Engine::with_seats`
+//! and Omaha or short deck through `Engine::with_variant`.
 //! Provides game state management, hand evaluation, and comprehensive logging
 //! with reproducible RNG for scientific comparison and debugging.
 //!
 //! ## Core Modules
 //!
-//! - [`cards`] - Card representation (Suit, Rank, Card) and deck construction
-//! - [`deck`] - Deterministic deck shuffling with ChaCha8 RNG
+//! - [`cards`] - Card representation (Suit, Rank, Card), standard notation and deck construction
+//! - [`deck`] - Deterministic deck shuffling with ChaCha8 RNG, preset orders and pinned deals,
+//!   OS or recorded shuffle sources and commit-reveal of each shuffle
 //! - [`engine`] - Main game orchestration and hand execution
-//! - [`game`] - Game state management and button rotation
+//! - [`game`] - Game state, button rotation and the multi-hand [`game::Match`] driver
 //! - [`hand`] - Poker hand evaluation and strength comparison
+//! - [`equity`] - Win/tie/lose equity of known hands, exact or by seeded Monte Carlo
+//! - [`range`] - Weighted hand ranges in standard notation (`22+, A2s+, KTo+`)
 //! - [`player`] - Player state, actions, and stack management
-//! - [`pot`] - Pot calculation and side pot handling
-//! - [`rules`] - Betting validation and blind structure
+//! - [`pot`] - Pot calculation and multi-way side pot handling
+//! - [`rules`] - Betting validation, legal action enumeration, betting and blind structures
+//! - [`variant`] - Game variants: Texas Hold'em, Omaha and short deck
+//! - [`events`] - Stream of table events reported by the engine to observers
 //! - [`logger`] - Event logging and HandRecord serialization
+//! - [`observation`] - Imperfect-information view of a hand for AI policies
+//! - [`seed`] - Per-match, per-hand and per-runout seeds derived from one root seed
 //! - [`errors`] - Error types for game operations
 //!
 //! ## Quick Start
@@ -72,10 +81,16 @@
 pub mod cards;
 pub mod deck;
 pub mod engine;
+pub mod equity;
 pub mod errors;
+pub mod events;
 pub mod game;
 pub mod hand;
 pub mod logger;
+pub mod observation;
 pub mod player;
 pub mod pot;
+pub mod range;
 pub mod rules;
+pub mod seed;
+pub mod variant;
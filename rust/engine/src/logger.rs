@@ -1,7 +1,13 @@
-use serde::{Deserialize, Serialize};
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::BTreeMap;
 
 use crate::cards::Card;
+use crate::engine::{EndReason, HandOutcome};
+use crate::events::{EngineEvent, EngineObserver};
 use crate::player::PlayerAction;
+use crate::rules::BlindStructure;
+use crate::variant::GameVariant;
 
 /// Represents a betting street in Texas Hold'em poker.
 /// Defines the four stages of a poker hand.
@@ -27,16 +33,62 @@ pub struct ActionRecord {
     pub street: Street,
     /// The action taken by the player
     pub action: PlayerAction,
+    /// Chips the action put into the pot (absent in v1 records)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub amount: Option<u32>,
+}
+
+/// Schema version written by this crate; see [`HandRecord`].
+pub const HAND_RECORD_SCHEMA_VERSION: u32 = 2;
+
+/// Identifier of a seat in hand histories: `"p0"`, `"p1"`, ...
+pub fn seat_id(seat: usize) -> String {
+    format!("p{}", seat)
+}
+
+/// A seat at the start of a hand.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct PlayerRecord {
+    /// Seat identifier (see [`seat_id`])
+    pub id: String,
+    /// Stack before blinds and antes were posted
+    pub stack_start: u32,
+    /// Hole cards dealt to the seat: two in Hold'em, four in Omaha
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hole_cards: Option<Vec<Card>>,
 }
 
 /// Complete record of a poker hand including all actions, board cards, and outcome.
 /// Serialized to JSONL format for hand history storage and replay.
+///
+/// Records are versioned by `schema_version`. Version 1 records, which have
+/// no `schema_version` field, are upgraded to the current version while
+/// deserializing: fields they lack are left empty, except level, blinds and
+/// button, which are recovered from `meta` when the engine wrote it.
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self")]
 pub struct HandRecord {
+    /// Schema version of the record ([`HAND_RECORD_SCHEMA_VERSION`] once read)
+    pub schema_version: u32,
     /// Unique identifier for this hand (format: YYYYMMDD-NNNNNN)
     pub hand_id: String,
     /// RNG seed used for deck shuffling (enables deterministic replay)
     pub seed: Option<u64>,
+    /// Blind level of the hand
+    #[serde(default)]
+    pub level: Option<u8>,
+    /// Small blind of the level
+    #[serde(default)]
+    pub sb: Option<u32>,
+    /// Big blind of the level
+    #[serde(default)]
+    pub bb: Option<u32>,
+    /// Seat holding the button
+    #[serde(default)]
+    pub button: Option<String>,
+    /// Seats dealt into the hand, in seat order
+    #[serde(default)]
+    pub players: Vec<PlayerRecord>,
     /// Chronological list of all player actions
     pub actions: Vec<ActionRecord>,
     /// Community cards on the board (up to 5 cards)
@@ -52,6 +104,224 @@ pub struct HandRecord {
     /// Showdown information if hand went to showdown
     #[serde(default)]
     pub showdown: Option<ShowdownInfo>,
+    /// Net chip change per seat identifier; sums to zero
+    #[serde(default)]
+    pub net_result: BTreeMap<String, i64>,
+    /// How the hand ended, if it was completed
+    #[serde(default)]
+    pub end_reason: Option<EndReason>,
+}
+
+impl Default for HandRecord {
+    fn default() -> Self {
+        Self {
+            schema_version: HAND_RECORD_SCHEMA_VERSION,
+            hand_id: String::new(),
+            seed: None,
+            level: None,
+            sb: None,
+            bb: None,
+            button: None,
+            players: Vec::new(),
+            actions: Vec::new(),
+            board: Vec::new(),
+            result: None,
+            ts: None,
+            meta: None,
+            showdown: None,
+            net_result: BTreeMap::new(),
+            end_reason: None,
+        }
+    }
+}
+
+impl Serialize for HandRecord {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        HandRecord::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for HandRecord {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        let version = match value.get("schema_version") {
+            None => 1,
+            Some(v) => v
+                .as_u64()
+                .ok_or_else(|| D::Error::custom("schema_version must be an integer"))?,
+        };
+        match version {
+            1 => HandRecordV1::deserialize(value)
+                .map(HandRecord::from)
+                .map_err(D::Error::custom),
+            v if v == u64::from(HAND_RECORD_SCHEMA_VERSION) => {
+                HandRecord::deserialize(value).map_err(D::Error::custom)
+            }
+            other => Err(D::Error::custom(format_args!(
+                "unsupported hand record schema_version {}",
+                other
+            ))),
+        }
+    }
+}
+
+/// Hand record as written before `schema_version` existed.
+#[derive(Deserialize)]
+struct HandRecordV1 {
+    hand_id: String,
+    seed: Option<u64>,
+    actions: Vec<ActionRecord>,
+    board: Vec<Card>,
+    result: Option<String>,
+    /// Written at the top level by `sim`
+    #[serde(default)]
+    level: Option<u8>,
+    #[serde(default)]
+    ts: Option<String>,
+    #[serde(default)]
+    meta: Option<serde_json::Value>,
+    #[serde(default)]
+    showdown: Option<ShowdownInfo>,
+}
+
+impl From<HandRecordV1> for HandRecord {
+    fn from(v1: HandRecordV1) -> Self {
+        // Engine-written meta carries the level, button and blind structure;
+        // `sim` wrote the level at the top level and left meta empty
+        let meta = v1.meta.as_ref();
+        let level = v1.level.or_else(|| {
+            meta.and_then(|m| m.get("level"))
+                .and_then(|l| l.as_u64())
+                .and_then(|l| u8::try_from(l).ok())
+        });
+        let structure = meta
+            .and_then(|m| m.get("blind_structure"))
+            .and_then(|b| serde_json::from_value::<BlindStructure>(b.clone()).ok())
+            .unwrap_or_default();
+        let blinds = level.and_then(|level| structure.blinds(level).ok());
+        let button = meta
+            .and_then(|m| m.get("button_position"))
+            .and_then(|b| b.as_u64())
+            .map(|seat| seat_id(seat as usize));
+        let end_reason = if v1.showdown.is_some() {
+            Some(EndReason::Showdown)
+        } else if v1.result.as_deref().is_some_and(|r| r.ends_with("(fold)")) {
+            Some(EndReason::Fold)
+        } else {
+            None
+        };
+        Self {
+            hand_id: v1.hand_id,
+            seed: v1.seed,
+            level,
+            sb: blinds.map(|(sb, _)| sb),
+            bb: blinds.map(|(_, bb)| bb),
+            button,
+            actions: v1.actions,
+            board: v1.board,
+            result: v1.result,
+            ts: v1.ts,
+            meta: v1.meta,
+            showdown: v1.showdown,
+            end_reason,
+            ..Self::default()
+        }
+    }
+}
+
+/// One-line summary of a settled hand, e.g. `"Player 0 wins 150 (fold)"`.
+pub(crate) fn describe_outcome(outcome: &HandOutcome) -> String {
+    let pot = outcome.pots.iter().map(|p| p.amount).sum::<u32>()
+        + outcome.uncalled_bet.map_or(0, |(_, amount)| amount);
+    match (outcome.winners.as_slice(), outcome.end_reason) {
+        ([winner], EndReason::Fold) => format!("Player {} wins {} (fold)", winner, pot),
+        ([winner], EndReason::Showdown) => format!("Player {} wins {} (showdown)", winner, pot),
+        _ => format!("Split pot {} (tie)", pot),
+    }
+}
+
+/// Showdown section of a record from the hands turned over and the settlement.
+///
+/// Each shown hand is described against `board` under `variant`, and the
+/// notes name the winning hand (or the split). When the board was run more
+/// than once, `runouts` holds every board and the notes cover each run.
+pub(crate) fn showdown_info(
+    outcome: &HandOutcome,
+    shown: &[(usize, Vec<Card>)],
+    board: &[Card],
+    variant: GameVariant,
+    runouts: &[Vec<Card>],
+) -> ShowdownInfo {
+    let describe = |cards: &[Card], board: &[Card]| {
+        variant
+            .best_hand(cards, board)
+            .ok()
+            .map(|best| best.describe())
+    };
+    let hands: Vec<ShownHand> = shown
+        .iter()
+        .map(|(seat, cards)| ShownHand {
+            player: seat_id(*seat),
+            cards: cards.clone(),
+            won: outcome.pots.iter().any(|pot| pot.winners.contains(seat)),
+            amount: outcome.payouts.get(*seat).copied().unwrap_or(0),
+            hand: describe(cards, board),
+        })
+        .collect();
+    let notes = if runouts.len() > 1 {
+        let notes: Vec<String> = runouts
+            .iter()
+            .enumerate()
+            .filter_map(|(run, board)| {
+                let awards: Vec<_> = outcome.pots.iter().filter(|p| p.runout == run).collect();
+                let split = awards.first()?.winners.len() > 1;
+                let hands = shown
+                    .iter()
+                    .map(|(seat, cards)| {
+                        let won = awards.iter().any(|pot| pot.winners.contains(seat));
+                        (won, describe(cards, board))
+                    })
+                    .collect();
+                let note = showdown_note(hands, split)?;
+                Some(format!("Run {}: {}", run + 1, note))
+            })
+            .collect();
+        (!notes.is_empty()).then(|| notes.join("; "))
+    } else {
+        let hands = hands.iter().map(|h| (h.won, h.hand.clone())).collect();
+        showdown_note(hands, outcome.winners.len() > 1)
+    };
+    ShowdownInfo {
+        winners: outcome.winners.clone(),
+        notes,
+        hands,
+        runouts: if runouts.len() > 1 {
+            runouts.to_vec()
+        } else {
+            Vec::new()
+        },
+    }
+}
+
+/// Note naming the winning hand against a losing one, or the split, from
+/// whether each shown hand won and its description.
+fn showdown_note(hands: Vec<(bool, Option<String>)>, split: bool) -> Option<String> {
+    let described = |won: bool| hands.iter().find(|(w, _)| *w == won)?.1.clone();
+    match (described(true), described(false)) {
+        (Some(winner), _) if split => Some(format!("Split with {}", winner)),
+        (Some(winner), Some(loser)) => Some(format!("{} beats {}", winner, loser)),
+        (winner, _) => winner,
+    }
+}
+
+/// Net results of a settlement keyed by seat identifier.
+pub(crate) fn net_result(outcome: &HandOutcome) -> BTreeMap<String, i64> {
+    outcome
+        .net
+        .iter()
+        .enumerate()
+        .map(|(seat, net)| (seat_id(seat), *net))
+        .collect()
 }
 
 pub fn format_hand_id(yyyymmdd: &str, seq: u32) -> String {
@@ -63,10 +333,29 @@ use std::fs::{File, create_dir_all};
 use std::io::{BufWriter, Write};
 use std::path::Path;
 
+/// Writes hand records as JSON lines.
+///
+/// Records can be written directly with [`HandLogger::write`], or the logger
+/// can be added to an engine with
+/// [`Engine::add_observer`](crate::engine::Engine::add_observer), in which
+/// case it builds and writes a record for every hand from the engine's
+/// events. Such records carry no seed or meta since the event stream does
+/// not include them; otherwise they match
+/// [`Engine::hand_record`](crate::engine::Engine::hand_record).
 pub struct HandLogger {
     writer: Option<BufWriter<File>>,
     date: String,
     seq: u32,
+    /// Record of the hand being observed
+    pending: Option<HandRecord>,
+    /// Hands turned over at the observed showdown
+    shown: Vec<(usize, Vec<Card>)>,
+    /// Variant of the hand being observed
+    variant: GameVariant,
+    /// Boards of the observed hand when it was run more than once
+    runouts: Vec<Vec<Card>>,
+    /// First write failure while observing, kept until taken
+    write_error: Option<std::io::Error>,
 }
 
 /// Information about the showdown phase when hands are revealed.
@@ -78,6 +367,28 @@ pub struct ShowdownInfo {
     /// Optional notes about the showdown (e.g., "split pot", "flush over straight")
     #[serde(default)]
     pub notes: Option<String>,
+    /// Hands turned over, in seat order (absent in v1 records)
+    #[serde(default)]
+    pub hands: Vec<ShownHand>,
+    /// Every board, the record's own first, when the rest of the board was
+    /// run more than once
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub runouts: Vec<Vec<Card>>,
+}
+
+/// Hole cards a seat showed down and what it was paid.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ShownHand {
+    /// Seat identifier (see [`seat_id`])
+    pub player: String,
+    pub cards: Vec<Card>,
+    /// Whether the seat won any pot
+    pub won: bool,
+    /// Chips paid to the seat
+    pub amount: u32,
+    /// Description of the best hand made with the board, e.g. "Flush, Ace-high"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hand: Option<String>,
 }
 
 impl HandLogger {
@@ -92,6 +403,11 @@ impl HandLogger {
             writer: Some(BufWriter::new(f)),
             date: "19700101".to_string(),
             seq: 0,
+            pending: None,
+            shown: Vec::new(),
+            variant: GameVariant::default(),
+            runouts: Vec::new(),
+            write_error: None,
         })
     }
 
@@ -100,6 +416,11 @@ impl HandLogger {
             writer: None,
             date: date.to_string(),
             seq: 0,
+            pending: None,
+            shown: Vec::new(),
+            variant: GameVariant::default(),
+            runouts: Vec::new(),
+            write_error: None,
         }
     }
 
@@ -122,4 +443,113 @@ impl HandLogger {
         }
         Ok(())
     }
+
+    /// Take the error from a failed write while observing an engine, if any.
+    pub fn take_error(&mut self) -> Option<std::io::Error> {
+        self.write_error.take()
+    }
+}
+
+impl EngineObserver for HandLogger {
+    fn on_event(&mut self, event: &EngineEvent) {
+        match event {
+            EngineEvent::HandStarted {
+                button,
+                level,
+                small_blind,
+                big_blind,
+                stacks,
+                variant,
+            } => {
+                self.shown.clear();
+                self.runouts.clear();
+                self.variant = *variant;
+                self.pending = Some(HandRecord {
+                    hand_id: self.next_id(),
+                    level: Some(*level),
+                    sb: Some(*small_blind),
+                    bb: Some(*big_blind),
+                    button: Some(seat_id(*button)),
+                    players: stacks
+                        .iter()
+                        .enumerate()
+                        .map(|(seat, stack)| PlayerRecord {
+                            id: seat_id(seat),
+                            stack_start: *stack,
+                            hole_cards: None,
+                        })
+                        .collect(),
+                    // Hold'em records stay as they always were
+                    meta: (*variant != GameVariant::Holdem)
+                        .then(|| serde_json::json!({ "variant": variant })),
+                    ..HandRecord::default()
+                });
+            }
+            EngineEvent::HoleCardsDealt { player_id, cards } => {
+                if let Some(player) = self
+                    .pending
+                    .as_mut()
+                    .and_then(|pending| pending.players.get_mut(*player_id))
+                {
+                    player.hole_cards = Some(cards.clone());
+                }
+            }
+            EngineEvent::ActionApplied { record, .. } => {
+                if let Some(pending) = self.pending.as_mut() {
+                    pending.actions.push(record.clone());
+                }
+            }
+            EngineEvent::StreetChanged { board, .. } => {
+                if let Some(pending) = self.pending.as_mut() {
+                    pending.board = board.clone();
+                }
+            }
+            EngineEvent::RunoutDealt { board, .. } => {
+                if let Some(pending) = self.pending.as_ref() {
+                    if self.runouts.is_empty() {
+                        self.runouts.push(pending.board.clone());
+                    }
+                    self.runouts.push(board.clone());
+                }
+            }
+            EngineEvent::Showdown { hands } => {
+                self.shown = hands.clone();
+            }
+            EngineEvent::Rewound {
+                action_index,
+                board,
+                ..
+            } => {
+                if let Some(pending) = self.pending.as_mut() {
+                    pending.actions.truncate(*action_index);
+                    pending.board = board.clone();
+                }
+                self.shown.clear();
+                self.runouts.clear();
+            }
+            EngineEvent::HandCompleted(outcome) => {
+                let Some(mut record) = self.pending.take() else {
+                    return;
+                };
+                record.result = Some(describe_outcome(outcome));
+                if outcome.end_reason == EndReason::Showdown {
+                    record.showdown = Some(showdown_info(
+                        outcome,
+                        &self.shown,
+                        &record.board,
+                        self.variant,
+                        &self.runouts,
+                    ));
+                }
+                record.net_result = net_result(outcome);
+                record.end_reason = Some(outcome.end_reason);
+                if let Err(e) = self.write(&record)
+                    && self.write_error.is_none()
+                {
+                    self.write_error = Some(e);
+                }
+            }
+            _ => {}
+        }
+    }
 }
@@ -0,0 +1,65 @@
+use crate::cards::Card;
+use crate::logger::{ActionRecord, Street};
+use crate::rules::LegalActions;
+use crate::variant::GameVariant;
+use serde::{Deserialize, Serialize};
+
+/// What one player is allowed to know about the hand in progress.
+///
+/// Built by [`Engine::observation`](crate::engine::Engine::observation) for
+/// the seat about to act. It carries the player's own hole cards, the board
+/// dealt so far and all public betting information, but never the opponent's
+/// hole cards or the undealt deck, so a policy that only sees this value
+/// cannot cheat.
+///
+/// # Examples
+///
+/// ```
+/// use axiomind_engine::engine::Engine;
+///
+/// let mut engine = Engine::new(Some(42), 1);
+/// engine.deal_hand().unwrap();
+///
+/// let obs = engine.observation(0).unwrap();
+/// assert_eq!(obs.player_id, 0);
+/// assert_eq!(obs.hole.len(), 2);
+/// assert!(obs.board.is_empty());
+/// assert_eq!(obs.to_call, 50);
+/// assert_eq!(obs.legal_actions.call, Some(50));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlayerObservation {
+    /// Seat this observation belongs to
+    pub player_id: usize,
+    /// The observing player's hole cards; four in Omaha
+    #[serde(default)]
+    pub hole: Vec<Card>,
+    /// Game being played
+    #[serde(default)]
+    pub variant: GameVariant,
+    /// Community cards revealed so far
+    pub board: Vec<Card>,
+    /// Chips behind for each seat
+    pub stacks: Vec<u32>,
+    /// Chips in the pot, including this street's bets
+    pub pot: u32,
+    /// Chips the observing player needs to call
+    pub to_call: u32,
+    /// Actions the observing player may take; empty when it is not their turn
+    pub legal_actions: LegalActions,
+    /// Current street, or `None` when no hand has been dealt
+    pub street: Option<Street>,
+    /// Every action taken so far in this hand
+    pub action_history: Vec<ActionRecord>,
+    /// Seat holding the button this hand
+    pub button: usize,
+    /// Blind level of this hand
+    pub level: u8,
+}
+
+impl PlayerObservation {
+    /// The observing player's stack.
+    pub fn stack(&self) -> u32 {
+        self.stacks[self.player_id]
+    }
+}
@@ -1,14 +1,20 @@
 use crate::cards::Card;
-use serde::{Deserialize, Serialize};
+use crate::variant::GameVariant;
+use serde::{Deserialize, Deserializer, Serialize};
 
-/// Represents a player's position at the table in heads-up poker.
-/// Button posts the small blind, BigBlind posts the big blind.
-#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+/// Represents a player's position at the table.
+/// Heads-up the Button posts the small blind and the other seat the big blind;
+/// with three or more seats the two seats after the button post the blinds.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub enum Position {
     /// Button position (small blind in heads-up)
     Button,
+    /// Small blind position (only used with three or more seats)
+    SmallBlind,
     /// Big blind position
     BigBlind,
+    /// Any seat not posting a blind or holding the button
+    Other,
 }
 
 /// Represents a player action during a betting round.
@@ -32,21 +38,25 @@ pub enum PlayerAction {
 /// Default starting stack size for each player in chips
 pub const STARTING_STACK: u32 = 20_000;
 
+/// Most hole cards a player can hold (Omaha)
+pub const MAX_HOLE_CARDS: usize = 4;
+
 /// Default number of hands to be played per level
 pub const HANDS_PER_LEVEL: u32 = 15;
 
 /// Represents a poker player with their chip stack, position, and hole cards.
 /// Manages chip operations (betting, adding chips) and card management.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Player {
-    /// Player identifier (0 or 1 in heads-up)
+    /// Player identifier (seat index)
     _id: usize,
     /// Current chip stack
     stack: u32,
-    /// Table position (Button or BigBlind)
+    /// Table position
     position: Position,
-    /// Hole cards (up to 2 cards)
-    hole: [Option<Card>; 2],
+    /// Hole cards in the order dealt (up to [`MAX_HOLE_CARDS`])
+    #[serde(deserialize_with = "deserialize_hole")]
+    hole: Vec<Card>,
 }
 
 impl Player {
@@ -55,7 +65,7 @@ impl Player {
             _id: id,
             stack,
             position,
-            hole: [None, None],
+            hole: Vec::with_capacity(2),
         }
     }
 
@@ -69,24 +79,34 @@ impl Player {
         self.position = pos;
     }
 
+    /// The first two hole cards, `None` where not yet dealt.
+    ///
+    /// Enough for Hold'em; use [`Player::hole`] for every card of an Omaha hand.
     pub fn hole_cards(&self) -> [Option<Card>; 2] {
-        self.hole
+        [self.hole.first().copied(), self.hole.get(1).copied()]
+    }
+
+    /// All hole cards dealt so far.
+    pub fn hole(&self) -> &[Card] {
+        &self.hole
     }
 
+    /// Give the player a Texas Hold'em hole card; at most two are held.
     pub fn give_card(&mut self, c: Card) -> Result<(), String> {
-        if self.hole[0].is_none() {
-            self.hole[0] = Some(c);
-            Ok(())
-        } else if self.hole[1].is_none() {
-            self.hole[1] = Some(c);
-            Ok(())
-        } else {
-            Err("Hole cards already full".to_string())
+        self.give_card_for(GameVariant::Holdem, c)
+    }
+
+    /// Give the player a hole card of `variant`, refusing more than it deals.
+    pub fn give_card_for(&mut self, variant: GameVariant, c: Card) -> Result<(), String> {
+        if self.hole.len() >= variant.hole_cards() {
+            return Err("Hole cards already full".to_string());
         }
+        self.hole.push(c);
+        Ok(())
     }
 
     pub fn clear_cards(&mut self) {
-        self.hole = [None, None];
+        self.hole.clear();
     }
 
     pub fn add_chips(&mut self, amount: u32) {
@@ -104,3 +124,16 @@ impl Player {
         Ok(())
     }
 }
+
+/// Read hole cards written either as a list of cards or, by older snapshots,
+/// as two slots that may be `null`.
+fn deserialize_hole<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Card>, D::Error> {
+    let slots = Vec::<Option<Card>>::deserialize(deserializer)?;
+    if slots.len() > MAX_HOLE_CARDS {
+        return Err(serde::de::Error::invalid_length(
+            slots.len(),
+            &"at most four hole cards",
+        ));
+    }
+    Ok(slots.into_iter().flatten().collect())
+}
@@ -1,23 +1,26 @@
+/// A single pot layer together with the seats that contributed enough to contest it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pot {
+    /// Chips in this pot
+    pub amount: u32,
+    /// Seats whose contribution covers this pot layer
+    pub eligible: Vec<usize>,
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct PotManager {
     main: u32,
     sides: Vec<u32>,
+    pots: Vec<Pot>,
 }
 
 impl PotManager {
-    pub fn from_contributions(contrib: [u32; 2]) -> Self {
-        let a = contrib[0];
-        let b = contrib[1];
-        let shared = a.min(b);
-        let main = shared * 2;
-        let mut sides = Vec::new();
-        if a != b {
-            let side = a.max(b) - shared;
-            if side > 0 {
-                sides.push(side);
-            }
-        }
-        Self { main, sides }
+    /// Build the pots from each seat's total contribution, indexed by seat.
+    pub fn from_contributions(contrib: impl AsRef<[u32]>) -> Self {
+        let pots = layer_pots(contrib.as_ref());
+        let main = pots.first().map_or(0, |p| p.amount);
+        let sides = pots.iter().skip(1).map(|p| p.amount).collect();
+        Self { main, sides, pots }
     }
 
     pub fn main_pot(&self) -> u32 {
@@ -26,4 +29,64 @@ impl PotManager {
     pub fn side_pots(&self) -> &[u32] {
         &self.sides
     }
+
+    /// All pot layers, main pot first, each with the seats eligible to win it.
+    ///
+    /// A layer with a single eligible seat is an uncalled bet and belongs to that seat.
+    pub fn pots(&self) -> &[Pot] {
+        &self.pots
+    }
+}
+
+/// Split total contributions into pot layers, one per distinct contribution level.
+fn layer_pots(contrib: &[u32]) -> Vec<Pot> {
+    let mut levels: Vec<u32> = contrib.iter().copied().filter(|&c| c > 0).collect();
+    levels.sort_unstable();
+    levels.dedup();
+
+    let mut pots = Vec::with_capacity(levels.len());
+    let mut prev = 0u32;
+    for level in levels {
+        let amount = contrib
+            .iter()
+            .map(|&c| c.min(level) - c.min(prev))
+            .sum::<u32>();
+        let eligible = contrib
+            .iter()
+            .enumerate()
+            .filter(|&(_, &c)| c >= level)
+            .map(|(seat, _)| seat)
+            .collect();
+        pots.push(Pot { amount, eligible });
+        prev = level;
+    }
+    pots
+}
+
+/// Divide `amount` evenly among `winners`.
+///
+/// `winners` must be ordered by odd-chip priority: when the pot does not split
+/// evenly, the leftover chips are handed out one at a time starting from the
+/// first winner. Returns `(seat, share)` pairs in the same order.
+///
+/// # Examples
+/// ```
+/// use axiomind_engine::pot::split_pot;
+///
+/// assert_eq!(split_pot(300, &[1, 0]), vec![(1, 150), (0, 150)]);
+/// assert_eq!(split_pot(301, &[1, 0]), vec![(1, 151), (0, 150)]);
+/// assert!(split_pot(100, &[]).is_empty());
+/// ```
+pub fn split_pot(amount: u32, winners: &[usize]) -> Vec<(usize, u32)> {
+    if winners.is_empty() {
+        return Vec::new();
+    }
+    let count = winners.len() as u32;
+    let share = amount / count;
+    let remainder = (amount % count) as usize;
+    winners
+        .iter()
+        .enumerate()
+        .map(|(i, &seat)| (seat, share + u32::from(i < remainder)))
+        .collect()
 }
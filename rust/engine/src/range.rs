@@ -0,0 +1,594 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::cards::{Card, Rank, all_ranks, all_suits, parse_cards};
+use crate::errors::RangeParseError;
+
+/// Number of distinct two-card starting hands.
+pub const COMBOS: usize = 1326;
+
+/// One specific two-card holding such as `AhKd`, stored higher card first.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Combo {
+    high: Card,
+    low: Card,
+}
+
+impl Combo {
+    /// Combine two cards in either order; `None` if they are the same card.
+    pub fn new(a: Card, b: Card) -> Option<Combo> {
+        match (a.rank, a.suit).cmp(&(b.rank, b.suit)) {
+            Ordering::Greater => Some(Combo { high: a, low: b }),
+            Ordering::Less => Some(Combo { high: b, low: a }),
+            Ordering::Equal => None,
+        }
+    }
+
+    /// Both cards, higher rank first (suits break ties: s > h > d > c).
+    pub fn cards(&self) -> [Card; 2] {
+        [self.high, self.low]
+    }
+
+    pub fn contains(&self, card: Card) -> bool {
+        self.high == card || self.low == card
+    }
+
+    /// Dense index in `0..COMBOS`, stable across runs.
+    pub fn index(&self) -> usize {
+        let (a, b) = (card_index(self.high), card_index(self.low));
+        let (i, j) = (a.min(b), a.max(b));
+        j * (j - 1) / 2 + i
+    }
+
+    /// Inverse of [`Combo::index`]; `None` for indices of `COMBOS` and up.
+    pub fn from_index(index: usize) -> Option<Combo> {
+        if index >= COMBOS {
+            return None;
+        }
+        let mut j = 1;
+        while (j + 1) * j / 2 <= index {
+            j += 1;
+        }
+        let i = index - j * (j - 1) / 2;
+        Combo::new(card_from_index(i), card_from_index(j))
+    }
+
+    /// All 1326 combos in index order.
+    pub fn all() -> impl Iterator<Item = Combo> {
+        (0..COMBOS).filter_map(Combo::from_index)
+    }
+
+    /// The 169-way class this combo belongs to, e.g. `AKs` for `AhKh`.
+    pub fn class(&self) -> HandClass {
+        HandClass::new(
+            self.high.rank,
+            self.low.rank,
+            self.high.suit == self.low.suit,
+        )
+    }
+}
+
+/// Formats both cards back to back, e.g. `AhKd`.
+impl fmt::Display for Combo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.high, self.low)
+    }
+}
+
+impl FromStr for Combo {
+    type Err = RangeParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || RangeParseError::InvalidToken(s.to_string());
+        match parse_cards(s).map_err(|_| invalid())?[..] {
+            [a, b] => Combo::new(a, b).ok_or_else(invalid),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+/// Whether a [`HandClass`] is a pocket pair, suited or offsuit.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
+pub enum ClassKind {
+    Pair,
+    Suited,
+    Offsuit,
+}
+
+/// One of the 169 strategically distinct starting hands: `AA`, `AKs`, `AKo`...
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct HandClass {
+    high: Rank,
+    low: Rank,
+    kind: ClassKind,
+}
+
+impl HandClass {
+    /// Class of two ranks in either order; `suited` is ignored for pairs.
+    pub fn new(a: Rank, b: Rank, suited: bool) -> HandClass {
+        let kind = if a == b {
+            ClassKind::Pair
+        } else if suited {
+            ClassKind::Suited
+        } else {
+            ClassKind::Offsuit
+        };
+        HandClass {
+            high: a.max(b),
+            low: a.min(b),
+            kind,
+        }
+    }
+
+    pub fn high(&self) -> Rank {
+        self.high
+    }
+
+    pub fn low(&self) -> Rank {
+        self.low
+    }
+
+    pub fn kind(&self) -> ClassKind {
+        self.kind
+    }
+
+    /// Every concrete combo of the class: 6 for pairs, 4 suited, 12 offsuit.
+    pub fn combos(&self) -> Vec<Combo> {
+        let mut combos = Vec::with_capacity(12);
+        for a in all_suits() {
+            for b in all_suits() {
+                let keep = match self.kind {
+                    ClassKind::Pair => a > b,
+                    ClassKind::Suited => a == b,
+                    ClassKind::Offsuit => a != b,
+                };
+                if keep {
+                    combos.extend(Combo::new(
+                        Card {
+                            rank: self.high,
+                            suit: a,
+                        },
+                        Card {
+                            rank: self.low,
+                            suit: b,
+                        },
+                    ));
+                }
+            }
+        }
+        combos
+    }
+
+    /// `(row, column)` in the 13x13 grid, Aces first.
+    ///
+    /// Pairs sit on the diagonal, suited hands above it and offsuit hands
+    /// below it.
+    pub fn grid_position(&self) -> (usize, usize) {
+        let (h, l) = (grid_index(self.high), grid_index(self.low));
+        match self.kind {
+            ClassKind::Offsuit => (l, h),
+            _ => (h, l),
+        }
+    }
+
+    /// All 169 classes in grid order, row by row.
+    pub fn all() -> Vec<HandClass> {
+        let ranks = grid_ranks();
+        let mut classes = Vec::with_capacity(169);
+        for (row, &a) in ranks.iter().enumerate() {
+            for (col, &b) in ranks.iter().enumerate() {
+                classes.push(HandClass::new(a, b, row < col));
+            }
+        }
+        classes
+    }
+}
+
+/// Formats as `AA`, `AKs` or `AKo`.
+impl fmt::Display for HandClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.high, self.low)?;
+        match self.kind {
+            ClassKind::Pair => Ok(()),
+            ClassKind::Suited => f.write_str("s"),
+            ClassKind::Offsuit => f.write_str("o"),
+        }
+    }
+}
+
+impl FromStr for HandClass {
+    type Err = RangeParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match parse_class(s)? {
+            (class, false) => Ok(class),
+            _ => Err(RangeParseError::InvalidToken(s.to_string())),
+        }
+    }
+}
+
+/// A weighted set of starting hands, e.g. `22+, A2s+, KTo+, 76s`.
+///
+/// Each of the 1326 combos carries a weight from 0 (absent) to 1 (always
+/// held); fractional weights express mixed strategies. Notation tokens are
+/// separated by commas and each may end in `:weight`:
+///
+/// - `QQ`, `AKs`, `AKo`, `AK` (suited and offsuit) and single combos `AhKh`
+/// - `TT+` for tens or better, `A2s+` and `KTo+` up to one below the top card
+/// - spans such as `99-66` or `A5s-A2s`
+///
+/// Later tokens overwrite the weight of combos named earlier. Ranges
+/// serialize as their notation string.
+///
+/// # Examples
+///
+/// ```
+/// use axiomind_engine::cards::parse_cards;
+/// use axiomind_engine::range::Range;
+///
+/// let mut range: Range = "22+, A2s+, KTo+, 76s".parse().unwrap();
+/// assert_eq!(range.len(), 78 + 48 + 36 + 4);
+/// assert_eq!(range.to_string(), "22+, A2s+, 76s, KTo+");
+///
+/// // Holding the ace of spades removes three AA combos and the twelve AXs spade combos
+/// range.remove_blocked(&parse_cards("As").unwrap());
+/// assert_eq!(range.len(), 166 - 3 - 12);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Range {
+    weights: Vec<f64>,
+}
+
+impl Default for Range {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Range {
+    /// An empty range.
+    pub fn new() -> Self {
+        Self {
+            weights: vec![0.0; COMBOS],
+        }
+    }
+
+    /// Every combo at full weight.
+    pub fn full() -> Self {
+        Self {
+            weights: vec![1.0; COMBOS],
+        }
+    }
+
+    pub fn weight(&self, combo: Combo) -> f64 {
+        self.weights[combo.index()]
+    }
+
+    /// Set a combo's weight, clamped to `0.0..=1.0`.
+    pub fn set_weight(&mut self, combo: Combo, weight: f64) {
+        self.weights[combo.index()] = weight.clamp(0.0, 1.0);
+    }
+
+    /// Set the weight of every combo of a class.
+    pub fn set_class_weight(&mut self, class: HandClass, weight: f64) {
+        for combo in class.combos() {
+            self.set_weight(combo, weight);
+        }
+    }
+
+    /// Mean weight over the combos of a class.
+    pub fn class_weight(&self, class: HandClass) -> f64 {
+        let combos = class.combos();
+        combos.iter().map(|&c| self.weight(c)).sum::<f64>() / combos.len() as f64
+    }
+
+    pub fn contains(&self, combo: Combo) -> bool {
+        self.weight(combo) > 0.0
+    }
+
+    /// Combos with non-zero weight, in index order.
+    pub fn combos(&self) -> impl Iterator<Item = (Combo, f64)> + '_ {
+        Combo::all()
+            .map(|c| (c, self.weight(c)))
+            .filter(|&(_, w)| w > 0.0)
+    }
+
+    /// Number of combos with non-zero weight.
+    pub fn len(&self) -> usize {
+        self.weights.iter().filter(|&&w| w > 0.0).count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Sum of weights: the expected number of combos held.
+    pub fn total_weight(&self) -> f64 {
+        self.weights.iter().sum()
+    }
+
+    /// Drop every combo that uses one of `cards`, e.g. the board or our hole cards.
+    pub fn remove_blocked(&mut self, cards: &[Card]) {
+        for combo in Combo::all() {
+            if cards.iter().any(|&c| combo.contains(c)) {
+                self.weights[combo.index()] = 0.0;
+            }
+        }
+    }
+
+    /// Combos in either range, at the higher of the two weights.
+    pub fn union(&self, other: &Range) -> Range {
+        self.zip_with(other, f64::max)
+    }
+
+    /// Combos in both ranges, at the lower of the two weights.
+    pub fn intersection(&self, other: &Range) -> Range {
+        self.zip_with(other, f64::min)
+    }
+
+    /// Combos of `self` that are not in `other` at all.
+    pub fn subtract(&self, other: &Range) -> Range {
+        self.zip_with(other, |a, b| if b > 0.0 { 0.0 } else { a })
+    }
+
+    /// Mean class weight of every cell of the 13x13 grid (see
+    /// [`HandClass::grid_position`]).
+    pub fn grid(&self) -> [[f64; 13]; 13] {
+        let mut grid = [[0.0; 13]; 13];
+        for class in HandClass::all() {
+            let (row, col) = class.grid_position();
+            grid[row][col] = self.class_weight(class);
+        }
+        grid
+    }
+
+    /// Text rendering of [`Range::grid`]: the class name where fully held,
+    /// the percentage where partly held and `.` where absent.
+    pub fn render_grid(&self) -> String {
+        let mut out = String::new();
+        for class in HandClass::all() {
+            let weight = self.class_weight(class);
+            let cell = if weight >= 1.0 {
+                class.to_string()
+            } else if weight > 0.0 {
+                format!("{:.0}%", weight * 100.0)
+            } else {
+                ".".to_string()
+            };
+            out.push_str(&format!("{:<4}", cell));
+            if class.grid_position().1 == 12 {
+                out.truncate(out.trim_end().len());
+                out.push('\n');
+            }
+        }
+        out
+    }
+
+    fn zip_with(&self, other: &Range, f: impl Fn(f64, f64) -> f64) -> Range {
+        Range {
+            weights: self
+                .weights
+                .iter()
+                .zip(&other.weights)
+                .map(|(&a, &b)| f(a, b))
+                .collect(),
+        }
+    }
+
+    /// The weight shared by every combo of `class`, if they all agree.
+    fn uniform_weight(&self, class: HandClass) -> Option<f64> {
+        let combos = class.combos();
+        let w = self.weight(combos[0]);
+        combos.iter().all(|&c| self.weight(c) == w).then_some(w)
+    }
+}
+
+impl FromStr for Range {
+    type Err = RangeParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut range = Range::new();
+        for token in s.split(',').map(str::trim).filter(|t| !t.is_empty()) {
+            let (body, weight) = match token.split_once(':') {
+                Some((body, w)) => (body.trim(), parse_weight(w.trim())?),
+                None => (token, 1.0),
+            };
+            for combo in expand_token(body)? {
+                range.set_weight(combo, weight);
+            }
+        }
+        Ok(range)
+    }
+}
+
+/// Formats in canonical notation: pairs, then suited and offsuit hands by
+/// top card, then combos that do not fill a whole class. Runs are collapsed
+/// into `+` and `-` forms, and weights other than 1 are appended as `:w`.
+impl fmt::Display for Range {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ranks = grid_ranks();
+        let mut tokens: Vec<String> = Vec::new();
+        let mut partial: Vec<Combo> = Vec::new();
+        let mut weight_of = |class: HandClass| match self.uniform_weight(class) {
+            Some(w) => w,
+            None => {
+                partial.extend(class.combos().into_iter().filter(|&c| self.contains(c)));
+                0.0
+            }
+        };
+
+        let pairs: Vec<(HandClass, f64)> = ranks
+            .iter()
+            .map(|&r| {
+                let class = HandClass::new(r, r, false);
+                (class, weight_of(class))
+            })
+            .collect();
+        push_runs(&mut tokens, &pairs);
+
+        for suited in [true, false] {
+            for (i, &high) in ranks.iter().enumerate() {
+                let row: Vec<(HandClass, f64)> = ranks[i + 1..]
+                    .iter()
+                    .map(|&low| {
+                        let class = HandClass::new(high, low, suited);
+                        (class, weight_of(class))
+                    })
+                    .collect();
+                push_runs(&mut tokens, &row);
+            }
+        }
+
+        for combo in partial {
+            tokens.push(with_weight(combo.to_string(), self.weight(combo)));
+        }
+        f.write_str(&tokens.join(", "))
+    }
+}
+
+impl Serialize for Range {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Range {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Collapse equally weighted runs of a row of classes, best first, into
+/// tokens. A run that starts at the top of the row is written with `+`
+/// (`TT+`, `A9s+`), any other run as a span (`99-66`).
+fn push_runs(tokens: &mut Vec<String>, classes: &[(HandClass, f64)]) {
+    let mut start = 0;
+    while start < classes.len() {
+        let weight = classes[start].1;
+        let mut end = start + 1;
+        while end < classes.len() && classes[end].1 == weight {
+            end += 1;
+        }
+        if weight > 0.0 {
+            let (top, bottom) = (classes[start].0, classes[end - 1].0);
+            let token = if end - start == 1 {
+                top.to_string()
+            } else if start == 0 {
+                format!("{}+", bottom)
+            } else {
+                format!("{}-{}", top, bottom)
+            };
+            tokens.push(with_weight(token, weight));
+        }
+        start = end;
+    }
+}
+
+fn with_weight(token: String, weight: f64) -> String {
+    if weight == 1.0 {
+        token
+    } else {
+        format!("{}:{}", token, weight)
+    }
+}
+
+fn parse_weight(s: &str) -> Result<f64, RangeParseError> {
+    s.parse::<f64>()
+        .ok()
+        .filter(|w| (0.0..=1.0).contains(w))
+        .ok_or_else(|| RangeParseError::InvalidWeight(s.to_string()))
+}
+
+/// Expand one token (without its weight) into combos.
+fn expand_token(token: &str) -> Result<Vec<Combo>, RangeParseError> {
+    let classes: Vec<HandClass> = if let Some((a, b)) = token.split_once('-') {
+        let span_err = || RangeParseError::InvalidSpan(token.to_string());
+        let (a, any_a) = parse_class(a.trim())?;
+        let (b, any_b) = parse_class(b.trim())?;
+        let same_shape =
+            a.kind == b.kind && any_a == any_b && (a.kind == ClassKind::Pair || a.high == b.high);
+        if !same_shape {
+            return Err(span_err());
+        }
+        let (top, bottom) = if a.low >= b.low { (a, b) } else { (b, a) };
+        all_ranks()
+            .into_iter()
+            .filter(|&r| r >= bottom.low && r <= top.low)
+            .flat_map(|r| class_with_low(top, r, any_a))
+            .collect()
+    } else if let Some(body) = token.strip_suffix('+') {
+        let (class, any) = parse_class(body)?;
+        all_ranks()
+            .into_iter()
+            .filter(|&r| r >= class.low && (class.kind == ClassKind::Pair || r < class.high))
+            .flat_map(|r| class_with_low(class, r, any))
+            .collect()
+    } else if let Ok(combo) = token.parse::<Combo>() {
+        return Ok(vec![combo]);
+    } else {
+        let (class, any) = parse_class(token)?;
+        class_with_low(class, class.low, any)
+    };
+    Ok(classes.iter().flat_map(HandClass::combos).collect())
+}
+
+/// `class` with its low card replaced by `low` (both ranks for pairs),
+/// plus the offsuit twin when the token named no suitedness.
+fn class_with_low(class: HandClass, low: Rank, any: bool) -> Vec<HandClass> {
+    match class.kind {
+        ClassKind::Pair => vec![HandClass::new(low, low, false)],
+        ClassKind::Suited if any => vec![
+            HandClass::new(class.high, low, true),
+            HandClass::new(class.high, low, false),
+        ],
+        kind => vec![HandClass::new(class.high, low, kind == ClassKind::Suited)],
+    }
+}
+
+/// Parse `AA`, `AKs`, `AKo` or `AK`; the flag is set for `AK`, which
+/// stands for both the suited and offsuit class (returned as suited).
+fn parse_class(s: &str) -> Result<(HandClass, bool), RangeParseError> {
+    let invalid = || RangeParseError::InvalidToken(s.to_string());
+    let mut chars = s.chars();
+    let mut rank = || {
+        chars
+            .next()
+            .and_then(|c| c.to_string().parse::<Rank>().ok())
+            .ok_or_else(invalid)
+    };
+    let (a, b) = (rank()?, rank()?);
+    let suffix: String = chars.collect();
+    match (a == b, suffix.as_str()) {
+        (true, "") => Ok((HandClass::new(a, b, false), false)),
+        (false, "") => Ok((HandClass::new(a, b, true), true)),
+        (false, "s" | "S") => Ok((HandClass::new(a, b, true), false)),
+        (false, "o" | "O") => Ok((HandClass::new(a, b, false), false)),
+        _ => Err(invalid()),
+    }
+}
+
+fn card_index(card: Card) -> usize {
+    card.suit as usize * 13 + (card.rank as usize - 2)
+}
+
+fn card_from_index(i: usize) -> Card {
+    Card {
+        suit: all_suits()[i / 13],
+        rank: all_ranks()[i % 13],
+    }
+}
+
+/// Ranks from Ace down to Two, the grid's row and column order.
+fn grid_ranks() -> [Rank; 13] {
+    let mut ranks = all_ranks();
+    ranks.reverse();
+    ranks
+}
+
+fn grid_index(rank: Rank) -> usize {
+    Rank::Ace as usize - rank as usize
+}
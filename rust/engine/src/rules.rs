@@ -1,5 +1,10 @@
 use crate::errors::GameError;
-use crate::player::PlayerAction as A;
+use crate::logger::Street;
+use crate::player::{HANDS_PER_LEVEL, PlayerAction as A};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::path::Path;
+use std::str::FromStr;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ValidatedAction {
@@ -35,6 +40,8 @@ pub enum ValidatedAction {
 /// - [`GameError::InsufficientChips`] - Player tries to check when facing a bet
 /// - [`GameError::InvalidBetAmount`] - Bet/raise amount is below minimum or zero
 ///
+/// Every action offered by [`legal_actions`] for the same situation is accepted.
+///
 /// # Examples
 ///
 /// ```
@@ -91,6 +98,11 @@ pub fn validate_action(
             }
             if amount >= stack {
                 Ok(ValidatedAction::AllIn(stack))
+            } else if amount < min_raise {
+                Err(GameError::InvalidBetAmount {
+                    amount,
+                    minimum: min_raise,
+                })
             } else {
                 Ok(ValidatedAction::Bet(amount))
             }
@@ -110,3 +122,584 @@ pub fn validate_action(
         A::AllIn => Ok(ValidatedAction::AllIn(stack)),
     }
 }
+
+/// Smallest chip denomination (GAME_RULES.md). Bet and raise sizes are
+/// multiples of it, except for an all-in.
+pub const MIN_CHIP_UNIT: u32 = 25;
+
+/// Inclusive range of sizes for a bet or raise.
+///
+/// Both ends are always legal; under no-limit `max` is the player's all-in,
+/// while pot-limit and fixed-limit cap it (see [`BettingStructure`]). Sizes in
+/// between go up in steps of [`MIN_CHIP_UNIT`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SizeRange {
+    /// Smallest legal size
+    pub min: u32,
+    /// Largest legal size (all-in)
+    pub max: u32,
+}
+
+impl SizeRange {
+    /// Snap `amount` to a legal size: down to a whole chip unit, then into `min..=max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use axiomind_engine::rules::SizeRange;
+    ///
+    /// let range = SizeRange { min: 100, max: 1_330 };
+    /// assert_eq!(range.clamp(640), 625);
+    /// assert_eq!(range.clamp(10), 100);
+    /// assert_eq!(range.clamp(5_000), 1_330);
+    /// ```
+    pub fn clamp(&self, amount: u32) -> u32 {
+        let rounded = amount - amount % MIN_CHIP_UNIT;
+        rounded.clamp(self.min, self.max)
+    }
+
+    /// Whether `amount` lies within the range.
+    pub fn contains(&self, amount: u32) -> bool {
+        (self.min..=self.max).contains(&amount)
+    }
+}
+
+/// The actions available to the player whose turn it is.
+///
+/// `bet` sizes are the chips put in by the bet. `raise_to` sizes are the
+/// player's total contribution for the street after raising, so a raise to
+/// `total` is submitted as `PlayerAction::Raise(total - current_bet)` (see
+/// [`LegalActions::raise_to_action`]). The default value allows nothing and is
+/// returned for a player who is not on turn.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LegalActions {
+    /// Whether the player may fold
+    pub fold: bool,
+    /// Whether the player may check
+    pub check: bool,
+    /// Chips a call puts in, capped at the stack; `None` when there is nothing to call
+    pub call: Option<u32>,
+    /// Bet sizes, when nobody has bet yet
+    pub bet: Option<SizeRange>,
+    /// Raise-to totals, when facing a bet
+    pub raise_to: Option<SizeRange>,
+    /// Chips an all-in puts in; `None` with an empty stack
+    pub all_in: Option<u32>,
+    /// Bet to match on this street
+    pub current_bet: u32,
+}
+
+impl LegalActions {
+    /// Whether no action at all is available.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Whether `action` is one of the enumerated legal actions.
+    ///
+    /// Bet and raise sizes only need to fall inside their range here; use
+    /// [`SizeRange::clamp`] to keep them on the chip unit.
+    pub fn allows(&self, action: &A) -> bool {
+        match *action {
+            A::Fold => self.fold,
+            A::Check => self.check,
+            A::Call => self.call.is_some(),
+            A::Bet(amount) => self.bet.is_some_and(|r| r.contains(amount)),
+            A::Raise(by) => self
+                .raise_to
+                .is_some_and(|r| r.contains(self.current_bet.saturating_add(by))),
+            A::AllIn => self.all_in.is_some(),
+        }
+    }
+
+    /// Build the `PlayerAction` that raises to `total`, snapped to a legal size.
+    /// Returns `None` if raising is not allowed.
+    pub fn raise_to_action(&self, total: u32) -> Option<A> {
+        let range = self.raise_to?;
+        let total = range.clamp(total);
+        // The top of a capped range is a raise, not an all-in
+        Some(if total == range.max && self.all_in.is_some() {
+            A::AllIn
+        } else {
+            A::Raise(total - self.current_bet)
+        })
+    }
+}
+
+/// Enumerates the legal actions for a player, given the same inputs as
+/// [`validate_action`] plus the bet to match on this street.
+///
+/// Heads-up no-limit rules apply: bets start at `min_raise` (the big blind on
+/// a fresh street), raises must add at least `min_raise` on top of
+/// `current_bet`, and a stack too short for the minimum can still go all-in.
+///
+/// # Examples
+///
+/// ```
+/// use axiomind_engine::rules::{SizeRange, legal_actions};
+///
+/// // The button facing the big blind with 50 already posted
+/// let legal = legal_actions(19_950, 50, 100, 100);
+/// assert!(legal.fold && !legal.check);
+/// assert_eq!(legal.call, Some(50));
+/// assert_eq!(legal.bet, None);
+/// assert_eq!(legal.raise_to, Some(SizeRange { min: 200, max: 20_000 }));
+/// assert_eq!(legal.all_in, Some(19_950));
+/// ```
+pub fn legal_actions(stack: u32, to_call: u32, current_bet: u32, min_raise: u32) -> LegalActions {
+    let committed = current_bet.saturating_sub(to_call);
+    let bet = (to_call == 0 && stack > 0).then(|| SizeRange {
+        min: min_raise.max(1).min(stack),
+        max: stack,
+    });
+    let raise_to = (to_call > 0 && stack > to_call).then(|| {
+        let max = committed + stack;
+        SizeRange {
+            min: current_bet.saturating_add(min_raise).min(max),
+            max,
+        }
+    });
+    LegalActions {
+        fold: true,
+        check: to_call == 0,
+        call: (to_call > 0).then(|| to_call.min(stack)),
+        bet,
+        raise_to,
+        all_in: (stack > 0).then_some(stack),
+        current_bet,
+    }
+}
+
+/// How bets and raises are sized.
+///
+/// No-limit is the default. Pot-limit caps a bet or raise at the size of the
+/// pot after calling. Fixed-limit allows a single size per street, `small_bet`
+/// preflop and on the flop and `big_bet` on the turn and river, and at most
+/// `raise_cap` bets and raises per street (preflop the big blind counts as the
+/// first bet). A stack too short for the size can still go all-in.
+///
+/// Notation is `nl`, `pl` or `fl:SMALL/BIG[/CAP]`, the cap defaulting to 4.
+///
+/// # Examples
+///
+/// ```
+/// use axiomind_engine::logger::Street;
+/// use axiomind_engine::rules::{BettingSpot, BettingStructure, SizeRange};
+///
+/// let spot = BettingSpot {
+///     stack: 19_900,
+///     to_call: 0,
+///     current_bet: 0,
+///     min_raise: 100,
+///     pot: 300,
+///     street: Street::Flop,
+///     bets: 0,
+/// };
+/// let bet = |s: &str| s.parse::<BettingStructure>().unwrap().legal_actions(&spot).bet;
+/// assert_eq!(bet("nl"), Some(SizeRange { min: 100, max: 19_900 }));
+/// assert_eq!(bet("pl"), Some(SizeRange { min: 100, max: 300 }));
+/// assert_eq!(bet("fl:100/200"), Some(SizeRange { min: 100, max: 100 }));
+///
+/// let limit: BettingStructure = "fl:100/200".parse().unwrap();
+/// assert_eq!(limit.to_string(), "fl:100/200/4");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum BettingStructure {
+    /// Any size from the minimum raise up to all-in
+    #[default]
+    NoLimit,
+    /// Up to the size of the pot after calling
+    PotLimit,
+    /// Fixed bet sizes and a cap on raises per street
+    FixedLimit {
+        /// Bet size preflop and on the flop
+        small_bet: u32,
+        /// Bet size on the turn and river
+        big_bet: u32,
+        /// Most bets and raises allowed on one street
+        raise_cap: u32,
+    },
+}
+
+/// Default number of bets and raises per street under fixed-limit.
+pub const DEFAULT_RAISE_CAP: u32 = 4;
+
+/// What a [`BettingStructure`] needs to know to size the next bet or raise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BettingSpot {
+    /// Chips behind
+    pub stack: u32,
+    /// Chips needed to call
+    pub to_call: u32,
+    /// Bet to match on this street
+    pub current_bet: u32,
+    /// Smallest raise increment
+    pub min_raise: u32,
+    /// Chips in the pot, including this street's bets
+    pub pot: u32,
+    /// Street being bet
+    pub street: Street,
+    /// Bets and raises so far this street, counting the big blind preflop
+    pub bets: u32,
+}
+
+impl BettingStructure {
+    /// Check that fixed-limit sizes are positive and the cap allows a bet.
+    pub fn validate(&self) -> Result<(), GameError> {
+        if let Self::FixedLimit {
+            small_bet,
+            big_bet,
+            raise_cap,
+        } = *self
+            && (small_bet == 0 || big_bet < small_bet || raise_cap == 0)
+        {
+            return Err(GameError::InvalidBettingStructure(format!(
+                "need 0 < small bet <= big bet and a cap of at least 1, got {}",
+                self
+            )));
+        }
+        Ok(())
+    }
+
+    /// Enumerate the legal actions in `spot` under this structure.
+    ///
+    /// Starts from the no-limit actions of [`legal_actions`] and caps the bet
+    /// and raise sizes; an all-in is only offered when it stays within the cap
+    /// or merely calls.
+    pub fn legal_actions(&self, spot: &BettingSpot) -> LegalActions {
+        let mut legal = legal_actions(spot.stack, spot.to_call, spot.current_bet, spot.min_raise);
+        let committed = spot.current_bet.saturating_sub(spot.to_call);
+        let (max_total, fixed) = match *self {
+            Self::NoLimit => return legal,
+            // Call first, then raise by the whole pot including the call
+            Self::PotLimit => (spot.current_bet + spot.pot + spot.to_call, false),
+            Self::FixedLimit {
+                small_bet,
+                big_bet,
+                raise_cap,
+            } => {
+                if spot.bets >= raise_cap {
+                    legal.bet = None;
+                    legal.raise_to = None;
+                    legal.all_in = legal.all_in.filter(|_| spot.stack <= spot.to_call);
+                    return legal;
+                }
+                let size = match spot.street {
+                    Street::Preflop | Street::Flop => small_bet,
+                    Street::Turn | Street::River => big_bet,
+                };
+                (spot.current_bet + size, true)
+            }
+        };
+        let cap = |range: SizeRange, base: u32| {
+            let max = range.max.min(max_total.saturating_sub(base));
+            let min = if fixed { max } else { range.min.min(max) };
+            SizeRange { min, max }
+        };
+        legal.bet = legal.bet.map(|range| cap(range, committed));
+        legal.raise_to = legal.raise_to.map(|range| cap(range, 0));
+        if committed + spot.stack > max_total && spot.stack > spot.to_call {
+            legal.all_in = None;
+        }
+        legal
+    }
+
+    /// Validate `action` in `spot`: the no-limit rules of [`validate_action`],
+    /// then this structure's limits.
+    ///
+    /// # Errors
+    ///
+    /// Besides the errors of [`validate_action`], returns
+    /// [`GameError::BetAboveLimit`] for a bet, raise or all-in over the cap,
+    /// [`GameError::InvalidBetAmount`] for a fixed-limit size below the fixed
+    /// size, and [`GameError::RaiseCapReached`] once fixed-limit betting is capped.
+    pub fn validate_action(
+        &self,
+        spot: &BettingSpot,
+        action: A,
+    ) -> Result<ValidatedAction, GameError> {
+        let validated = validate_action(spot.stack, spot.to_call, spot.min_raise, action)?;
+        if *self == Self::NoLimit {
+            return Ok(validated);
+        }
+        let legal = self.legal_actions(spot);
+        let committed = spot.current_bet.saturating_sub(spot.to_call);
+        let capped = || match *self {
+            Self::FixedLimit { raise_cap, .. } if spot.bets >= raise_cap => {
+                GameError::RaiseCapReached(raise_cap)
+            }
+            _ => GameError::InvalidBettingStructure(format!(
+                "no bet or raise allowed under {}",
+                self
+            )),
+        };
+        let within = |amount: u32, range: Option<SizeRange>, base: u32| match range {
+            None => Err(capped()),
+            Some(range) if base + amount > range.max => Err(GameError::BetAboveLimit {
+                amount,
+                maximum: range.max - base,
+            }),
+            Some(range) if base + amount < range.min => Err(GameError::InvalidBetAmount {
+                amount,
+                minimum: range.min - base,
+            }),
+            Some(_) => Ok(()),
+        };
+        match validated {
+            ValidatedAction::Bet(amount) => within(amount, legal.bet, 0)?,
+            ValidatedAction::Raise(by) => within(by, legal.raise_to, spot.current_bet)?,
+            ValidatedAction::AllIn(amount) if amount > spot.to_call && legal.all_in.is_none() => {
+                let limit = legal
+                    .bet
+                    .map(|range| range.max)
+                    .or_else(|| legal.raise_to.map(|range| range.max - committed));
+                return Err(match limit {
+                    Some(maximum) => GameError::BetAboveLimit { amount, maximum },
+                    None => capped(),
+                });
+            }
+            _ => {}
+        }
+        Ok(validated)
+    }
+}
+
+impl fmt::Display for BettingStructure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoLimit => f.write_str("nl"),
+            Self::PotLimit => f.write_str("pl"),
+            Self::FixedLimit {
+                small_bet,
+                big_bet,
+                raise_cap,
+            } => write!(f, "fl:{}/{}/{}", small_bet, big_bet, raise_cap),
+        }
+    }
+}
+
+impl FromStr for BettingStructure {
+    type Err = GameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || {
+            GameError::InvalidBettingStructure(format!(
+                "{:?}, expected nl, pl or fl:SMALL/BIG[/CAP]",
+                s
+            ))
+        };
+        let structure = match s.trim().to_ascii_lowercase().as_str() {
+            "nl" | "no-limit" => Self::NoLimit,
+            "pl" | "pot-limit" => Self::PotLimit,
+            other => {
+                let sizes = other
+                    .strip_prefix("fl:")
+                    .or_else(|| other.strip_prefix("fixed-limit:"))
+                    .ok_or_else(invalid)?;
+                let numbers = sizes
+                    .split('/')
+                    .map(|n| n.trim().parse::<u32>())
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|_| invalid())?;
+                match numbers[..] {
+                    [small_bet, big_bet] => Self::FixedLimit {
+                        small_bet,
+                        big_bet,
+                        raise_cap: DEFAULT_RAISE_CAP,
+                    },
+                    [small_bet, big_bet, raise_cap] => Self::FixedLimit {
+                        small_bet,
+                        big_bet,
+                        raise_cap,
+                    },
+                    _ => return Err(invalid()),
+                }
+            }
+        };
+        structure.validate()?;
+        Ok(structure)
+    }
+}
+
+/// Small and big blinds of the default structure (GAME_RULES.md), levels 1 through 20.
+pub(crate) const STANDARD_BLINDS: [(u32, u32); 20] = [
+    (50, 100),
+    (75, 150),
+    (100, 200),
+    (125, 250),
+    (150, 300),
+    (200, 400),
+    (250, 500),
+    (300, 600),
+    (400, 800),
+    (500, 1000),
+    (600, 1200),
+    (800, 1600),
+    (1000, 2000),
+    (1200, 2400),
+    (1500, 3000),
+    (2000, 4000),
+    (2500, 5000),
+    (3000, 6000),
+    (3500, 7000),
+    (4000, 8000),
+];
+
+/// Forced bets for one blind level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlindLevel {
+    /// Small blind, posted by the button heads-up
+    pub sb: u32,
+    /// Big blind
+    pub bb: u32,
+    /// Ante; who pays it depends on [`BlindStructure::big_blind_ante`]
+    #[serde(default)]
+    pub ante: u32,
+}
+
+/// A tournament blind schedule: the forced bets at each level and how long a level lasts.
+///
+/// The default is the structure from GAME_RULES.md: twenty levels from 50/100 to
+/// 4000/8000, no antes, 15 hands per level. Levels past the end of the schedule
+/// keep the last level's blinds.
+///
+/// Antes are dead money: they go into the pot but do not count towards the
+/// amount a player has to call. With `big_blind_ante` set, the big blind pays
+/// the level's `ante` on behalf of the table; otherwise every player pays it.
+///
+/// # Examples
+///
+/// ```
+/// use axiomind_engine::rules::BlindStructure;
+///
+/// let turbo = BlindStructure::from_toml_str(
+///     r#"
+///     hands_per_level = 5
+///     big_blind_ante = true
+///
+///     [[levels]]
+///     sb = 100
+///     bb = 200
+///     ante = 200
+///
+///     [[levels]]
+///     sb = 200
+///     bb = 400
+///     ante = 400
+///     "#,
+/// )
+/// .unwrap();
+/// assert_eq!(turbo.hands_per_level, 5);
+/// assert_eq!(turbo.level(2).unwrap().ante, 400);
+/// // Past the last level the blinds stay put
+/// assert_eq!(turbo.blinds(9).unwrap(), (200, 400));
+///
+/// assert_eq!(BlindStructure::default().blinds(3).unwrap(), (100, 200));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlindStructure {
+    /// Hands played at each level before moving to the next
+    #[serde(default = "default_hands_per_level")]
+    pub hands_per_level: u32,
+    /// Whether the big blind pays the ante for everyone
+    #[serde(default)]
+    pub big_blind_ante: bool,
+    /// Levels in order, starting at level 1
+    pub levels: Vec<BlindLevel>,
+}
+
+fn default_hands_per_level() -> u32 {
+    HANDS_PER_LEVEL
+}
+
+impl Default for BlindStructure {
+    fn default() -> Self {
+        Self {
+            hands_per_level: HANDS_PER_LEVEL,
+            big_blind_ante: false,
+            levels: STANDARD_BLINDS
+                .iter()
+                .map(|&(sb, bb)| BlindLevel { sb, bb, ante: 0 })
+                .collect(),
+        }
+    }
+}
+
+impl BlindStructure {
+    /// Parse a structure from TOML and check that it is usable.
+    pub fn from_toml_str(s: &str) -> Result<Self, GameError> {
+        let structure: Self = toml::from_str(s)
+            .map_err(|e| GameError::InvalidBlindStructure(e.message().to_string()))?;
+        structure.validate()?;
+        Ok(structure)
+    }
+
+    /// Parse a structure from JSON and check that it is usable.
+    pub fn from_json_str(s: &str) -> Result<Self, GameError> {
+        let structure: Self =
+            serde_json::from_str(s).map_err(|e| GameError::InvalidBlindStructure(e.to_string()))?;
+        structure.validate()?;
+        Ok(structure)
+    }
+
+    /// Load a structure from a `.toml` or `.json` file, picking the format from the extension.
+    pub fn load(path: &Path) -> Result<Self, GameError> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| GameError::InvalidBlindStructure(format!("{}: {}", path.display(), e)))?;
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => Self::from_toml_str(&text),
+            Some("json") => Self::from_json_str(&text),
+            _ => Err(GameError::InvalidBlindStructure(format!(
+                "{}: expected a .toml or .json file",
+                path.display()
+            ))),
+        }
+    }
+
+    /// Check that the structure has at least one level and every level has sane blinds.
+    pub fn validate(&self) -> Result<(), GameError> {
+        if self.levels.is_empty() {
+            return Err(GameError::InvalidBlindStructure(
+                "at least one level is required".to_string(),
+            ));
+        }
+        for (i, level) in self.levels.iter().enumerate() {
+            if level.bb == 0 || level.sb > level.bb {
+                return Err(GameError::InvalidBlindStructure(format!(
+                    "level {}: need 0 <= sb <= bb and bb > 0, got {}/{}",
+                    i + 1,
+                    level.sb,
+                    level.bb
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Number of distinct levels; later levels repeat the last one.
+    pub fn level_count(&self) -> u8 {
+        u8::try_from(self.levels.len()).unwrap_or(u8::MAX)
+    }
+
+    /// Forced bets at a (1-based) level.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GameError::InvalidLevel` if level is 0.
+    pub fn level(&self, level: u8) -> Result<BlindLevel, GameError> {
+        if level == 0 {
+            return Err(GameError::InvalidLevel { level, minimum: 1 });
+        }
+        let idx = usize::from(level - 1).min(self.levels.len().saturating_sub(1));
+        self.levels
+            .get(idx)
+            .copied()
+            .ok_or_else(|| GameError::InvalidBlindStructure("no levels defined".to_string()))
+    }
+
+    /// `(small_blind, big_blind)` at a level.
+    pub fn blinds(&self, level: u8) -> Result<(u32, u32), GameError> {
+        self.level(level).map(|l| (l.sb, l.bb))
+    }
+}
@@ -0,0 +1,202 @@
+//! Hierarchical seed derivation.
+//!
+//! A [`SeedTree`] turns one root seed into independent, well-mixed seeds for
+//! every match, hand, seat swap and runout, so any hand can be dealt again
+//! from the root and its [`SeedPath`] alone. Neighbouring indices give
+//! unrelated seeds, unlike `root + i`, and the scheme is recorded with each
+//! hand as a [`SeedDerivation`] so `replay` and `verify` can rebuild it.
+//!
+//! # Scheme
+//!
+//! `mix` is the SplitMix64 output function: add `0x9E3779B97F4A7C15`, then
+//! xor-shift-multiply by `0xBF58476D1CE4E5B9` and `0x94D049BB133111EB`. A
+//! child of `parent` is `mix(mix(parent ^ label) + index)`, with the label
+//! the little-endian bytes of `match`, `hand`, `swap` or `runout`:
+//!
+//! - match `m`: child of the root, label `match`, index `m`
+//! - hand `h`: child of the match seed, label `hand`, index `h`
+//! - with seats swapped: child of the hand seed, label `swap`, index 1
+//! - runout `r` (from 1): child of the hand seed, label `runout`, index `r`
+//!
+//! # Example
+//!
+//! ```
+//! use axiomind_engine::seed::{SeedPath, SeedTree};
+//!
+//! let tree = SeedTree::new(42);
+//! let first = tree.hand_seed(SeedPath::hand(0));
+//! assert_ne!(tree.hand_seed(SeedPath::hand(1)), first.wrapping_add(1));
+//! assert_ne!(tree.hand_seed(SeedPath::hand(0).swapped()), first);
+//! assert_eq!(SeedTree::new(42).hand_seed(SeedPath::hand(0)), first);
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+use crate::errors::GameError;
+
+/// Name of the derivation scheme, recorded in [`SeedDerivation::scheme`].
+pub const SEED_SCHEME: &str = "splitmix64/v1";
+
+/// Root used when no seed is given.
+pub const DEFAULT_ROOT: u64 = 0xA1A2_A3A4;
+
+const MATCH: u64 = u64::from_le_bytes(*b"match\0\0\0");
+const HAND: u64 = u64::from_le_bytes(*b"hand\0\0\0\0");
+const SWAP: u64 = u64::from_le_bytes(*b"swap\0\0\0\0");
+const RUNOUT: u64 = u64::from_le_bytes(*b"runout\0\0");
+
+/// Seeds derived from one root; see the [module documentation](self).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SeedTree {
+    root: u64,
+}
+
+impl SeedTree {
+    pub fn new(root: u64) -> Self {
+        Self { root }
+    }
+
+    /// The root seed.
+    pub fn root(&self) -> u64 {
+        self.root
+    }
+
+    /// Seed of match `index`, the parent of its hands.
+    pub fn match_seed(&self, index: u64) -> u64 {
+        child(self.root, MATCH, index)
+    }
+
+    /// Seed the deck of the hand at `path` is shuffled from.
+    pub fn hand_seed(&self, path: SeedPath) -> u64 {
+        let hand = child(self.match_seed(path.match_index), HAND, path.hand);
+        if path.seat_swap {
+            child(hand, SWAP, 1)
+        } else {
+            hand
+        }
+    }
+
+    /// Seed of the `run`-th extra runout of the hand at `path`, counting the
+    /// second board as 1.
+    pub fn runout_seed(&self, path: SeedPath, run: usize) -> u64 {
+        child(self.hand_seed(path), RUNOUT, run as u64)
+    }
+}
+
+impl Default for SeedTree {
+    fn default() -> Self {
+        Self::new(DEFAULT_ROOT)
+    }
+}
+
+/// Position of a hand in a [`SeedTree`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SeedPath {
+    /// Match number, 0 for tools playing a single match
+    #[serde(rename = "match", default)]
+    pub match_index: u64,
+    /// Hand number within the match, from 0
+    pub hand: u64,
+    /// Whether this is the hand replayed with the seats swapped
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub seat_swap: bool,
+}
+
+impl SeedPath {
+    /// Hand `hand` of match 0.
+    pub fn hand(hand: u64) -> Self {
+        Self {
+            hand,
+            ..Self::default()
+        }
+    }
+
+    /// The same hand in match `index`.
+    pub fn in_match(self, index: u64) -> Self {
+        Self {
+            match_index: index,
+            ..self
+        }
+    }
+
+    /// The same hand with the seats swapped.
+    pub fn swapped(self) -> Self {
+        Self {
+            seat_swap: true,
+            ..self
+        }
+    }
+}
+
+/// How a hand's seed was derived, as recorded in `HandRecord.meta.seed_derivation`.
+///
+/// # Example
+///
+/// ```
+/// use axiomind_engine::seed::{SeedDerivation, SeedPath, SeedTree};
+///
+/// let derivation = SeedDerivation::new(SeedTree::new(7), SeedPath::hand(3));
+/// let json = serde_json::to_value(&derivation).unwrap();
+/// assert_eq!(json["scheme"], "splitmix64/v1");
+/// assert_eq!(json["hand"], 3);
+///
+/// let back: SeedDerivation = serde_json::from_value(json).unwrap();
+/// assert_eq!(back.hand_seed().unwrap(), SeedTree::new(7).hand_seed(SeedPath::hand(3)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SeedDerivation {
+    /// Derivation scheme, [`SEED_SCHEME`]
+    pub scheme: String,
+    /// Root seed of the tree
+    pub root: u64,
+    /// Position of the hand in the tree
+    #[serde(flatten)]
+    pub path: SeedPath,
+}
+
+impl SeedDerivation {
+    pub fn new(tree: SeedTree, path: SeedPath) -> Self {
+        Self {
+            scheme: SEED_SCHEME.to_string(),
+            root: tree.root(),
+            path,
+        }
+    }
+
+    /// The tree the hand was derived from.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GameError::InvalidSeedDerivation` for a scheme other than
+    /// [`SEED_SCHEME`].
+    pub fn tree(&self) -> Result<SeedTree, GameError> {
+        if self.scheme != SEED_SCHEME {
+            return Err(GameError::InvalidSeedDerivation(format!(
+                "unknown scheme {:?}",
+                self.scheme
+            )));
+        }
+        Ok(SeedTree::new(self.root))
+    }
+
+    /// Seed the hand's deck was shuffled from.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GameError::InvalidSeedDerivation` for an unknown scheme.
+    pub fn hand_seed(&self) -> Result<u64, GameError> {
+        Ok(self.tree()?.hand_seed(self.path))
+    }
+}
+
+fn child(parent: u64, label: u64, index: u64) -> u64 {
+    mix(mix(parent ^ label).wrapping_add(index))
+}
+
+/// SplitMix64 output function.
+fn mix(z: u64) -> u64 {
+    let mut z = z.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
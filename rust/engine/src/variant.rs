@@ -0,0 +1,161 @@
+//! Poker variants the engine can deal.
+//!
+//! A variant decides which deck is used, how many hole cards each seat is
+//! dealt, and how a hand is made from them and the board at showdown and
+//! ranked against others. Betting works the same in every variant; pair
+//! one with a [`BettingStructure`] to get e.g. pot-limit Omaha.
+
+use crate::cards::Card;
+use crate::errors::GameError;
+use crate::hand::{
+    BestHand, HandStrength, compare_hands, compare_short_deck, evaluate_best,
+    evaluate_hand_optimized, evaluate_omaha, evaluate_short_deck,
+};
+use crate::rules::BettingStructure;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+/// Game variant dealt by the engine; Texas Hold'em unless chosen otherwise.
+///
+/// Written as `"holdem"`, `"omaha"` (also `"plo"`) or `"short_deck"` (also
+/// `"short-deck"` and `"6+"`).
+///
+/// # Examples
+///
+/// ```
+/// use axiomind_engine::cards::parse_cards;
+/// use axiomind_engine::rules::BettingStructure;
+/// use axiomind_engine::variant::GameVariant;
+///
+/// let omaha: GameVariant = "plo".parse().unwrap();
+/// assert_eq!(omaha.hole_cards(), 4);
+/// assert_eq!(omaha.default_betting_structure(), BettingStructure::PotLimit);
+///
+/// // Hold'em plays the board's straight; Omaha must use two hole cards
+/// let hole = parse_cards("AsAd2c2h").unwrap();
+/// let board = parse_cards("9h8d7c6sTc").unwrap();
+/// let best = omaha.best_hand(&hole, &board).unwrap();
+/// assert_eq!(best.describe(), "One Pair, Aces");
+/// let best = GameVariant::Holdem.best_hand(&hole[..2], &board).unwrap();
+/// assert_eq!(best.describe(), "Straight, Ten-high");
+/// ```
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GameVariant {
+    /// Two hole cards; any five of the seven cards make the hand
+    #[default]
+    Holdem,
+    /// Four hole cards; exactly two of them plus three board cards make the hand
+    Omaha,
+    /// Hold'em with the 36 cards from Six to Ace: a flush beats a full house
+    /// and A-6-7-8-9 is the lowest straight
+    ShortDeck,
+}
+
+impl GameVariant {
+    /// Hole cards dealt to each seat.
+    pub fn hole_cards(self) -> usize {
+        match self {
+            Self::Holdem | Self::ShortDeck => 2,
+            Self::Omaha => 4,
+        }
+    }
+
+    /// The variant dealing `count` hole cards, if there is one.
+    ///
+    /// This only tells Hold'em and Omaha apart: two cards are taken to be
+    /// Hold'em, so short deck is never returned.
+    pub fn from_hole_cards(count: usize) -> Option<Self> {
+        [Self::Holdem, Self::Omaha]
+            .into_iter()
+            .find(|variant| variant.hole_cards() == count)
+    }
+
+    /// Whether the variant is dealt from the 36-card short deck.
+    pub fn uses_short_deck(self) -> bool {
+        self == Self::ShortDeck
+    }
+
+    /// Betting structure the variant is usually played with: no-limit
+    /// Hold'em (full or short deck) and pot-limit Omaha.
+    pub fn default_betting_structure(self) -> BettingStructure {
+        match self {
+            Self::Holdem | Self::ShortDeck => BettingStructure::NoLimit,
+            Self::Omaha => BettingStructure::PotLimit,
+        }
+    }
+
+    /// Best five-card hand from `hole` and a board of 3 to 5 cards under the
+    /// variant's rules.
+    ///
+    /// # Errors
+    ///
+    /// Fails like [`evaluate_best`], [`evaluate_omaha`] or
+    /// [`evaluate_short_deck`] when the cards cannot make a hand.
+    pub fn best_hand(self, hole: &[Card], board: &[Card]) -> Result<BestHand, GameError> {
+        match self {
+            Self::Holdem => {
+                let cards: Vec<Card> = hole.iter().chain(board).copied().collect();
+                evaluate_best(&cards)
+            }
+            Self::Omaha => evaluate_omaha(hole, board),
+            Self::ShortDeck => {
+                let cards: Vec<Card> = hole.iter().chain(board).copied().collect();
+                evaluate_short_deck(&cards)
+            }
+        }
+    }
+
+    /// Order two hand strengths under the variant's hand rankings.
+    pub fn compare_hands(self, a: &HandStrength, b: &HandStrength) -> Ordering {
+        match self {
+            Self::Holdem | Self::Omaha => compare_hands(a, b),
+            Self::ShortDeck => compare_short_deck(a, b),
+        }
+    }
+
+    /// Strength of a seat's hand on a complete board, or `None` if it was not
+    /// dealt a full hand of this variant.
+    pub(crate) fn showdown_strength(self, hole: &[Card], board: &[Card]) -> Option<HandStrength> {
+        if hole.len() != self.hole_cards() || board.len() != 5 {
+            return None;
+        }
+        match (self, hole, board) {
+            // The lookup evaluator is much faster for the common case
+            (Self::Holdem, &[first, second], &[b0, b1, b2, b3, b4]) => {
+                Some(evaluate_hand_optimized(&[
+                    first, second, b0, b1, b2, b3, b4,
+                ]))
+            }
+            _ => self.best_hand(hole, board).ok().map(|best| best.strength),
+        }
+    }
+}
+
+impl fmt::Display for GameVariant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Holdem => "holdem",
+            Self::Omaha => "omaha",
+            Self::ShortDeck => "short_deck",
+        })
+    }
+}
+
+impl FromStr for GameVariant {
+    type Err = GameError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "holdem" | "hold'em" => Ok(Self::Holdem),
+            "omaha" | "plo" => Ok(Self::Omaha),
+            "short_deck" | "short-deck" | "shortdeck" | "6+" => Ok(Self::ShortDeck),
+            _ => Err(GameError::InvalidVariant(format!(
+                "{:?}, expected holdem, omaha or short_deck",
+                s
+            ))),
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use axiomind_engine::engine::{EndReason, Engine};
+use axiomind_engine::errors::GameError;
+use axiomind_engine::events::{EngineEvent, EventQueue};
+use axiomind_engine::logger::Street;
+use axiomind_engine::player::PlayerAction as A;
+
+fn set_stacks(eng: &mut Engine, stacks: &[u32]) {
+    for (player, &stack) in eng.players_mut().iter_mut().zip(stacks) {
+        let excess = player.stack() - stack;
+        player.bet(excess).expect("trim stack");
+    }
+}
+
+fn streets_dealt(events: &EventQueue) -> Vec<Street> {
+    events
+        .drain()
+        .into_iter()
+        .filter_map(|e| match e {
+            EngineEvent::StreetChanged { street, .. } => Some(street),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn called_preflop_shove_runs_out_to_showdown() {
+    let mut eng = Engine::new(Some(3), 1);
+    let events = EventQueue::new();
+    eng.add_observer(events.clone());
+    eng.deal_hand().unwrap();
+    events.drain();
+
+    eng.apply_action(0, A::AllIn).unwrap();
+    assert_eq!(eng.current_player(), Ok(1));
+    eng.apply_action(1, A::Call).unwrap();
+
+    assert!(eng.is_hand_complete());
+    assert_eq!(eng.board().len(), 5);
+    assert_eq!(eng.current_player(), Err(GameError::HandAlreadyComplete));
+    assert_eq!(
+        streets_dealt(&events),
+        vec![Street::Flop, Street::Turn, Street::River]
+    );
+    let outcome = eng.hand_outcome().unwrap();
+    assert_eq!(outcome.end_reason, EndReason::Showdown);
+    assert_eq!(eng.action_history().len(), 2);
+}
+
+#[test]
+fn all_in_called_on_the_flop_deals_turn_and_river() {
+    let mut eng = Engine::new(Some(4), 1);
+    let events = EventQueue::new();
+    eng.add_observer(events.clone());
+    eng.deal_hand().unwrap();
+    eng.apply_action(0, A::Call).unwrap();
+    eng.apply_action(1, A::Check).unwrap();
+    assert_eq!(streets_dealt(&events), vec![Street::Flop]);
+
+    eng.apply_action(1, A::AllIn).unwrap();
+    eng.apply_action(0, A::Call).unwrap();
+    assert!(eng.is_hand_complete());
+    assert_eq!(streets_dealt(&events), vec![Street::Turn, Street::River]);
+    let stacks: u32 = eng.players().iter().map(|p| p.stack()).sum();
+    assert_eq!(stacks, 40_000);
+}
+
+#[test]
+fn short_all_in_sits_out_while_the_others_keep_betting() {
+    let mut eng = Engine::new(Some(1), 1).with_seats(3).unwrap();
+    set_stacks(&mut eng, &[1_000, 5_000, 5_000]);
+    eng.deal_hand().unwrap();
+    eng.apply_action(0, A::AllIn).unwrap();
+    eng.apply_action(1, A::Call).unwrap();
+    eng.apply_action(2, A::Call).unwrap();
+
+    // The all-in seat is never asked to act again
+    assert_eq!(eng.current_street(), Some(Street::Flop));
+    assert_eq!(eng.current_player(), Ok(1));
+    eng.apply_action(1, A::Check).unwrap();
+    eng.apply_action(2, A::Check).unwrap();
+    assert_eq!(eng.current_street(), Some(Street::Turn));
+    assert_eq!(eng.current_player(), Ok(1));
+
+    // Once a second seat is all-in and called, the river comes at once
+    eng.apply_action(1, A::AllIn).unwrap();
+    eng.apply_action(2, A::Call).unwrap();
+    assert!(eng.is_hand_complete());
+    assert_eq!(eng.board().len(), 5);
+    assert_eq!(eng.hand_outcome().unwrap().pots.len(), 2);
+}
+
+#[test]
+fn blinds_that_leave_nobody_to_bet_run_out_on_the_deal() {
+    let mut eng = Engine::new(Some(6), 1);
+    set_stacks(&mut eng, &[20_000, 30]);
+    eng.deal_hand().unwrap();
+    assert!(eng.is_hand_complete());
+    assert_eq!(eng.board().len(), 5);
+    assert_eq!(eng.current_player(), Err(GameError::HandAlreadyComplete));
+    assert_eq!(eng.hand_outcome().unwrap().uncalled_bet, Some((0, 20)));
+}
+
+#[test]
+fn undoing_the_call_takes_back_the_runout() {
+    let mut eng = Engine::new(Some(3), 1);
+    eng.deal_hand().unwrap();
+    eng.apply_action(0, A::AllIn).unwrap();
+    eng.apply_action(1, A::Call).unwrap();
+    let board = eng.board().clone();
+
+    eng.undo_last_action().unwrap();
+    assert!(!eng.is_hand_complete());
+    assert!(eng.board().is_empty());
+    assert_eq!(eng.current_player(), Ok(1));
+
+    // The same cards come out again
+    eng.apply_action(1, A::Call).unwrap();
+    assert_eq!(eng.board(), &board);
+}
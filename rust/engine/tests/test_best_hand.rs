@@ -0,0 +1,95 @@
+use axiomind_engine::cards::{Card, Rank, Suit, full_deck, parse_cards};
+use axiomind_engine::errors::GameError;
+use axiomind_engine::hand::{Category, compare_hands, evaluate_best, evaluate_hand};
+use rand::seq::SliceRandom;
+use rand::{SeedableRng, rngs::StdRng};
+
+fn best(s: &str) -> axiomind_engine::hand::BestHand {
+    evaluate_best(&parse_cards(s).expect("cards")).expect("evaluate")
+}
+
+#[test]
+fn seven_cards_agree_with_evaluate_hand() {
+    let mut rng = StdRng::seed_from_u64(9);
+    let mut deck = full_deck();
+    for _ in 0..20_000 {
+        deck.shuffle(&mut rng);
+        let cards: [Card; 7] = deck[..7].try_into().unwrap();
+        let b = evaluate_best(&cards).expect("evaluate");
+        assert_eq!(b.strength, evaluate_hand(&cards));
+        // The reported five cards make the same hand on their own
+        assert_eq!(evaluate_best(&b.cards).unwrap().strength, b.strength);
+        assert!(b.cards.iter().all(|c| cards.contains(c)));
+    }
+}
+
+#[test]
+fn flop_and_turn_do_not_invent_hands() {
+    // Old padding with low fillers turned this into a straight
+    let flop = best("3h4d 5c9sKd");
+    assert_eq!(flop.strength.category, Category::HighCard);
+    assert_eq!(flop.strength.kickers, [13, 9, 5, 4, 3]);
+
+    let turn = best("3h4d 5c9sKd3c");
+    assert_eq!(turn.strength.category, Category::OnePair);
+    assert_eq!(turn.strength.kickers, [3, 13, 9, 5, 0]);
+}
+
+#[test]
+fn best_five_are_ordered_by_significance() {
+    assert_eq!(
+        best("7c Kh 7d Ks 2c 9h").cards,
+        parse_cards("KsKh7d7c9h").unwrap()[..]
+    );
+    assert_eq!(
+        best("As2d3c4h5s").cards,
+        parse_cards("5s4h3c2dAs").unwrap()[..]
+    );
+    assert_eq!(
+        best("QcQh2d2s2h9c").cards,
+        parse_cards("2s2h2dQhQc").unwrap()[..]
+    );
+}
+
+#[test]
+fn describes_every_category() {
+    for (cards, expected) in [
+        ("AhKhQhJhTh", "Royal Flush"),
+        ("9c8c7c6c5c2d", "Straight Flush, Nine-high"),
+        ("AhAdAsAc2c", "Four of a Kind, Aces"),
+        ("KhKdKs7c7h", "Full House, Kings full of Sevens"),
+        ("Ah9h7h4h2h", "Flush, Ace-high"),
+        ("Ts9h8c7d6s", "Straight, Ten-high"),
+        ("Ah2d3c4s5h", "Straight, Five-high"),
+        ("6h6d6s9cKh", "Three of a Kind, Sixes"),
+        ("KhKd7s7c2h", "Two Pair, Kings and Sevens"),
+        ("QhQd9s7c2h", "One Pair, Queens"),
+        ("Ah9d7s4c2h", "High Card, Ace"),
+    ] {
+        assert_eq!(best(cards).describe(), expected, "{cards}");
+    }
+}
+
+#[test]
+fn rejects_wrong_sizes_and_duplicates() {
+    let cards = parse_cards("AhKdQcJsTh9c8d7h").unwrap();
+    assert_eq!(
+        evaluate_best(&cards[..4]),
+        Err(GameError::InvalidHandSize(4))
+    );
+    assert_eq!(evaluate_best(&cards), Err(GameError::InvalidHandSize(8)));
+
+    let ace = Card {
+        rank: Rank::Ace,
+        suit: Suit::Hearts,
+    };
+    let dup = [ace, cards[1], cards[2], ace, cards[4]];
+    assert_eq!(evaluate_best(&dup), Err(GameError::DuplicateCard(ace)));
+}
+
+#[test]
+fn strengths_from_fewer_cards_compare_normally() {
+    let set = best("7h7d 7c2sKd").strength;
+    let overpair = best("AhAd 7c2sKd").strength;
+    assert_eq!(compare_hands(&set, &overpair), std::cmp::Ordering::Greater);
+}
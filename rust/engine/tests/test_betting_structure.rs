@@ -0,0 +1,155 @@
+use axiomind_engine::engine::Engine;
+use axiomind_engine::errors::GameError;
+use axiomind_engine::logger::Street;
+use axiomind_engine::player::PlayerAction as A;
+use axiomind_engine::rules::{BettingStructure, SizeRange};
+
+fn dealt(structure: &str) -> Engine {
+    let mut eng = Engine::new(Some(1), 1).with_betting_structure(structure.parse().unwrap());
+    eng.deal_hand().unwrap();
+    eng
+}
+
+fn range(min: u32, max: u32) -> Option<SizeRange> {
+    Some(SizeRange { min, max })
+}
+
+#[test]
+fn parses_and_formats_structures() {
+    for (input, expected) in [
+        ("nl", "nl"),
+        ("No-Limit", "nl"),
+        ("pl", "pl"),
+        ("fl:100/200", "fl:100/200/4"),
+        ("fixed-limit:50/100/3", "fl:50/100/3"),
+    ] {
+        let structure: BettingStructure = input.parse().unwrap();
+        assert_eq!(structure.to_string(), expected, "{input}");
+        assert_eq!(expected.parse::<BettingStructure>().unwrap(), structure);
+    }
+    for input in [
+        "limit",
+        "fl:100",
+        "fl:200/100",
+        "fl:100/200/0",
+        "fl:0/0",
+        "fl:a/b",
+    ] {
+        assert!(
+            matches!(
+                input.parse::<BettingStructure>(),
+                Err(GameError::InvalidBettingStructure(_))
+            ),
+            "{input}"
+        );
+    }
+    let json = serde_json::to_value("fl:100/200/3".parse::<BettingStructure>().unwrap()).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({"kind": "fixed_limit", "small_bet": 100, "big_bet": 200, "raise_cap": 3})
+    );
+}
+
+#[test]
+fn pot_limit_caps_raises_at_the_pot_after_calling() {
+    let mut eng = dealt("pl");
+    let legal = eng.legal_actions(0);
+    assert_eq!(legal.raise_to, range(200, 300));
+    assert_eq!(legal.all_in, None);
+    assert_eq!(legal.raise_to_action(5_000), Some(A::Raise(200)));
+    assert_eq!(
+        eng.apply_action(0, A::Raise(300)).unwrap_err(),
+        GameError::BetAboveLimit {
+            amount: 300,
+            maximum: 200
+        }
+    );
+    assert_eq!(
+        eng.apply_action(0, A::AllIn).unwrap_err(),
+        GameError::BetAboveLimit {
+            amount: 19_950,
+            maximum: 250
+        }
+    );
+    eng.apply_action(0, A::Raise(200)).unwrap();
+
+    // Facing 300 with 400 in the pot: call 200, then raise 600
+    assert_eq!(eng.legal_actions(1).raise_to, range(500, 900));
+    eng.apply_action(1, A::Call).unwrap();
+    assert_eq!(eng.current_street(), Some(Street::Flop));
+    assert_eq!(eng.legal_actions(1).bet, range(100, 600));
+}
+
+#[test]
+fn pot_limit_allows_an_all_in_within_the_cap() {
+    let mut eng = Engine::new(Some(1), 1).with_betting_structure(BettingStructure::PotLimit);
+    eng.players_mut()[0].bet(19_750).unwrap();
+    eng.deal_hand().unwrap();
+
+    let legal = eng.legal_actions(0);
+    assert_eq!(legal.raise_to, range(200, 250));
+    assert_eq!(legal.all_in, Some(200));
+    assert_eq!(legal.raise_to_action(250), Some(A::AllIn));
+    eng.apply_action(0, A::AllIn).unwrap();
+}
+
+#[test]
+fn fixed_limit_uses_one_size_per_street_and_caps_raises() {
+    let mut eng = dealt("fl:100/200");
+    assert_eq!(eng.legal_actions(0).raise_to, range(200, 200));
+    eng.apply_action(0, A::Raise(100)).unwrap();
+    eng.apply_action(1, A::Raise(100)).unwrap();
+    eng.apply_action(0, A::Raise(100)).unwrap();
+
+    // The big blind and three raises make four bets: calling is all that is left
+    let legal = eng.legal_actions(1);
+    assert_eq!(legal.raise_to, None);
+    assert_eq!(legal.all_in, None);
+    assert_eq!(legal.call, Some(100));
+    assert_eq!(
+        eng.apply_action(1, A::Raise(100)).unwrap_err(),
+        GameError::RaiseCapReached(4)
+    );
+    eng.apply_action(1, A::Call).unwrap();
+
+    // Small bets on the flop
+    assert_eq!(eng.legal_actions(1).bet, range(100, 100));
+    assert_eq!(
+        eng.apply_action(1, A::Bet(200)).unwrap_err(),
+        GameError::BetAboveLimit {
+            amount: 200,
+            maximum: 100
+        }
+    );
+    eng.apply_action(1, A::Bet(100)).unwrap();
+    eng.apply_action(0, A::Call).unwrap();
+
+    // Big bets on the turn
+    assert_eq!(eng.current_street(), Some(Street::Turn));
+    assert_eq!(
+        eng.apply_action(1, A::Bet(100)).unwrap_err(),
+        GameError::InvalidBetAmount {
+            amount: 100,
+            minimum: 200
+        }
+    );
+    eng.apply_action(1, A::Bet(200)).unwrap();
+    assert_eq!(eng.legal_actions(0).raise_to, range(400, 400));
+}
+
+#[test]
+fn structure_survives_snapshots_and_is_recorded() {
+    let eng = dealt("fl:100/200/3");
+    let restored = Engine::restore(eng.snapshot()).unwrap();
+    assert_eq!(restored.betting_structure(), eng.betting_structure());
+    assert_eq!(restored.legal_actions(0), eng.legal_actions(0));
+
+    let meta = eng.record_meta();
+    let recorded: BettingStructure =
+        serde_json::from_value(meta["betting_structure"].clone()).unwrap();
+    assert_eq!(recorded, eng.betting_structure());
+    assert_eq!(
+        Engine::new(None, 1).betting_structure(),
+        BettingStructure::NoLimit
+    );
+}
@@ -0,0 +1,169 @@
+use axiomind_engine::engine::{Engine, blinds_for_level};
+use axiomind_engine::errors::GameError;
+use axiomind_engine::game::Match;
+use axiomind_engine::player::{PlayerAction as A, STARTING_STACK};
+use axiomind_engine::rules::{BlindLevel, BlindStructure};
+
+fn one_level(sb: u32, bb: u32, ante: u32, big_blind_ante: bool) -> BlindStructure {
+    BlindStructure {
+        hands_per_level: 15,
+        big_blind_ante,
+        levels: vec![BlindLevel { sb, bb, ante }],
+    }
+}
+
+#[test]
+fn default_structure_matches_level_table() {
+    let structure = BlindStructure::default();
+    assert_eq!(structure.level_count(), 20);
+    assert_eq!(structure.hands_per_level, 15);
+    for level in 1..=25u8 {
+        assert_eq!(structure.blinds(level), blinds_for_level(level));
+        assert_eq!(structure.level(level).unwrap().ante, 0);
+    }
+    assert!(matches!(
+        structure.level(0),
+        Err(GameError::InvalidLevel { .. })
+    ));
+}
+
+#[test]
+fn toml_and_json_describe_the_same_structure() {
+    let toml = r#"
+        hands_per_level = 8
+
+        [[levels]]
+        sb = 25
+        bb = 50
+
+        [[levels]]
+        sb = 50
+        bb = 100
+        ante = 10
+    "#;
+    let json = r#"{
+        "hands_per_level": 8,
+        "levels": [
+            {"sb": 25, "bb": 50},
+            {"sb": 50, "bb": 100, "ante": 10}
+        ]
+    }"#;
+    let from_toml = BlindStructure::from_toml_str(toml).expect("toml");
+    let from_json = BlindStructure::from_json_str(json).expect("json");
+    assert_eq!(from_toml, from_json);
+    assert!(!from_toml.big_blind_ante);
+    assert_eq!(from_toml.level(2).unwrap().ante, 10);
+}
+
+#[test]
+fn load_picks_format_from_extension() {
+    let dir = std::env::temp_dir().join(format!("axm_blinds_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("turbo.toml");
+    std::fs::write(&path, "[[levels]]\nsb = 100\nbb = 200\n").unwrap();
+    let structure = BlindStructure::load(&path).expect("load toml");
+    assert_eq!(structure.blinds(1).unwrap(), (100, 200));
+    // hands_per_level falls back to the rulebook default
+    assert_eq!(structure.hands_per_level, 15);
+
+    let bad = dir.join("turbo.yaml");
+    std::fs::write(&bad, "levels: []").unwrap();
+    assert!(matches!(
+        BlindStructure::load(&bad),
+        Err(GameError::InvalidBlindStructure(_))
+    ));
+    std::fs::remove_dir_all(&dir).ok();
+}
+
+#[test]
+fn invalid_structures_are_rejected() {
+    assert!(BlindStructure::from_json_str(r#"{"levels": []}"#).is_err());
+    assert!(BlindStructure::from_json_str(r#"{"levels": [{"sb": 200, "bb": 100}]}"#).is_err());
+    assert!(BlindStructure::from_toml_str("levels = 3").is_err());
+}
+
+#[test]
+fn every_player_posts_ante_as_dead_money() {
+    let mut eng = Engine::new(Some(1), 1).with_blind_structure(one_level(50, 100, 25, false));
+    eng.deal_hand().expect("deal");
+    assert_eq!(eng.pot(), 200);
+    assert_eq!(eng.players()[0].stack(), STARTING_STACK - 75);
+    assert_eq!(eng.players()[1].stack(), STARTING_STACK - 125);
+    // Antes do not count towards the call
+    assert_eq!(eng.to_call(0), Some(50));
+
+    eng.apply_action(0, A::Fold).expect("fold");
+    let outcome = eng.hand_outcome().expect("settled");
+    assert_eq!(outcome.net, vec![-75, 75]);
+    assert_eq!(outcome.uncalled_bet, Some((1, 50)));
+}
+
+#[test]
+fn big_blind_ante_is_paid_by_big_blind_only() {
+    let mut eng = Engine::new(Some(2), 1).with_blind_structure(one_level(50, 100, 100, true));
+    eng.deal_hand().expect("deal");
+    assert_eq!(eng.pot(), 250);
+    assert_eq!(eng.players()[0].stack(), STARTING_STACK - 50);
+    assert_eq!(eng.players()[1].stack(), STARTING_STACK - 200);
+
+    eng.apply_action(0, A::Call).expect("call");
+    eng.apply_action(1, A::Check).expect("check");
+    while eng.hand_outcome().is_none() {
+        let pid = eng.current_player().expect("actor");
+        eng.apply_action(pid, A::Check).expect("check down");
+    }
+    let outcome = eng.hand_outcome().expect("settled");
+    assert_eq!(outcome.pots.iter().map(|p| p.amount).sum::<u32>(), 300);
+    assert_eq!(outcome.net.iter().sum::<i64>(), 0);
+}
+
+#[test]
+fn match_follows_custom_level_schedule() {
+    let structure = BlindStructure {
+        hands_per_level: 2,
+        big_blind_ante: false,
+        levels: vec![
+            BlindLevel {
+                sb: 25,
+                bb: 50,
+                ante: 0,
+            },
+            BlindLevel {
+                sb: 50,
+                bb: 100,
+                ante: 0,
+            },
+        ],
+    };
+    let mut m = Match::new(Some(3), 1).with_blind_structure(structure);
+    let mut seen = Vec::new();
+    for _ in 0..6 {
+        m.start_hand().expect("deal");
+        seen.push(m.engine().blinds().unwrap());
+        let button = m.button();
+        m.apply_action(button, A::Fold).expect("fold");
+    }
+    assert_eq!(
+        seen,
+        vec![
+            (25, 50),
+            (25, 50),
+            (50, 100),
+            (50, 100),
+            (50, 100),
+            (50, 100)
+        ]
+    );
+    assert_eq!(m.level(), 2);
+}
+
+#[test]
+fn record_meta_round_trips_structure() {
+    let structure = one_level(100, 200, 200, true);
+    let mut eng = Engine::new(Some(4), 1).with_blind_structure(structure.clone());
+    eng.set_button(1);
+    let meta = eng.record_meta();
+    assert_eq!(meta["button_position"], 1);
+    let parsed: BlindStructure = serde_json::from_value(meta["blind_structure"].clone()).unwrap();
+    assert_eq!(parsed, structure);
+}
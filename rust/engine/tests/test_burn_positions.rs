@@ -1,16 +1,76 @@
 use axiomind_engine::engine::Engine;
+use axiomind_engine::player::PlayerAction as A;
 
 #[test]
 fn burn_cards_and_board_count_are_correct() {
     let mut eng = Engine::new(Some(123), 1);
     eng.shuffle();
     eng.deal_hand().expect("deal_hand should succeed");
-    // 5 board cards should be present
-    assert_eq!(eng.board().len(), 5);
+    // no community cards before the flop
+    assert!(eng.board().is_empty());
     // each player has 2 hole cards
     let players = eng.players();
     assert!(players[0].hole_cards()[0].is_some() && players[0].hole_cards()[1].is_some());
     assert!(players[1].hole_cards()[0].is_some() && players[1].hole_cards()[1].is_some());
+    assert_eq!(eng.deck_remaining(), 48);
+
+    eng.run_out_board().expect("run out");
+    assert_eq!(eng.board().len(), 5);
     // Remaining cards: 52 - 4 (holes) - 3 (flop) - 1 (turn) - 1 (river) - 3 burns = 40
     assert_eq!(eng.deck_remaining(), 40);
 }
+
+#[test]
+fn board_is_revealed_street_by_street() {
+    let mut eng = Engine::new(Some(123), 1);
+    eng.shuffle();
+    eng.deal_hand().expect("deal ok");
+
+    eng.apply_action(0, A::Call).expect("call");
+    assert!(eng.board().is_empty());
+    eng.apply_action(1, A::Check).expect("check");
+    assert_eq!(eng.board().len(), 3);
+    assert_eq!(eng.deck_remaining(), 44);
+
+    eng.apply_action(1, A::Check).expect("check");
+    eng.apply_action(0, A::Check).expect("check");
+    assert_eq!(eng.board().len(), 4);
+
+    eng.apply_action(1, A::Check).expect("check");
+    eng.apply_action(0, A::Check).expect("check");
+    assert_eq!(eng.board().len(), 5);
+    assert!(!eng.is_hand_complete());
+    assert_eq!(eng.deck_remaining(), 40);
+}
+
+#[test]
+fn same_seed_gives_same_runout_however_it_is_dealt() {
+    let mut stepped = Engine::new(Some(77), 1);
+    stepped.shuffle();
+    stepped.deal_hand().expect("deal ok");
+    stepped.apply_action(0, A::Call).expect("call");
+    stepped.apply_action(1, A::Check).expect("check");
+    for _ in 0..3 {
+        stepped.apply_action(1, A::Check).expect("check");
+        stepped.apply_action(0, A::Check).expect("check");
+    }
+
+    let mut run_out = Engine::new(Some(77), 1);
+    run_out.shuffle();
+    run_out.deal_hand().expect("deal ok");
+    run_out.run_out_board().expect("run out");
+
+    assert_eq!(stepped.board(), run_out.board());
+    assert!(stepped.is_hand_complete());
+}
+
+#[test]
+fn folded_hand_does_not_reveal_board() {
+    let mut eng = Engine::new(Some(5), 1);
+    eng.shuffle();
+    eng.deal_hand().expect("deal ok");
+    eng.apply_action(0, A::Fold).expect("fold");
+    assert!(eng.is_hand_complete());
+    assert!(eng.board().is_empty());
+    assert!(eng.run_out_board().is_err());
+}
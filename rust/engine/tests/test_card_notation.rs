@@ -0,0 +1,136 @@
+use axiomind_engine::cards::{
+    Card, Rank, Suit, all_ranks, compact, format_cards, full_deck, parse_cards,
+};
+use axiomind_engine::errors::CardParseError;
+use serde::{Deserialize, Serialize};
+
+fn c(rank: Rank, suit: Suit) -> Card {
+    Card { rank, suit }
+}
+
+#[test]
+fn every_card_roundtrips_through_notation() {
+    for card in full_deck() {
+        let s = card.to_string();
+        assert_eq!(s.len(), 2);
+        assert_eq!(s.parse::<Card>(), Ok(card));
+    }
+    assert_eq!(c(Rank::Ten, Suit::Diamonds).to_string(), "Td");
+    assert_eq!(c(Rank::Two, Suit::Clubs).to_string(), "2c");
+}
+
+#[test]
+fn parsing_accepts_common_variants() {
+    let ace_spades = c(Rank::Ace, Suit::Spades);
+    for s in ["As", "as", "AS", "A♠", " As "] {
+        assert_eq!(s.parse::<Card>(), Ok(ace_spades), "{s}");
+    }
+    assert_eq!("10h".parse::<Card>(), Ok(c(Rank::Ten, Suit::Hearts)));
+    assert_eq!("t".parse::<Rank>(), Ok(Rank::Ten));
+    assert_eq!("♦".parse::<Suit>(), Ok(Suit::Diamonds));
+}
+
+#[test]
+fn parsing_rejects_malformed_cards() {
+    assert_eq!(
+        "Xh".parse::<Card>(),
+        Err(CardParseError::InvalidRank("X".into()))
+    );
+    assert_eq!(
+        "Ax".parse::<Card>(),
+        Err(CardParseError::InvalidSuit("x".into()))
+    );
+    assert_eq!(
+        "A".parse::<Card>(),
+        Err(CardParseError::InvalidCard("A".into()))
+    );
+    assert!("".parse::<Card>().is_err());
+    assert!("AhK".parse::<Card>().is_err());
+}
+
+#[test]
+fn rank_try_from_rejects_out_of_range_values() {
+    for rank in all_ranks() {
+        assert_eq!(Rank::try_from(rank as u8), Ok(rank));
+    }
+    assert_eq!(Rank::try_from(14), Ok(Rank::Ace));
+    for v in [0, 1, 15, 255] {
+        assert_eq!(Rank::try_from(v), Err(CardParseError::InvalidRankValue(v)));
+    }
+}
+
+#[test]
+fn parse_cards_handles_boards_and_separators() {
+    let expected = vec![
+        c(Rank::Ace, Suit::Hearts),
+        c(Rank::King, Suit::Diamonds),
+        c(Rank::Seven, Suit::Clubs),
+    ];
+    assert_eq!(parse_cards("AhKd7c"), Ok(expected.clone()));
+    assert_eq!(parse_cards("Ah Kd 7c"), Ok(expected.clone()));
+    assert_eq!(parse_cards("Ah, Kd,7c"), Ok(expected.clone()));
+    assert_eq!(format_cards(&expected), "AhKd7c");
+    assert_eq!(
+        parse_cards("10sJs"),
+        Ok(vec![
+            c(Rank::Ten, Suit::Spades),
+            c(Rank::Jack, Suit::Spades)
+        ])
+    );
+    assert_eq!(parse_cards(""), Ok(vec![]));
+}
+
+#[test]
+fn parse_cards_reports_duplicates_and_truncation() {
+    assert_eq!(
+        parse_cards("AhKdah"),
+        Err(CardParseError::DuplicateCard(c(Rank::Ace, Suit::Hearts)))
+    );
+    assert_eq!(
+        parse_cards("AhK"),
+        Err(CardParseError::InvalidCard("K".into()))
+    );
+    assert!(parse_cards("AhKz").is_err());
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Compact {
+    #[serde(with = "compact")]
+    card: Card,
+    #[serde(with = "compact::vec")]
+    board: Vec<Card>,
+    #[serde(with = "compact::option_vec")]
+    hole: Option<Vec<Card>>,
+}
+
+#[test]
+fn compact_serde_writes_notation_and_reads_both_forms() {
+    let value = Compact {
+        card: c(Rank::Queen, Suit::Clubs),
+        board: vec![c(Rank::Two, Suit::Hearts)],
+        hole: None,
+    };
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(json, r#"{"card":"Qc","board":["2h"],"hole":null}"#);
+    assert_eq!(serde_json::from_str::<Compact>(&json).unwrap(), value);
+
+    let mixed = r#"{"card":{"suit":"Clubs","rank":"Queen"},"board":["2h"],"hole":["As",{"suit":"Spades","rank":"King"}]}"#;
+    let decoded: Compact = serde_json::from_str(mixed).unwrap();
+    assert_eq!(decoded.card, value.card);
+    assert_eq!(
+        decoded.hole,
+        Some(vec![
+            c(Rank::Ace, Suit::Spades),
+            c(Rank::King, Suit::Spades)
+        ])
+    );
+
+    let bad = r#"{"card":"Zz","board":[],"hole":null}"#;
+    assert!(serde_json::from_str::<Compact>(bad).is_err());
+}
+
+#[test]
+fn default_serde_form_is_unchanged() {
+    let json = serde_json::to_string(&c(Rank::Ace, Suit::Hearts)).unwrap();
+    assert_eq!(json, r#"{"suit":"Hearts","rank":"Ace"}"#);
+}
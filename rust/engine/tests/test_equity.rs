@@ -0,0 +1,120 @@
+use axiomind_engine::cards::{Card, parse_cards};
+use axiomind_engine::equity::{EquityCalculator, equity};
+use axiomind_engine::errors::GameError;
+
+fn hand(s: &str) -> [Card; 2] {
+    parse_cards(s).unwrap().try_into().unwrap()
+}
+
+fn cards(s: &str) -> Vec<Card> {
+    parse_cards(s).unwrap()
+}
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-12
+}
+
+#[test]
+fn turn_spot_matches_hand_count() {
+    // Kings need one of the two remaining kings on the river
+    let result = equity(&[hand("AhAd"), hand("KcKs")], &cards("2c7d9hTs")).unwrap();
+    assert!(result.exact);
+    assert_eq!(result.runouts, 44);
+    assert!(close(result.hands[1].win, 2.0 / 44.0));
+    assert!(close(result.hands[0].equity, 42.0 / 44.0));
+    assert!(close(result.hands[0].lose, 2.0 / 44.0));
+    assert_eq!(result.hands[0].std_error, 0.0);
+}
+
+#[test]
+fn dead_cards_are_removed_from_the_runouts() {
+    let result = EquityCalculator::new(vec![hand("AhAd"), hand("KcKs")])
+        .with_board(cards("2c7d9hTs"))
+        .with_dead_cards(cards("Kh"))
+        .calculate()
+        .unwrap();
+    assert_eq!(result.runouts, 43);
+    assert!(close(result.hands[1].equity, 1.0 / 43.0));
+}
+
+#[test]
+fn identical_hands_split_every_runout() {
+    let result = equity(&[hand("AhKd"), hand("AcKs")], &cards("2c7d9hTs")).unwrap();
+    for h in &result.hands {
+        assert!(close(h.tie, 1.0));
+        assert!(close(h.equity, 0.5));
+        assert_eq!(h.win, 0.0);
+    }
+}
+
+#[test]
+fn multiway_equities_sum_to_one() {
+    let result = equity(
+        &[hand("AhKh"), hand("QsQd"), hand("9c8c")],
+        &cards("Jh7h2c"),
+    )
+    .unwrap();
+    assert!(result.exact);
+    assert_eq!(result.runouts, 903); // C(43, 2)
+    let total: f64 = result.hands.iter().map(|h| h.equity).sum();
+    assert!((total - 1.0).abs() < 1e-9);
+    for h in &result.hands {
+        assert!((h.win + h.tie + h.lose - 1.0).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn monte_carlo_is_seeded_and_close_to_exact() {
+    let calc = EquityCalculator::new(vec![hand("AhKh"), hand("QsQd")]).with_board(cards("Jh7h2c"));
+    let exact = calc.calculate().unwrap();
+
+    let sampled = calc
+        .clone()
+        .with_exact_limit(0)
+        .with_samples(20_000)
+        .with_seed(7);
+    let a = sampled.calculate().unwrap();
+    let b = sampled.calculate().unwrap();
+    assert_eq!(a, b);
+    assert!(!a.exact);
+    assert_eq!(a.runouts, 20_000);
+
+    let se = a.hands[0].std_error;
+    assert!(se > 0.0 && se < 0.01);
+    assert!((a.hands[0].equity - exact.hands[0].equity).abs() < 5.0 * se);
+
+    let other_seed = sampled.with_seed(8).calculate().unwrap();
+    assert_ne!(other_seed, a);
+}
+
+#[test]
+fn preflop_uses_monte_carlo_by_default() {
+    let calc = EquityCalculator::new(vec![hand("AsAh"), hand("KdKc")]).with_samples(5_000);
+    assert_eq!(calc.runouts(), 1_712_304);
+    let result = calc.calculate().unwrap();
+    assert!(!result.exact);
+    // Aces are about a 4:1 favourite
+    assert!((result.hands[0].equity - 0.82).abs() < 0.03);
+}
+
+#[test]
+fn rejects_invalid_queries() {
+    assert!(matches!(
+        equity(&[hand("AhKh")], &[]),
+        Err(GameError::InvalidEquityQuery(_))
+    ));
+    assert_eq!(
+        equity(&[hand("AhKh"), hand("AhQd")], &[]),
+        Err(GameError::DuplicateCard(hand("AhKh")[0]))
+    );
+    assert!(matches!(
+        equity(&[hand("AhKh"), hand("QsQd")], &cards("2c3c4c5c6c7c")),
+        Err(GameError::InvalidEquityQuery(_))
+    ));
+    assert!(matches!(
+        EquityCalculator::new(vec![hand("AhKh"), hand("QsQd")])
+            .with_samples(0)
+            .calculate(),
+        Err(GameError::InvalidEquityQuery(_))
+    ));
+}
@@ -0,0 +1,245 @@
+use std::fs;
+use std::path::PathBuf;
+
+use axiomind_engine::engine::Engine;
+use axiomind_engine::events::{EngineEvent, EventQueue, ForcedBet};
+use axiomind_engine::logger::{HandLogger, HandRecord, Street};
+use axiomind_engine::player::PlayerAction as A;
+use axiomind_engine::rules::{BlindLevel, BlindStructure};
+use axiomind_engine::variant::GameVariant;
+use std::sync::{Arc, Mutex};
+
+fn tmp_path(name: &str) -> PathBuf {
+    let mut p = PathBuf::from("target");
+    p.push(format!("{}_{}.jsonl", name, std::process::id()));
+    p
+}
+
+/// Check down a dealt hand to showdown.
+fn check_down(eng: &mut Engine) {
+    eng.apply_action(eng.button(), A::Call).expect("call");
+    while !eng.is_hand_complete() {
+        let actor = eng.current_player().expect("actor");
+        eng.apply_action(actor, A::Check).expect("check");
+    }
+}
+
+#[test]
+fn deal_reports_start_blinds_and_hole_cards() {
+    let queue = EventQueue::new();
+    let mut eng = Engine::new(Some(7), 1).with_blind_structure(BlindStructure {
+        hands_per_level: 15,
+        big_blind_ante: true,
+        levels: vec![BlindLevel {
+            sb: 50,
+            bb: 100,
+            ante: 100,
+        }],
+    });
+    eng.add_observer(queue.clone());
+    eng.set_button(1);
+    eng.deal_hand().expect("deal");
+
+    let events = queue.drain();
+    assert_eq!(
+        events[..4],
+        [
+            EngineEvent::HandStarted {
+                button: 1,
+                level: 1,
+                small_blind: 50,
+                big_blind: 100,
+                stacks: vec![20_000, 20_000],
+                variant: GameVariant::Holdem,
+            },
+            EngineEvent::BlindPosted {
+                player_id: 1,
+                kind: ForcedBet::SmallBlind,
+                amount: 50,
+            },
+            EngineEvent::BlindPosted {
+                player_id: 0,
+                kind: ForcedBet::BigBlind,
+                amount: 100,
+            },
+            EngineEvent::BlindPosted {
+                player_id: 0,
+                kind: ForcedBet::Ante,
+                amount: 100,
+            },
+        ]
+    );
+    for (seat, event) in events[4..].iter().enumerate() {
+        let EngineEvent::HoleCardsDealt { player_id, cards } = event else {
+            panic!("expected hole cards, got {:?}", event);
+        };
+        assert_eq!(*player_id, seat);
+        assert_eq!(
+            eng.players()[seat].hole_cards(),
+            [Some(cards[0]), Some(cards[1])]
+        );
+    }
+    assert_eq!(events.len(), 6);
+}
+
+#[test]
+fn events_follow_the_hand_to_showdown() {
+    let queue = EventQueue::new();
+    let mut eng = Engine::new(Some(21), 1);
+    eng.add_observer(queue.clone());
+    eng.deal_hand().expect("deal");
+    queue.drain();
+    check_down(&mut eng);
+
+    let events = queue.drain();
+    let actions: Vec<_> = events
+        .iter()
+        .filter_map(|e| match e {
+            EngineEvent::ActionApplied { record, .. } => Some(record.clone()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(actions, eng.action_history());
+
+    let streets: Vec<_> = events
+        .iter()
+        .filter_map(|e| match e {
+            EngineEvent::StreetChanged {
+                street,
+                cards,
+                board,
+            } => Some((*street, cards.len(), board.len())),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        streets,
+        [
+            (Street::Flop, 3, 3),
+            (Street::Turn, 1, 4),
+            (Street::River, 1, 5)
+        ]
+    );
+
+    let n = events.len();
+    assert!(matches!(&events[n - 3], EngineEvent::Showdown { hands } if hands.len() == 2));
+    assert!(matches!(&events[n - 2], EngineEvent::PotAwarded(pot) if pot.amount == 200));
+    assert_eq!(
+        events[n - 1],
+        EngineEvent::HandCompleted(eng.hand_outcome().expect("outcome").clone())
+    );
+    // The last action's pot is the whole pot
+    let last_pot = events.iter().rev().find_map(|e| match e {
+        EngineEvent::ActionApplied { pot, .. } => Some(*pot),
+        _ => None,
+    });
+    assert_eq!(last_pot, Some(200));
+}
+
+#[test]
+fn fold_reports_uncalled_bet_without_showdown() {
+    let queue = EventQueue::new();
+    let mut eng = Engine::new(Some(4), 1);
+    eng.add_observer(queue.clone());
+    eng.deal_hand().expect("deal");
+    eng.apply_action(0, A::Raise(200)).expect("raise");
+    queue.drain();
+    eng.apply_action(1, A::Fold).expect("fold");
+
+    let events = queue.drain();
+    assert!(matches!(
+        events[0],
+        EngineEvent::ActionApplied {
+            amount: 0,
+            pot: 400,
+            ..
+        }
+    ));
+    assert_eq!(
+        events[1],
+        EngineEvent::UncalledBetReturned {
+            player_id: 0,
+            amount: 200
+        }
+    );
+    assert!(matches!(events[2], EngineEvent::PotAwarded(_)));
+    assert!(matches!(events[3], EngineEvent::HandCompleted(_)));
+    assert!(
+        !events
+            .iter()
+            .any(|e| matches!(e, EngineEvent::Showdown { .. }))
+    );
+}
+
+#[test]
+fn undo_reports_rewound_state() {
+    let queue = EventQueue::new();
+    let mut eng = Engine::new(Some(2), 1);
+    eng.add_observer(queue.clone());
+    eng.deal_hand().expect("deal");
+    eng.apply_action(0, A::Call).expect("call");
+    eng.apply_action(1, A::Check).expect("check");
+    queue.drain();
+
+    eng.undo_last_action().expect("undo");
+    assert_eq!(
+        queue.drain(),
+        [EngineEvent::Rewound {
+            action_index: 1,
+            street: Street::Preflop,
+            board: vec![],
+        }]
+    );
+}
+
+#[test]
+fn closures_observe_events() {
+    let seen = Arc::new(Mutex::new(0usize));
+    let counter = Arc::clone(&seen);
+    let mut eng = Engine::new(Some(3), 1);
+    eng.add_observer(move |event: &EngineEvent| {
+        if matches!(event, EngineEvent::HandCompleted(_)) {
+            *counter.lock().unwrap() += 1;
+        }
+    });
+    for _ in 0..3 {
+        eng.deal_hand().expect("deal");
+        check_down(&mut eng);
+    }
+    assert_eq!(*seen.lock().unwrap(), 3);
+}
+
+#[test]
+fn hand_logger_records_hands_from_events() {
+    let path = tmp_path("observed_hands");
+    let mut eng = Engine::new(Some(11), 1);
+    eng.add_observer(HandLogger::create(&path).expect("create logger"));
+
+    eng.deal_hand().expect("deal");
+    check_down(&mut eng);
+    let first = (eng.action_history(), eng.board().clone());
+
+    eng.set_button(1);
+    eng.deal_hand().expect("deal");
+    eng.apply_action(1, A::Fold).expect("fold");
+
+    let content = fs::read_to_string(&path).expect("read log");
+    let records: Vec<HandRecord> = content
+        .lines()
+        .map(|l| serde_json::from_str(l).expect("record"))
+        .collect();
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[0].hand_id, "19700101-000001");
+    assert_eq!(
+        (records[0].actions.clone(), records[0].board.clone()),
+        first
+    );
+    assert!(records[0].showdown.is_some());
+    assert_eq!(records[1].hand_id, "19700101-000002");
+    assert_eq!(records[1].actions, eng.action_history());
+    assert!(records[1].board.is_empty());
+    assert_eq!(
+        records[1].result.as_deref(),
+        Some("Player 0 wins 150 (fold)")
+    );
+}
@@ -1,17 +1,26 @@
 use axiomind_engine::engine::Engine;
+use axiomind_engine::player::PlayerAction as A;
 
 #[test]
 fn deal_hand_progresses_streets_and_completes() {
     let mut eng = Engine::new(Some(1), 1);
     eng.shuffle();
     eng.deal_hand().expect("deal ok");
-    let board = eng.board();
-    assert_eq!(board.len(), 5);
+    assert!(eng.board().is_empty());
     let players = eng.players();
     assert!(
         players
             .iter()
             .all(|p| p.hole_cards()[0].is_some() && p.hole_cards()[1].is_some())
     );
+    assert!(!eng.is_hand_complete());
+
+    eng.apply_action(0, A::Call).expect("call");
+    eng.apply_action(1, A::Check).expect("check");
+    for _ in 0..3 {
+        eng.apply_action(1, A::Check).expect("check");
+        eng.apply_action(0, A::Check).expect("check");
+    }
+    assert_eq!(eng.board().len(), 5);
     assert!(eng.is_hand_complete());
 }
@@ -0,0 +1,122 @@
+use axiomind_engine::cards::{Card, full_deck, parse_cards};
+use axiomind_engine::hand::{
+    Category, compare_hands, evaluate_hand, evaluate_hand_optimized, evaluate_hand_value,
+};
+use rand::seq::SliceRandom;
+use rand::{SeedableRng, rngs::StdRng};
+
+fn seven(s: &str) -> [Card; 7] {
+    parse_cards(s)
+        .expect("cards")
+        .try_into()
+        .expect("seven cards")
+}
+
+#[test]
+fn three_pairs_use_the_third_pair_as_kicker() {
+    let cards = seven("AhAdKcKsQhQd2c");
+    let base = evaluate_hand(&cards);
+    assert_eq!(base.category, Category::TwoPair);
+    assert_eq!(base.kickers, [14, 13, 12, 0, 0]);
+    assert_eq!(evaluate_hand_optimized(&cards), base);
+}
+
+#[test]
+fn table_matches_baseline_on_random_hands() {
+    let mut rng = StdRng::seed_from_u64(12);
+    let mut deck = full_deck();
+    for _ in 0..200_000 {
+        deck.shuffle(&mut rng);
+        let cards: [Card; 7] = deck[..7].try_into().unwrap();
+        assert_eq!(
+            evaluate_hand_optimized(&cards),
+            evaluate_hand(&cards),
+            "{:?}",
+            cards
+        );
+    }
+}
+
+#[test]
+fn hand_values_order_like_compare_hands() {
+    let mut rng = StdRng::seed_from_u64(5);
+    let mut deck = full_deck();
+    for _ in 0..50_000 {
+        deck.shuffle(&mut rng);
+        let a: [Card; 7] = deck[..7].try_into().unwrap();
+        let b: [Card; 7] = deck[7..14].try_into().unwrap();
+        assert_eq!(
+            evaluate_hand_value(&a).cmp(&evaluate_hand_value(&b)),
+            compare_hands(&evaluate_hand(&a), &evaluate_hand(&b))
+        );
+    }
+}
+
+#[test]
+fn table_handles_every_category_edge() {
+    for s in [
+        "AhKhQhJhTh2c3d", // royal flush
+        "Ah2h3h4h5hKcKd", // steel wheel
+        "6h2h3h4h5hAhKd", // six-high straight flush over the wheel
+        "AcAdAhAsKcKdKh", // quads with a trips kicker
+        "KcKdKhQcQdQh2s", // two trips make a full house
+        "KcKdKhQcQd2h2s", // trips and two pairs
+        "Ah2c3d4s5hKcQd", // wheel
+        "AhKhQhJh9h8h7h", // seven-card flush
+        "2c3d4h5s7c8d9h", // no straight
+        "QcQhQd2s3c4h8d", // trips
+        "AcKdQhJs9c8d6h", // high card
+    ] {
+        let cards = seven(s);
+        assert_eq!(
+            evaluate_hand_optimized(&cards),
+            evaluate_hand(&cards),
+            "{s}"
+        );
+    }
+}
+
+/// Cross-check every 7-card combination (133,784,560 hands).
+///
+/// Run with `cargo test --release -p axiomind-engine --test test_hand_eval_table -- --ignored`.
+#[test]
+#[ignore = "exhaustive: takes minutes even in release mode"]
+fn table_matches_baseline_on_every_seven_card_hand() {
+    let deck = full_deck();
+    let checked: u64 = std::thread::scope(|scope| {
+        let workers: Vec<_> = (0..46)
+            .map(|a| {
+                let deck = &deck;
+                scope.spawn(move || {
+                    let mut n = 0u64;
+                    for b in a + 1..52 {
+                        for c in b + 1..52 {
+                            for d in c + 1..52 {
+                                for e in d + 1..52 {
+                                    for f in e + 1..52 {
+                                        for g in f + 1..52 {
+                                            let cards = [
+                                                deck[a], deck[b], deck[c], deck[d], deck[e],
+                                                deck[f], deck[g],
+                                            ];
+                                            assert_eq!(
+                                                evaluate_hand_optimized(&cards),
+                                                evaluate_hand(&cards),
+                                                "{:?}",
+                                                cards
+                                            );
+                                            n += 1;
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    n
+                })
+            })
+            .collect();
+        workers.into_iter().map(|w| w.join().unwrap()).sum()
+    });
+    assert_eq!(checked, 133_784_560);
+}
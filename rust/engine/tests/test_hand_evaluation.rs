@@ -1,5 +1,5 @@
 use axiomind_engine::cards::{Card, Rank as R, Suit as S};
-use axiomind_engine::hand::{Category, compare_hands, evaluate_hand};
+use axiomind_engine::hand::{Category, compare_hands, evaluate_batch_optimized, evaluate_hand};
 
 fn c(s: S, r: R) -> Card {
     Card { suit: s, rank: r }
@@ -121,3 +121,51 @@ fn pair_vs_high_card() {
     let b = evaluate_hand(&high);
     assert!(compare_hands(&a, &b).is_gt());
 }
+
+#[test]
+fn third_pair_plays_as_the_two_pair_kicker() {
+    // Kings and queens with nines: the best five cards are KKQQ9
+    let three_pairs = [
+        c(S::Clubs, R::King),
+        c(S::Diamonds, R::King),
+        c(S::Clubs, R::Queen),
+        c(S::Diamonds, R::Queen),
+        c(S::Clubs, R::Nine),
+        c(S::Diamonds, R::Nine),
+        c(S::Hearts, R::Two),
+    ];
+    let eight_kicker = [
+        c(S::Hearts, R::King),
+        c(S::Spades, R::King),
+        c(S::Hearts, R::Queen),
+        c(S::Spades, R::Queen),
+        c(S::Hearts, R::Eight),
+        c(S::Spades, R::Three),
+        c(S::Clubs, R::Two),
+    ];
+    let a = evaluate_hand(&three_pairs);
+    let b = evaluate_hand(&eight_kicker);
+    assert_eq!(a.category, Category::TwoPair);
+    assert_eq!(a.kickers[..3], [13, 12, 9]);
+    assert!(compare_hands(&a, &b).is_gt());
+}
+
+#[test]
+fn batch_optimized_evaluates_each_hand() {
+    let cards = [
+        c(S::Clubs, R::Ace),
+        c(S::Diamonds, R::Ace),
+        c(S::Hearts, R::King),
+        c(S::Spades, R::Queen),
+        c(S::Clubs, R::Jack),
+        c(S::Clubs, R::Two),
+        c(S::Diamonds, R::Three),
+    ];
+    let mut other = cards;
+    other[0] = c(S::Spades, R::Nine);
+    let out = evaluate_batch_optimized(&[cards, other, cards]);
+    assert_eq!(out.len(), 3);
+    assert_eq!(out[0], evaluate_hand(&cards));
+    assert_eq!(out[1], evaluate_hand(&other));
+    assert_eq!(out[2], out[0]);
+}
@@ -22,6 +22,7 @@ fn writes_jsonl_with_lf_only() {
             player_id: 0,
             street: Street::Preflop,
             action: PlayerAction::Check,
+            amount: None,
         }],
         board: vec![Card {
             suit: S::Clubs,
@@ -31,6 +32,7 @@ fn writes_jsonl_with_lf_only() {
         ts: None,
         meta: None,
         showdown: None,
+        ..Default::default()
     };
     logger.write(&rec).expect("write");
     let bytes = fs::read(&path).expect("read file");
@@ -62,6 +64,7 @@ fn ts_is_generated_when_missing_and_preserved_when_present() {
         ts: None,
         meta: None,
         showdown: None,
+        ..Default::default()
     };
     logger.write(&rec).expect("write");
     let line = String::from_utf8(fs::read(&path).unwrap()).unwrap();
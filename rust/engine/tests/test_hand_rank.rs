@@ -0,0 +1,137 @@
+use axiomind_engine::cards::{Card, format_cards, parse_cards};
+use axiomind_engine::errors::GameError;
+use axiomind_engine::hand::{
+    Category, HAND_CLASSES, HandStrength, canonical_hand, compare_hands, evaluate_best,
+    evaluate_hand_value, rank_on_board,
+};
+use std::cmp::Ordering;
+
+fn index_of(cards: &str) -> u16 {
+    evaluate_best(&parse_cards(cards).unwrap())
+        .unwrap()
+        .strength
+        .rank_index()
+}
+
+fn hole(cards: &str) -> [Card; 2] {
+    parse_cards(cards).unwrap().try_into().unwrap()
+}
+
+#[test]
+fn every_index_round_trips_through_its_canonical_hand() {
+    let mut previous: Option<HandStrength> = None;
+    for index in 1..=HAND_CLASSES {
+        let best = canonical_hand(index).unwrap();
+        assert_eq!(best.strength.rank_index(), index);
+        assert_eq!(
+            HandStrength::from_rank_index(index),
+            Some(best.strength.clone())
+        );
+        // Re-evaluating the canonical cards gives them back in the same order
+        assert_eq!(evaluate_best(&best.cards).unwrap(), best);
+        if let Some(previous) = previous {
+            assert_eq!(compare_hands(&previous, &best.strength), Ordering::Less);
+        }
+        previous = Some(best.strength);
+    }
+    for outside in [0, HAND_CLASSES + 1] {
+        assert!(canonical_hand(outside).is_none());
+        assert!(HandStrength::from_rank_index(outside).is_none());
+    }
+}
+
+#[test]
+fn classes_per_category_match_the_known_counts() {
+    let mut counts = [0u16; 9];
+    for index in 1..=HAND_CLASSES {
+        let strength = HandStrength::from_rank_index(index).unwrap();
+        counts[strength.category as usize] += 1;
+    }
+    assert_eq!(counts, [1277, 2860, 858, 858, 10, 1277, 156, 156, 10]);
+    assert_eq!(
+        HandStrength::from_rank_index(1277 + 1).unwrap().category,
+        Category::OnePair
+    );
+}
+
+#[test]
+fn rank_index_orders_hands_like_the_evaluator() {
+    assert_eq!(index_of("7c5d4h3s2c"), 1);
+    assert_eq!(index_of("AsKsQsJsTs"), HAND_CLASSES);
+    assert_eq!(index_of("5s4s3s2sAs"), HAND_CLASSES - 9);
+    // Suits do not matter outside flushes
+    assert_eq!(index_of("KhKd7s7c2h"), index_of("KsKc7h7d2s"));
+    // Six and seven cards rank by their best five
+    assert_eq!(index_of("AhAd Kc Qs 2h 3c 7d"), index_of("AhAdKcQs7d"));
+
+    let hands = [
+        "AhJh8h4h2h9c9d",
+        "AhJh8h4d9s9c9d",
+        "2c2d3h3s4c4d5h",
+        "Ts9s8s7s6s2h2d",
+        "AcAdAhKsKd2c3c",
+    ];
+    for a in hands {
+        for b in hands {
+            let (x, y) = (parse_cards(a).unwrap(), parse_cards(b).unwrap());
+            let by_value = evaluate_hand_value(&x.clone().try_into().unwrap())
+                .cmp(&evaluate_hand_value(&y.clone().try_into().unwrap()));
+            let by_index = evaluate_best(&x)
+                .unwrap()
+                .strength
+                .rank_index()
+                .cmp(&evaluate_best(&y).unwrap().strength.rank_index());
+            assert_eq!(by_index, by_value, "{a} vs {b}");
+        }
+    }
+}
+
+#[test]
+fn canonical_hands_are_fixed_representatives() {
+    assert_eq!(
+        format_cards(&canonical_hand(1).unwrap().cards),
+        "7c5c4c3c2d"
+    );
+    let wheel = canonical_hand(index_of("5s4d3h2cAc")).unwrap();
+    assert_eq!(wheel.describe(), "Straight, Five-high");
+    assert_eq!(wheel.cards[4].rank, parse_cards("Ac").unwrap()[0].rank);
+}
+
+#[test]
+fn board_rank_counts_every_opponent_holding() {
+    // Nobody beats the royal flush; the river leaves 45 cards, 990 holdings
+    let nuts = rank_on_board(&hole("AhKh"), &parse_cards("QhJhTh2c3d").unwrap()).unwrap();
+    assert_eq!(nuts.rank_index, HAND_CLASSES);
+    assert_eq!((nuts.beaten_by, nuts.ties, nuts.beats), (0, 0, 990));
+    assert_eq!(nuts.percentile(), 100.0);
+
+    // A royal flush on the board is shared by everyone
+    let board = parse_cards("AsKsQsJsTs").unwrap();
+    let chop = rank_on_board(&hole("2c3d"), &board).unwrap();
+    assert_eq!((chop.beaten_by, chop.ties, chop.beats), (0, 990, 0));
+    assert_eq!(chop.percentile(), 50.0);
+
+    // On the flop 47 cards are left: 1081 holdings
+    let flop = rank_on_board(&hole("7c2d"), &parse_cards("AsKhQd").unwrap()).unwrap();
+    assert_eq!(flop.holdings(), 1081);
+    let pair = rank_on_board(&hole("Qc2d"), &parse_cards("AsKhQd").unwrap()).unwrap();
+    assert!(flop.percentile() < pair.percentile());
+    assert!(pair.rank_index > flop.rank_index);
+
+    // The turn leaves 46 cards
+    let turn = rank_on_board(&hole("7c2d"), &parse_cards("AsKhQd9c").unwrap()).unwrap();
+    assert_eq!(turn.holdings(), 1035);
+}
+
+#[test]
+fn board_rank_rejects_bad_boards() {
+    assert_eq!(
+        rank_on_board(&hole("AhKh"), &parse_cards("QhJh").unwrap()).unwrap_err(),
+        GameError::InvalidHandSize(4)
+    );
+    let card = parse_cards("Ah").unwrap()[0];
+    assert_eq!(
+        rank_on_board(&hole("AhKh"), &parse_cards("AhJhTh").unwrap()).unwrap_err(),
+        GameError::DuplicateCard(card)
+    );
+}
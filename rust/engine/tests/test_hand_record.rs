@@ -1,5 +1,8 @@
 use axiomind_engine::cards::{Card, Rank, Suit};
-use axiomind_engine::logger::{ActionRecord, HandRecord, Street, format_hand_id};
+use axiomind_engine::engine::EndReason;
+use axiomind_engine::logger::{
+    ActionRecord, HAND_RECORD_SCHEMA_VERSION, HandRecord, Street, format_hand_id,
+};
 use axiomind_engine::player::PlayerAction;
 
 #[test]
@@ -12,11 +15,13 @@ fn hand_record_serializes_and_deserializes() {
                 player_id: 0,
                 street: Street::Preflop,
                 action: PlayerAction::Bet(50),
+                amount: None,
             },
             ActionRecord {
                 player_id: 1,
                 street: Street::Preflop,
                 action: PlayerAction::Call,
+                amount: None,
             },
         ],
         board: vec![
@@ -37,6 +42,7 @@ fn hand_record_serializes_and_deserializes() {
         ts: None,
         meta: None,
         showdown: None,
+        ..Default::default()
     };
 
     let s = serde_json::to_string(&rec).expect("serialize");
@@ -49,3 +55,105 @@ fn id_format_matches_spec() {
     let id = format_hand_id("20251231", 42);
     assert_eq!(id, "20251231-000042");
 }
+
+#[test]
+fn v1_records_upgrade_on_read() {
+    let eng = axiomind_engine::engine::Engine::new(Some(1), 3);
+    let v1 = serde_json::json!({
+        "hand_id": "20250102-000001",
+        "seed": 1,
+        "actions": [{"player_id": 0, "street": "Preflop", "action": "Fold"}],
+        "board": [],
+        "result": "Player 1 wins 150 (fold)",
+        "meta": eng.record_meta(),
+    });
+    let rec: HandRecord = serde_json::from_value(v1).expect("v1 record");
+    assert_eq!(rec.schema_version, HAND_RECORD_SCHEMA_VERSION);
+    assert_eq!((rec.level, rec.sb, rec.bb), (Some(3), Some(100), Some(200)));
+    assert_eq!(rec.button.as_deref(), Some("p0"));
+    assert_eq!(rec.end_reason, Some(EndReason::Fold));
+    assert_eq!(rec.actions[0].amount, None);
+    assert!(rec.players.is_empty() && rec.net_result.is_empty());
+
+    // Upgraded records are written back as the current version
+    let v: serde_json::Value = serde_json::to_value(&rec).unwrap();
+    assert_eq!(v["schema_version"], 2);
+}
+
+#[test]
+fn v1_sim_records_keep_their_level() {
+    // Shape written by `sim` before schema_version: level at the top, no meta
+    let line = r#"{"hand_id":"19700101-000001","seed":42,"level":3,"actions":[],"board":[],"result":"Player 0 wins 300 (showdown)","ts":"1970-01-01T00:00:00+00:00","meta":null,"showdown":{"winners":[0]}}"#;
+    let rec: HandRecord = serde_json::from_str(line).expect("v1 sim record");
+    assert_eq!((rec.level, rec.sb, rec.bb), (Some(3), Some(100), Some(200)));
+    assert_eq!(rec.end_reason, Some(EndReason::Showdown));
+}
+
+#[test]
+fn unknown_schema_versions_are_rejected() {
+    let future = r#"{"schema_version":3,"hand_id":"20250102-000001","seed":null,"actions":[],"board":[],"result":null}"#;
+    let err = serde_json::from_str::<HandRecord>(future).unwrap_err();
+    assert!(err.to_string().contains("schema_version 3"), "{}", err);
+}
+
+#[test]
+fn engine_record_describes_the_whole_hand() {
+    use axiomind_engine::engine::Engine;
+    use axiomind_engine::logger::{HandLogger, ShownHand, seat_id};
+
+    let path = std::env::temp_dir().join(format!("hand_record_v2_{}.jsonl", std::process::id()));
+    let mut eng = Engine::new(Some(21), 1);
+    eng.add_observer(HandLogger::create(&path).expect("logger"));
+    eng.set_button(1);
+    eng.deal_hand().expect("deal");
+    eng.apply_action(1, PlayerAction::Raise(200))
+        .expect("raise");
+    eng.apply_action(0, PlayerAction::Call).expect("call");
+    while !eng.is_hand_complete() {
+        let actor = eng.current_player().expect("actor");
+        eng.apply_action(actor, PlayerAction::Check).expect("check");
+    }
+
+    let rec = eng.hand_record("19700101-000001", Some(21));
+    assert_eq!(rec.button.as_deref(), Some("p1"));
+    assert!(rec.players.iter().all(|p| p.stack_start == 20_000));
+    assert_eq!(rec.actions[0].amount, Some(250));
+    assert_eq!(rec.actions[1].amount, Some(200));
+    assert_eq!(rec.net_result.values().sum::<i64>(), 0);
+    assert_eq!(rec.end_reason, Some(EndReason::Showdown));
+
+    let outcome = eng.hand_outcome().expect("outcome");
+    let showdown = rec.showdown.clone().expect("showdown");
+    assert_eq!(showdown.hands.len(), 2);
+    for ShownHand {
+        player,
+        cards,
+        won,
+        amount,
+        hand,
+    } in &showdown.hands
+    {
+        assert!(hand.is_some());
+        let seat = if player == "p0" { 0 } else { 1 };
+        assert_eq!(*player, seat_id(seat));
+        assert_eq!(
+            eng.players()[seat].hole_cards(),
+            [Some(cards[0]), Some(cards[1])]
+        );
+        assert_eq!(*won, outcome.winners.contains(&seat));
+        assert_eq!(*amount, outcome.payouts[seat]);
+    }
+
+    // The logger builds the same record from the event stream alone
+    let line = std::fs::read_to_string(&path).expect("read log");
+    let logged: HandRecord = serde_json::from_str(line.trim()).expect("logged record");
+    assert_eq!(
+        HandRecord { ts: None, ..logged },
+        HandRecord {
+            seed: None,
+            meta: None,
+            ..rec
+        }
+    );
+    let _ = std::fs::remove_file(&path);
+}
@@ -0,0 +1,144 @@
+use axiomind_engine::engine::Engine;
+use axiomind_engine::player::PlayerAction as A;
+use axiomind_engine::rules::{
+    LegalActions, MIN_CHIP_UNIT, SizeRange, legal_actions, validate_action,
+};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// Every concrete action a client could pick from `legal`, sampling bet and
+/// raise sizes at both ends of the range and on the chip unit in between.
+fn enumerate(legal: &LegalActions) -> Vec<A> {
+    let mut actions = Vec::new();
+    if legal.fold {
+        actions.push(A::Fold);
+    }
+    if legal.check {
+        actions.push(A::Check);
+    }
+    if legal.call.is_some() {
+        actions.push(A::Call);
+    }
+    if let Some(range) = legal.bet {
+        for size in sample_sizes(range) {
+            actions.push(A::Bet(size));
+        }
+    }
+    if let Some(range) = legal.raise_to {
+        for total in sample_sizes(range) {
+            actions.push(A::Raise(total - legal.current_bet));
+        }
+    }
+    if legal.all_in.is_some() {
+        actions.push(A::AllIn);
+    }
+    actions
+}
+
+fn sample_sizes(range: SizeRange) -> Vec<u32> {
+    let mut sizes = vec![range.min, range.max];
+    let mid = range.clamp(range.min + (range.max - range.min) / 2);
+    sizes.push(mid);
+    sizes
+}
+
+#[test]
+fn facing_the_big_blind() {
+    let legal = legal_actions(19_950, 50, 100, 100);
+    assert!(legal.fold);
+    assert!(!legal.check);
+    assert_eq!(legal.call, Some(50));
+    assert_eq!(legal.bet, None);
+    assert_eq!(
+        legal.raise_to,
+        Some(SizeRange {
+            min: 200,
+            max: 20_000
+        })
+    );
+    assert_eq!(legal.all_in, Some(19_950));
+}
+
+#[test]
+fn short_stack_can_only_call_all_in_or_fold() {
+    let legal = legal_actions(80, 100, 100, 100);
+    assert_eq!(legal.call, Some(80));
+    assert_eq!(legal.raise_to, None);
+    assert_eq!(legal.all_in, Some(80));
+}
+
+#[test]
+fn stack_below_min_raise_raises_all_in() {
+    // 150 behind, 100 to call: the only raise is all-in to 250
+    let legal = legal_actions(150, 100, 200, 100);
+    let range = legal.raise_to.expect("raise");
+    assert_eq!(range, SizeRange { min: 250, max: 250 });
+    assert_eq!(legal.raise_to_action(1_000), Some(A::AllIn));
+}
+
+#[test]
+fn bets_below_minimum_are_rejected() {
+    assert!(validate_action(10_000, 0, 100, A::Bet(50)).is_err());
+    let legal = legal_actions(10_000, 0, 0, 100);
+    assert!(!legal.allows(&A::Bet(50)));
+    assert!(legal.allows(&A::Bet(100)));
+}
+
+#[test]
+fn raise_to_action_snaps_to_chip_unit() {
+    let legal = legal_actions(10_000, 100, 200, 100);
+    assert_eq!(legal.raise_to_action(640), Some(A::Raise(425)));
+    assert_eq!(legal.raise_to_action(0), Some(A::Raise(100)));
+    assert_eq!(legal_actions(10_000, 0, 0, 100).raise_to_action(300), None);
+    assert_eq!(MIN_CHIP_UNIT, 25);
+}
+
+#[test]
+fn every_enumerated_action_is_accepted() {
+    let mut rng = ChaCha8Rng::seed_from_u64(5);
+    for _ in 0..5_000 {
+        let stack = rng.random_range(0..=5_000);
+        let current_bet = rng.random_range(0..=2_000);
+        let to_call = rng.random_range(0..=current_bet);
+        let min_raise = rng.random_range(1..=1_000);
+        let legal = legal_actions(stack, to_call, current_bet, min_raise);
+        for action in enumerate(&legal) {
+            assert!(
+                legal.allows(&action),
+                "{:?} not allowed by {:?}",
+                action,
+                legal
+            );
+            assert!(
+                validate_action(stack, to_call, min_raise, action.clone()).is_ok(),
+                "{:?} rejected: stack={} to_call={} bet={} min_raise={}",
+                action,
+                stack,
+                to_call,
+                current_bet,
+                min_raise
+            );
+        }
+    }
+}
+
+#[test]
+fn engine_accepts_every_enumerated_action_in_random_hands() {
+    let mut rng = ChaCha8Rng::seed_from_u64(11);
+    for seed in 0..200 {
+        let mut eng = Engine::new(Some(seed), 1);
+        eng.deal_hand().expect("deal");
+        while !eng.is_hand_complete() {
+            let actor = eng.current_player().expect("actor");
+            assert!(eng.legal_actions(1 - actor).is_empty());
+            let legal = eng.legal_actions(actor);
+            let choices = enumerate(&legal);
+            assert!(!choices.is_empty(), "no legal action for {}", actor);
+            let pick = choices[rng.random_range(0..choices.len())].clone();
+            eng.apply_action(actor, pick.clone())
+                .unwrap_or_else(|e| panic!("{:?} rejected: {}", pick, e));
+        }
+        assert!(eng.legal_actions(0).is_empty());
+        assert!(eng.legal_actions(1).is_empty());
+    }
+}
@@ -0,0 +1,128 @@
+use axiomind_engine::errors::GameError;
+use axiomind_engine::game::Match;
+use axiomind_engine::player::{HANDS_PER_LEVEL, PlayerAction as A};
+
+/// Check or call down whoever is to act until the hand ends.
+fn check_down(m: &mut Match) {
+    while m.engine().hand_outcome().is_none() {
+        let pid = m.engine().current_player().expect("actor");
+        let action = match m.engine().to_call(pid) {
+            Some(0) | None => A::Check,
+            Some(_) => A::Call,
+        };
+        m.apply_action(pid, action).expect("check down");
+    }
+}
+
+#[test]
+fn button_alternates_every_hand() {
+    let mut m = Match::new(Some(1), 1);
+    for hand in 0..6 {
+        assert_eq!(m.button(), hand % 2);
+        m.start_hand().expect("deal");
+        let button = m.button();
+        m.apply_action(button, A::Fold).expect("fold");
+    }
+    assert_eq!(m.hands_played(), 6);
+}
+
+#[test]
+fn level_rises_every_hands_per_level() {
+    let mut m = Match::new(Some(2), 1);
+    for _ in 0..HANDS_PER_LEVEL {
+        assert_eq!(m.level(), 1);
+        m.start_hand().expect("deal");
+        assert_eq!(m.engine().blinds().unwrap(), (50, 100));
+        let button = m.button();
+        m.apply_action(button, A::Fold).expect("fold");
+    }
+    assert_eq!(m.level(), 2);
+    m.start_hand().expect("deal");
+    assert_eq!(m.engine().blinds().unwrap(), (75, 150));
+}
+
+#[test]
+fn custom_stacks_names_and_button() {
+    let m = Match::new(Some(3), 4)
+        .with_stacks([5_000, 12_000])
+        .with_names(["hero", "villain"])
+        .with_button(1)
+        .with_hands_per_level(2);
+    assert_eq!(m.stacks(), [5_000, 12_000]);
+    assert_eq!(m.name(0), "hero");
+    assert_eq!(m.name(1), "villain");
+    assert_eq!(m.button(), 1);
+    assert_eq!(m.level(), 4);
+}
+
+#[test]
+fn cannot_start_a_hand_while_one_is_running() {
+    let mut m = Match::new(Some(4), 1);
+    assert_eq!(
+        m.apply_action(0, A::Fold).unwrap_err(),
+        GameError::NoHandInProgress
+    );
+    m.start_hand().expect("deal");
+    assert_eq!(m.start_hand().unwrap_err(), GameError::HandInProgress);
+}
+
+#[test]
+fn match_ends_when_a_stack_is_empty() {
+    let mut m = Match::new(Some(5), 1).with_stacks([20_000, 100]);
+    while !m.is_over() {
+        m.start_hand().expect("deal");
+        let button = m.button();
+        if button == 1 {
+            // The short stack shoves from the button and gets called
+            m.apply_action(1, A::AllIn).expect("shove");
+        }
+        check_down(&mut m);
+        assert_eq!(m.stacks().iter().sum::<u32>(), 20_100);
+    }
+    let winner = m.winner().expect("winner");
+    assert_eq!(m.stacks()[winner], 20_100);
+    assert_eq!(m.start_hand().unwrap_err(), GameError::MatchOver);
+}
+
+#[test]
+fn short_stack_posts_blind_all_in() {
+    let mut m = Match::new(Some(6), 1)
+        .with_stacks([20_000, 30])
+        .with_button(0);
+    m.start_hand().expect("deal");
+    // Big blind posts its last 30 chips; with nobody left to bet against,
+    // the board runs out and the hand is settled on the deal
+    assert_eq!(m.engine().pot(), 80);
+    assert!(m.engine().is_hand_complete());
+    assert_eq!(m.engine().board().len(), 5);
+    assert_eq!(m.hands_played(), 1);
+
+    let outcome = m.engine().hand_outcome().expect("settled");
+    // The small blind's extra 20 was never matched and comes back
+    assert_eq!(outcome.uncalled_bet, Some((0, 20)));
+    assert_eq!(m.stacks().iter().sum::<u32>(), 20_030);
+}
+
+#[test]
+fn level_stops_rising_at_last_level() {
+    let mut m = Match::new(Some(7), 19).with_hands_per_level(1);
+    for _ in 0..3 {
+        m.start_hand().expect("deal");
+        let button = m.button();
+        m.apply_action(button, A::Fold).expect("fold");
+    }
+    assert_eq!(m.level(), 20);
+}
+
+#[test]
+fn every_hand_is_committed_to() {
+    let mut m = Match::new(Some(8), 1).with_commit_reveal().expect("commit");
+    let mut commitments = Vec::new();
+    for _ in 0..2 {
+        m.start_hand().expect("deal");
+        commitments.push(m.engine().shuffle_commitment().expect("commitment"));
+        let button = m.button();
+        m.apply_action(button, A::Fold).expect("fold");
+    }
+    assert_ne!(commitments[0], commitments[1]);
+}
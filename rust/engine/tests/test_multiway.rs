@@ -0,0 +1,275 @@
+use axiomind_engine::cards::{format_cards, parse_cards};
+use axiomind_engine::deck::{DealSpec, Deck};
+use axiomind_engine::engine::{EndReason, Engine};
+use axiomind_engine::errors::GameError;
+use axiomind_engine::logger::Street;
+use axiomind_engine::player::{PlayerAction as A, Position, STARTING_STACK};
+use axiomind_engine::variant::GameVariant;
+
+fn table(seats: usize, seed: u64) -> Engine {
+    let mut eng = Engine::new(Some(seed), 1).with_seats(seats).expect("seats");
+    eng.deal_hand().expect("deal ok");
+    eng
+}
+
+/// Reduce each seat's stack to `stacks[seat]` before the hand is dealt.
+fn set_stacks(eng: &mut Engine, stacks: &[u32]) {
+    for (player, &stack) in eng.players_mut().iter_mut().zip(stacks) {
+        let excess = player.stack() - stack;
+        player.bet(excess).expect("trim stack");
+    }
+}
+
+#[test]
+fn seat_count_is_validated() {
+    for seats in [0, 1, 10] {
+        assert_eq!(
+            Engine::new(None, 1).with_seats(seats).unwrap_err(),
+            GameError::InvalidSeatCount(seats)
+        );
+    }
+    for seats in 2..=9 {
+        let eng = Engine::new(None, 1).with_seats(seats).unwrap();
+        assert_eq!(eng.players().len(), seats);
+        assert!(eng.players().iter().all(|p| p.stack() == STARTING_STACK));
+    }
+}
+
+#[test]
+fn blinds_sit_left_of_the_button() {
+    let mut eng = Engine::new(Some(3), 1).with_seats(6).unwrap();
+    eng.set_button(4);
+    eng.deal_hand().unwrap();
+
+    let positions: Vec<Position> = eng.players().iter().map(|p| p.position()).collect();
+    assert_eq!(positions[4], Position::Button);
+    assert_eq!(positions[5], Position::SmallBlind);
+    assert_eq!(positions[0], Position::BigBlind);
+    assert_eq!(positions[1], Position::Other);
+    assert_eq!(eng.players()[5].stack(), STARTING_STACK - 50);
+    assert_eq!(eng.players()[0].stack(), STARTING_STACK - 100);
+    assert_eq!(eng.current_player().unwrap(), 1);
+    assert_eq!(eng.to_call(1), Some(100));
+    assert_eq!(eng.to_call(5), Some(50));
+
+    // Seat numbers wrap around the table
+    eng.set_button(13);
+    assert_eq!(eng.button(), 1);
+}
+
+#[test]
+fn action_goes_clockwise_and_the_big_blind_gets_its_option() {
+    let mut eng = table(4, 1);
+    // Button 0, small blind 1, big blind 2, seat 3 opens
+    let mut order = Vec::new();
+    for action in [A::Call, A::Call, A::Call] {
+        let seat = eng.current_player().unwrap();
+        order.push(seat);
+        eng.apply_action(seat, action).unwrap();
+    }
+    assert_eq!(order, vec![3, 0, 1]);
+    assert_eq!(eng.current_player().unwrap(), 2);
+    assert!(eng.legal_actions(2).check);
+    eng.apply_action(2, A::Check).unwrap();
+
+    // Postflop the small blind opens
+    assert_eq!(eng.current_street(), Some(Street::Flop));
+    assert_eq!(eng.current_player().unwrap(), 1);
+    assert_eq!(eng.pot(), 400);
+}
+
+#[test]
+fn a_raise_reopens_action_for_everyone_behind() {
+    let mut eng = table(3, 2);
+    // Button 0 opens three-handed
+    eng.apply_action(0, A::Call).unwrap();
+    eng.apply_action(1, A::Call).unwrap();
+    eng.apply_action(2, A::Raise(200)).unwrap();
+    assert_eq!(eng.current_player().unwrap(), 0);
+    eng.apply_action(0, A::Call).unwrap();
+    assert_eq!(eng.current_street(), Some(Street::Preflop));
+    eng.apply_action(1, A::Call).unwrap();
+    assert_eq!(eng.current_street(), Some(Street::Flop));
+    assert_eq!(eng.pot(), 900);
+}
+
+#[test]
+fn folded_seats_are_skipped_until_one_player_is_left() {
+    let mut eng = table(3, 4);
+    eng.apply_action(0, A::Fold).unwrap();
+    assert!(!eng.is_hand_complete());
+    eng.apply_action(1, A::Call).unwrap();
+    eng.apply_action(2, A::Check).unwrap();
+
+    // Flop: small blind, then big blind; the button is out
+    assert_eq!(eng.current_player().unwrap(), 1);
+    eng.apply_action(1, A::Bet(100)).unwrap();
+    assert_eq!(eng.current_player().unwrap(), 2);
+    assert_eq!(
+        eng.apply_action(0, A::Call).unwrap_err(),
+        GameError::NotPlayersTurn {
+            expected: 2,
+            actual: 0
+        }
+    );
+    eng.apply_action(2, A::Fold).unwrap();
+
+    assert!(eng.is_hand_complete());
+    assert!(!eng.reached_showdown());
+    assert_eq!(eng.folded_player(), Some(0));
+    let outcome = eng.hand_outcome().unwrap();
+    assert_eq!(outcome.end_reason, EndReason::Fold);
+    assert_eq!(outcome.winners, vec![1]);
+    assert_eq!(outcome.net, vec![0, 100, -100]);
+}
+
+#[test]
+fn three_way_all_in_builds_a_side_pot() {
+    // Deal order: one card per seat from seat 0, twice; then burn and flop
+    let order = parse_cards("AhKhQh AdKdQd 4c 2c7d9h 5c Ts 6c 3s").unwrap();
+    let mut eng = Engine::new(Some(1), 1)
+        .with_seats(3)
+        .unwrap()
+        .with_deck(Deck::from_order(order).unwrap())
+        .unwrap();
+    set_stacks(&mut eng, &[1_000, 3_000, 5_000]);
+    eng.deal_hand().unwrap();
+
+    eng.apply_action(0, A::AllIn).unwrap();
+    eng.apply_action(1, A::AllIn).unwrap();
+    // Seat 2 is the only one with chips left, so the call runs out the board
+    eng.apply_action(2, A::Call).unwrap();
+    assert!(eng.is_hand_complete());
+    assert_eq!(format_cards(eng.board()), "2c7d9hTs3s");
+
+    let outcome = eng.hand_outcome().unwrap();
+    assert_eq!(outcome.end_reason, EndReason::Showdown);
+    // Aces win the main pot, kings the side pot the queens paid into
+    assert_eq!(outcome.pots.len(), 2);
+    assert_eq!(outcome.pots[0].amount, 3_000);
+    assert_eq!(outcome.pots[0].winners, vec![0]);
+    assert_eq!(outcome.pots[1].amount, 4_000);
+    assert_eq!(outcome.pots[1].winners, vec![1]);
+    assert_eq!(outcome.net, vec![2_000, 1_000, -3_000]);
+    let stacks: Vec<u32> = eng.players().iter().map(|p| p.stack()).collect();
+    assert_eq!(stacks, vec![3_000, 4_000, 2_000]);
+}
+
+#[test]
+fn antes_come_from_every_seat_or_only_the_big_blind() {
+    use axiomind_engine::rules::{BlindLevel, BlindStructure};
+    let structure = |big_blind_ante| BlindStructure {
+        hands_per_level: 10,
+        big_blind_ante,
+        levels: vec![BlindLevel {
+            sb: 50,
+            bb: 100,
+            ante: 10,
+        }],
+    };
+    let mut eng = Engine::new(Some(1), 1)
+        .with_seats(5)
+        .unwrap()
+        .with_blind_structure(structure(false));
+    eng.deal_hand().unwrap();
+    assert_eq!(eng.pot(), 150 + 5 * 10);
+
+    let mut eng = Engine::new(Some(1), 1)
+        .with_seats(5)
+        .unwrap()
+        .with_blind_structure(structure(true));
+    eng.deal_hand().unwrap();
+    assert_eq!(eng.pot(), 160);
+    assert_eq!(eng.players()[2].stack(), STARTING_STACK - 110);
+}
+
+#[test]
+fn multiway_hands_survive_a_snapshot() {
+    let mut eng = table(6, 9);
+    eng.apply_action(3, A::Call).unwrap();
+    eng.apply_action(4, A::Fold).unwrap();
+    let mut restored = Engine::restore(eng.snapshot()).unwrap();
+    for eng in [&mut eng, &mut restored] {
+        eng.apply_action(5, A::Raise(300)).unwrap();
+        assert_eq!(eng.current_player().unwrap(), 0);
+    }
+    assert_eq!(restored.snapshot(), eng.snapshot());
+    let obs = restored.observation(0).unwrap();
+    assert_eq!(obs.stacks.len(), 6);
+    assert_eq!(obs.to_call, 400);
+}
+
+#[test]
+fn busted_seats_sit_the_hand_out() {
+    let mut eng = Engine::new(Some(6), 1).with_seats(4).unwrap();
+    set_stacks(
+        &mut eng,
+        &[STARTING_STACK, 0, STARTING_STACK, STARTING_STACK],
+    );
+    eng.deal_hand().unwrap();
+
+    // Seat 1 is passed over for the blinds and gets no cards
+    let positions: Vec<Position> = eng.players().iter().map(|p| p.position()).collect();
+    assert_eq!(
+        positions,
+        [
+            Position::Button,
+            Position::Other,
+            Position::SmallBlind,
+            Position::BigBlind
+        ]
+    );
+    assert!(eng.players()[1].hole().is_empty());
+    assert_eq!(eng.players()[2].stack(), STARTING_STACK - 50);
+    assert_eq!(eng.players()[3].stack(), STARTING_STACK - 100);
+    assert_eq!(eng.current_player().unwrap(), 0);
+
+    // Nor does it act after the flop
+    eng.apply_action(0, A::Call).unwrap();
+    eng.apply_action(2, A::Call).unwrap();
+    eng.apply_action(3, A::Check).unwrap();
+    assert_eq!(eng.current_street(), Some(Street::Flop));
+    assert_eq!(eng.current_player().unwrap(), 2);
+    let record = eng.hand_record("19700101-000001", Some(6));
+    assert!(record.players[1].hole_cards.is_none());
+
+    // A button on a busted seat moves on, and two seats left play heads-up
+    let mut eng = Engine::new(Some(6), 1).with_seats(3).unwrap();
+    set_stacks(&mut eng, &[STARTING_STACK, 0, STARTING_STACK]);
+    eng.set_button(1);
+    eng.deal_hand().unwrap();
+    assert_eq!(eng.button(), 2);
+    assert_eq!(eng.players()[2].position(), Position::Button);
+    assert_eq!(eng.players()[2].stack(), STARTING_STACK - 50);
+    assert_eq!(eng.players()[0].position(), Position::BigBlind);
+    assert_eq!(eng.current_player().unwrap(), 2);
+
+    // It takes two seats with chips to deal
+    let mut eng = Engine::new(Some(6), 1).with_seats(3).unwrap();
+    set_stacks(&mut eng, &[STARTING_STACK, 0, 0]);
+    assert!(eng.deal_hand().is_err());
+}
+
+#[test]
+fn odd_chips_follow_the_button_of_the_hand() {
+    // Seats 0 and 2 split with straights; the short small blind loses
+    let spec: DealSpec = "Tc2h 3c4c Td2s | AsKsQdJh8c".parse().unwrap();
+    let mut eng = Engine::new(Some(5), 1)
+        .with_seats(3)
+        .unwrap()
+        .with_deck(spec.deck(5, 3, GameVariant::Holdem).unwrap())
+        .unwrap();
+    set_stacks(&mut eng, &[STARTING_STACK, 25, STARTING_STACK]);
+    eng.deal_hand().unwrap();
+    // Moving the button for the next hand must not change this one's payout
+    eng.set_button(2);
+    eng.apply_action(0, A::Call).unwrap();
+    eng.apply_action(2, A::Check).unwrap();
+    while let Ok(seat) = eng.current_player() {
+        eng.apply_action(seat, A::Check).unwrap();
+    }
+
+    // The 75-chip main pot splits 38/37, the odd chip to the seat left of seat 0
+    let outcome = eng.hand_outcome().expect("settled");
+    assert_eq!(outcome.payouts, [112, 0, 113]);
+}
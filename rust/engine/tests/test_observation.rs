@@ -0,0 +1,64 @@
+use axiomind_engine::engine::Engine;
+use axiomind_engine::logger::Street;
+use axiomind_engine::player::PlayerAction as A;
+
+#[test]
+fn observation_shows_only_own_cards_and_visible_board() {
+    let mut eng = Engine::new(Some(9), 1);
+    eng.deal_hand().expect("deal");
+
+    for seat in 0..2 {
+        let obs = eng.observation(seat).unwrap();
+        assert_eq!(obs.hole, eng.players()[seat].hole());
+        assert!(obs.board.is_empty());
+        assert_eq!(obs.street, Some(Street::Preflop));
+    }
+
+    eng.apply_action(0, A::Call).expect("call");
+    eng.apply_action(1, A::Check).expect("check");
+
+    let obs = eng.observation(1).unwrap();
+    assert_eq!(obs.board.len(), 3);
+    assert_eq!(&obs.board, eng.board());
+    assert_eq!(obs.street, Some(Street::Flop));
+    assert_eq!(obs.action_history, eng.action_history());
+    assert_eq!(obs.pot, 200);
+    assert_eq!(obs.stacks, [19_900, 19_900]);
+    assert_eq!(obs.stack(), 19_900);
+}
+
+#[test]
+fn observation_tracks_turn_and_button() {
+    let mut eng = Engine::new(Some(3), 2);
+    eng.set_button(1);
+    eng.deal_hand().expect("deal");
+
+    let actor = eng.observation(1).unwrap();
+    assert_eq!(actor.button, 1);
+    assert_eq!(actor.level, 2);
+    assert_eq!(actor.to_call, 75);
+    assert_eq!(actor.legal_actions, eng.legal_actions(1));
+    assert!(!actor.legal_actions.is_empty());
+
+    // The big blind waits and has nothing to choose from yet
+    let waiting = eng.observation(0).unwrap();
+    assert_eq!(waiting.to_call, 0);
+    assert!(waiting.legal_actions.is_empty());
+}
+
+#[test]
+fn observation_without_a_hand() {
+    let eng = Engine::new(Some(1), 1);
+    let obs = eng.observation(0).unwrap();
+    assert!(obs.hole.is_empty());
+    assert_eq!(obs.street, None);
+    assert_eq!(obs.pot, 0);
+    assert!(obs.legal_actions.is_empty());
+}
+
+#[test]
+fn observation_of_a_missing_seat_is_none() {
+    let mut eng = Engine::new(Some(1), 1);
+    eng.deal_hand().expect("deal");
+    assert!(eng.observation(2).is_none());
+}
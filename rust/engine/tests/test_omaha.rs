@@ -0,0 +1,219 @@
+use axiomind_engine::cards::{Card, format_cards, parse_cards};
+use axiomind_engine::deck::Deck;
+use axiomind_engine::engine::{EndReason, Engine};
+use axiomind_engine::errors::GameError;
+use axiomind_engine::events::{EngineEvent, EventQueue};
+use axiomind_engine::hand::evaluate_omaha;
+use axiomind_engine::player::{Player, PlayerAction as A, Position};
+use axiomind_engine::rules::BettingStructure;
+use axiomind_engine::variant::GameVariant;
+use std::collections::HashSet;
+
+fn omaha(hole: &str, board: &str) -> String {
+    let best = evaluate_omaha(&parse_cards(hole).unwrap(), &parse_cards(board).unwrap()).unwrap();
+    format!("{} [{}]", best.describe(), format_cards(&best.cards))
+}
+
+fn plo(seed: u64) -> Engine {
+    Engine::new(Some(seed), 1)
+        .with_variant(GameVariant::Omaha)
+        .unwrap()
+        .with_betting_structure(BettingStructure::PotLimit)
+}
+
+#[test]
+fn omaha_bets_pot_limit_unless_a_structure_was_chosen() {
+    use axiomind_engine::game::Match;
+
+    let eng = Engine::new(Some(1), 1)
+        .with_variant(GameVariant::Omaha)
+        .unwrap();
+    assert_eq!(eng.betting_structure(), BettingStructure::PotLimit);
+    let game = Match::new(Some(1), 1).with_variant(GameVariant::Omaha);
+    assert_eq!(
+        game.engine().betting_structure(),
+        BettingStructure::PotLimit
+    );
+
+    // A chosen structure stays, whichever builder comes first
+    let before = Engine::new(Some(1), 1)
+        .with_betting_structure(BettingStructure::NoLimit)
+        .with_variant(GameVariant::Omaha)
+        .unwrap();
+    assert_eq!(before.betting_structure(), BettingStructure::NoLimit);
+    let after = Match::new(Some(1), 1)
+        .with_variant(GameVariant::Omaha)
+        .with_betting_structure(BettingStructure::NoLimit);
+    assert_eq!(
+        after.engine().betting_structure(),
+        BettingStructure::NoLimit
+    );
+
+    // Back to Hold'em, the default follows the variant again
+    let holdem = Engine::new(Some(1), 1)
+        .with_variant(GameVariant::Omaha)
+        .unwrap()
+        .with_variant(GameVariant::Holdem)
+        .unwrap();
+    assert_eq!(holdem.betting_structure(), BettingStructure::NoLimit);
+}
+
+#[test]
+fn omaha_hands_use_exactly_two_hole_cards() {
+    // Four to a flush on the board plays only with two hearts in hand
+    assert_eq!(
+        omaha("Ah4c4d8s", "2h5h9hJhQc"),
+        "One Pair, Fours [4d4cQcJh9h]"
+    );
+    assert_eq!(
+        omaha("AhKh4d8s", "2h5h9hJhQc"),
+        "Flush, Ace-high [AhKhJh9h5h]"
+    );
+    // A straight on the board needs two hole cards to join it
+    assert_eq!(
+        omaha("AsAd2c2h", "9h8d7c6sTc"),
+        "One Pair, Aces [AsAdTc9h8d]"
+    );
+    // Quads on the board play as trips at most
+    assert_eq!(
+        omaha("AsKd3c2h", "9h9d9c9s5c"),
+        "Three of a Kind, Nines [9h9d9cAsKd]"
+    );
+    // All four cards suited still only contribute two
+    assert_eq!(omaha("AsKsQsJs", "Ts2h3d"), "High Card, Ace [AsKsTs3d2h]");
+
+    let hole = parse_cards("AsKsQsJs").unwrap();
+    assert_eq!(
+        evaluate_omaha(&hole, &parse_cards("2h3d").unwrap()).unwrap_err(),
+        GameError::InvalidOmahaHand { hole: 4, board: 2 }
+    );
+    assert_eq!(
+        evaluate_omaha(&hole, &parse_cards("As2h3d").unwrap()).unwrap_err(),
+        GameError::DuplicateCard(hole[0])
+    );
+}
+
+#[test]
+fn variants_parse_and_default_to_holdem() {
+    assert_eq!(Engine::new(None, 1).variant(), GameVariant::Holdem);
+    for (input, expected) in [
+        ("holdem", GameVariant::Holdem),
+        ("Hold'em", GameVariant::Holdem),
+        ("omaha", GameVariant::Omaha),
+        ("PLO", GameVariant::Omaha),
+    ] {
+        assert_eq!(input.parse::<GameVariant>().unwrap(), expected, "{input}");
+    }
+    assert!(matches!(
+        "stud".parse::<GameVariant>(),
+        Err(GameError::InvalidVariant(_))
+    ));
+    assert_eq!(GameVariant::from_hole_cards(4), Some(GameVariant::Omaha));
+    assert_eq!(GameVariant::from_hole_cards(3), None);
+    assert_eq!(
+        serde_json::to_value(GameVariant::Omaha).unwrap(),
+        serde_json::json!("omaha")
+    );
+}
+
+#[test]
+fn omaha_deals_four_cards_to_every_seat() {
+    let mut eng = Engine::new(Some(8), 1)
+        .with_seats(9)
+        .unwrap()
+        .with_variant(GameVariant::Omaha)
+        .unwrap();
+    let events = EventQueue::new();
+    eng.add_observer(events.clone());
+    eng.deal_hand().unwrap();
+
+    let mut seen: HashSet<Card> = HashSet::new();
+    for player in eng.players() {
+        assert_eq!(player.hole().len(), 4);
+        seen.extend(player.hole());
+    }
+    assert_eq!(seen.len(), 36);
+    assert_eq!(eng.deck_remaining(), 52 - 36);
+
+    let dealt: Vec<usize> = events
+        .drain()
+        .iter()
+        .filter_map(|e| match e {
+            EngineEvent::HoleCardsDealt { cards, .. } => Some(cards.len()),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(dealt, vec![4; 9]);
+    let obs = eng.observation(3).unwrap();
+    assert_eq!(obs.hole, eng.players()[3].hole());
+    assert_eq!(obs.variant, GameVariant::Omaha);
+}
+
+#[test]
+fn omaha_showdown_is_won_without_a_one_card_flush() {
+    // One card to each seat per round, four rounds; then burn and flop,
+    // burn and turn, burn and river
+    let order = parse_cards("Ah Kc 4c Kd 4d 7s 8s 6s 2c 2h5h9h Ts Jh Td Qc").unwrap();
+    let mut eng = plo(1).with_deck(Deck::from_order(order).unwrap()).unwrap();
+    eng.deal_hand().unwrap();
+    assert_eq!(format_cards(eng.players()[0].hole()), "Ah4c4d8s");
+    assert_eq!(format_cards(eng.players()[1].hole()), "KcKd7s6s");
+
+    eng.apply_action(0, A::Call).unwrap();
+    eng.apply_action(1, A::Check).unwrap();
+    eng.run_out_board().unwrap();
+    assert_eq!(format_cards(eng.board()), "2h5h9hJhQc");
+
+    // The ace of hearts would make a Hold'em flush; in Omaha kings win
+    let outcome = eng.hand_outcome().unwrap();
+    assert_eq!(outcome.end_reason, EndReason::Showdown);
+    assert_eq!(outcome.winners, vec![1]);
+
+    let record = eng.hand_record("19700101-000001", Some(1));
+    assert_eq!(record.players[0].hole_cards.as_ref().unwrap().len(), 4);
+    assert_eq!(record.meta.as_ref().unwrap()["variant"], "omaha");
+    let showdown = record.showdown.unwrap();
+    assert_eq!(
+        showdown.notes.as_deref(),
+        Some("One Pair, Kings beats One Pair, Fours")
+    );
+    assert_eq!(showdown.hands[0].cards.len(), 4);
+}
+
+#[test]
+fn omaha_survives_snapshots_and_old_snapshots_still_load() {
+    let mut eng = plo(4);
+    eng.deal_hand().unwrap();
+    let restored = Engine::restore(eng.snapshot()).unwrap();
+    assert_eq!(restored.variant(), GameVariant::Omaha);
+    assert_eq!(restored.players()[1].hole(), eng.players()[1].hole());
+
+    // Older snapshots have no variant and two hole card slots that may be empty
+    let mut json = serde_json::to_value(Engine::new(Some(4), 1).snapshot()).unwrap();
+    json.as_object_mut().unwrap().remove("variant");
+    json["players"][0]["hole"] = serde_json::json!([null, null]);
+    let restored = Engine::restore(serde_json::from_value(json).unwrap()).unwrap();
+    assert_eq!(restored.variant(), GameVariant::Holdem);
+    assert!(restored.players()[0].hole().is_empty());
+
+    // A Hold'em table cannot hold four-card hands
+    let mut json = serde_json::to_value(eng.snapshot()).unwrap();
+    json["variant"] = serde_json::json!("holdem");
+    assert!(matches!(
+        Engine::restore(serde_json::from_value(json).unwrap()),
+        Err(GameError::InvalidSnapshot(_))
+    ));
+}
+
+#[test]
+fn players_hold_at_most_four_hole_cards() {
+    let cards = parse_cards("AhKhQhJhTh").unwrap();
+    let mut player = Player::new(0, 100, Position::Button);
+    for &card in &cards[..4] {
+        player.give_card_for(GameVariant::Omaha, card).unwrap();
+    }
+    assert!(player.give_card_for(GameVariant::Omaha, cards[4]).is_err());
+    assert_eq!(player.hole(), &cards[..4]);
+    // The first two are still available the Hold'em way
+    assert_eq!(player.hole_cards(), [Some(cards[0]), Some(cards[1])]);
+}
@@ -1,6 +1,7 @@
-use axiomind_engine::cards::{Card, Rank, Suit};
+use axiomind_engine::cards::{Card, Rank, Suit, parse_cards};
 use axiomind_engine::game::GameState;
 use axiomind_engine::player::{Player, PlayerAction, Position, STARTING_STACK};
+use axiomind_engine::variant::GameVariant;
 
 #[test]
 fn players_start_with_20000_and_positions() {
@@ -30,6 +31,17 @@ fn player_receives_two_hole_cards() {
     assert_eq!(hc[1], Some(k));
 }
 
+#[test]
+fn holdem_player_refuses_a_third_hole_card() {
+    let mut p = Player::new(0, STARTING_STACK, Position::Button);
+    let cards = parse_cards("AsKsQs").unwrap();
+    p.give_card(cards[0]).unwrap();
+    p.give_card(cards[1]).unwrap();
+    assert!(p.give_card(cards[2]).is_err());
+    assert!(p.give_card_for(GameVariant::Holdem, cards[2]).is_err());
+    assert_eq!(p.hole(), &cards[..2]);
+}
+
 #[test]
 fn betting_reduces_stack_and_cannot_overbet() {
     let mut p = Player::new(0, STARTING_STACK, Position::Button);
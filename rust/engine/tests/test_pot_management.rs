@@ -1,4 +1,4 @@
-use axiomind_engine::pot::PotManager;
+use axiomind_engine::pot::{PotManager, split_pot};
 
 #[test]
 fn heads_up_simple_side_pot() {
@@ -13,3 +13,29 @@ fn equal_stacks_no_side_pot() {
     assert_eq!(pm.main_pot(), 2000);
     assert!(pm.side_pots().is_empty());
 }
+
+#[test]
+fn pot_layers_track_eligibility() {
+    let pm = PotManager::from_contributions([500, 1000]);
+    let pots = pm.pots();
+    assert_eq!(pots.len(), 2);
+    assert_eq!(pots[0].amount, 1000);
+    assert_eq!(pots[0].eligible, vec![0, 1]);
+    assert_eq!(pots[1].amount, 500);
+    assert_eq!(pots[1].eligible, vec![1]);
+}
+
+#[test]
+fn multiway_layers_cover_every_contribution_level() {
+    let pm = PotManager::from_contributions(vec![300, 1_000, 1_000, 600]);
+    assert_eq!(pm.main_pot(), 1_200);
+    assert_eq!(pm.side_pots(), &[900, 800]);
+    assert_eq!(pm.pots()[1].eligible, vec![1, 2, 3]);
+    assert_eq!(pm.pots()[2].eligible, vec![1, 2]);
+}
+
+#[test]
+fn split_pot_gives_odd_chip_to_first_winner() {
+    assert_eq!(split_pot(1001, &[1, 0]), vec![(1, 501), (0, 500)]);
+    assert_eq!(split_pot(1000, &[0]), vec![(0, 1000)]);
+}
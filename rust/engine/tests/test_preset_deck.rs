@@ -0,0 +1,161 @@
+use axiomind_engine::cards::{Card, format_cards, full_deck, parse_cards};
+use axiomind_engine::deck::{DealSpec, Deck};
+use axiomind_engine::engine::Engine;
+use axiomind_engine::errors::GameError;
+use axiomind_engine::variant::GameVariant;
+use std::collections::HashSet;
+
+fn dealt(spec: &str, seed: u64) -> Engine {
+    let spec: DealSpec = spec.parse().expect("spec");
+    let mut eng = Engine::new(Some(seed), 1)
+        .with_deck(spec.deck(seed, 2, GameVariant::Holdem).expect("deck"))
+        .unwrap();
+    eng.deal_hand().expect("deal ok");
+    eng.run_out_board().expect("run out");
+    eng
+}
+
+fn hole(eng: &Engine, seat: usize) -> String {
+    let cards: Vec<Card> = eng.players()[seat]
+        .hole_cards()
+        .iter()
+        .flatten()
+        .copied()
+        .collect();
+    format_cards(&cards)
+}
+
+#[test]
+fn from_order_deals_the_listed_cards_then_the_rest() {
+    let mut deck = Deck::from_order(parse_cards("2c3c4c").unwrap()).unwrap();
+    for _ in 0..2 {
+        deck.shuffle();
+        let dealt: Vec<Card> = std::iter::from_fn(|| deck.deal_card()).collect();
+        assert_eq!(format_cards(&dealt[..3]), "2c3c4c");
+        assert_eq!(dealt.len(), 52);
+        assert_eq!(dealt.iter().collect::<HashSet<_>>().len(), 52);
+    }
+    assert_eq!(
+        Deck::from_order(parse_cards("2c3c").unwrap().repeat(2)).unwrap_err(),
+        GameError::DuplicateCard(parse_cards("2c").unwrap()[0])
+    );
+}
+
+#[test]
+fn engine_deals_a_full_preset_order() {
+    let order = parse_cards("AhKh QsQd 2c JhTh9h 3c 4d 5c 8s").unwrap();
+    let mut eng = Engine::new(None, 1)
+        .with_deck(Deck::from_order(order).unwrap())
+        .unwrap();
+    eng.deal_hand().unwrap();
+    eng.run_out_board().unwrap();
+    assert_eq!(hole(&eng, 0), "AhQs");
+    assert_eq!(hole(&eng, 1), "KhQd");
+    assert_eq!(format_cards(eng.board()), "JhTh9h4d8s");
+}
+
+#[test]
+fn spec_pins_cards_and_fills_the_rest_from_the_seed() {
+    let a = dealt("7h7d KcKs | Kd7c2s", 5);
+    let b = dealt("7h7d KcKs | Kd7c2s", 5);
+    let c = dealt("7h7d KcKs | Kd7c2s", 6);
+    for eng in [&a, &b, &c] {
+        assert_eq!(hole(eng, 0), "7h7d");
+        assert_eq!(hole(eng, 1), "KcKs");
+        assert_eq!(format_cards(&eng.board()[..3]), "Kd7c2s");
+    }
+    assert_eq!(a.board(), b.board());
+    // Seeds differ, so the open turn and river almost surely do too
+    assert_ne!(a.board()[3..], c.board()[3..]);
+}
+
+#[test]
+fn open_slots_and_unlisted_seats_are_random() {
+    let eng = dealt("Ah?? | ??????8s", 9);
+    assert!(hole(&eng, 0).starts_with("Ah"));
+    assert_eq!(eng.board()[3].to_string(), "8s");
+    let mut seen: Vec<Card> = eng.board().clone();
+    for seat in 0..2 {
+        seen.extend(eng.players()[seat].hole_cards().iter().flatten());
+    }
+    assert_eq!(seen.iter().collect::<HashSet<_>>().len(), 9);
+}
+
+#[test]
+fn pinned_cards_persist_across_hands_and_snapshots() {
+    let spec: DealSpec = "AsAc".parse().unwrap();
+    let mut eng = Engine::new(Some(1), 1)
+        .with_deck(spec.deck(1, 2, GameVariant::Holdem).unwrap())
+        .unwrap();
+    eng.deal_hand().unwrap();
+    let restored = Engine::restore(eng.snapshot()).unwrap();
+    let json = serde_json::to_string(&eng.snapshot()).unwrap();
+    assert!(json.contains("pinned"));
+
+    for mut e in [eng, restored] {
+        e.run_out_board().unwrap();
+        e.deal_hand().unwrap();
+        assert_eq!(hole(&e, 0), "AsAc");
+    }
+}
+
+#[test]
+fn spec_positions_follow_the_deal_order() {
+    let spec: DealSpec = "AhKh QsQd | JhTh9h 4d 8s".parse().unwrap();
+    let slots = spec.positions(2, 2).unwrap();
+    let shown: Vec<String> = slots
+        .iter()
+        .map(|s| s.map_or("--".to_string(), |c| c.to_string()))
+        .collect();
+    assert_eq!(shown.join(" "), "Ah Qs Kh Qd -- Jh Th 9h -- 4d -- 8s");
+    assert_eq!(full_deck().len() - slots.iter().flatten().count(), 43);
+}
+
+#[test]
+fn rejects_malformed_specs() {
+    for spec in ["AhA", "Ah | Kd", "AhKd | 2c3c4c5c6c7c", "AhKd | Ah"] {
+        assert!(
+            matches!(
+                spec.parse::<DealSpec>(),
+                Err(GameError::InvalidDeck(_)) | Err(GameError::DuplicateCard(_))
+            ),
+            "{spec}"
+        );
+    }
+    // Three seats parse, but a heads-up deck has room for two
+    let three: DealSpec = "AhAd KcKs QcQs".parse().unwrap();
+    assert!(matches!(
+        three.deck(1, 2, GameVariant::Holdem),
+        Err(GameError::InvalidDeck(_))
+    ));
+}
+
+#[test]
+fn specs_fit_any_table_and_hole_card_count() {
+    // Three seats of Omaha, the last one left to chance
+    let spec: DealSpec = "AhAdKhKd QcQsJcJs | 2c3c4c".parse().unwrap();
+    let mut eng = Engine::new(Some(4), 1)
+        .with_seats(3)
+        .unwrap()
+        .with_variant(GameVariant::Omaha)
+        .unwrap()
+        .with_deck(spec.deck(4, 3, GameVariant::Omaha).unwrap())
+        .unwrap();
+    eng.deal_hand().unwrap();
+    eng.run_out_board().unwrap();
+    assert_eq!(format_cards(eng.players()[0].hole()), "AhAdKhKd");
+    assert_eq!(format_cards(eng.players()[1].hole()), "QcQsJcJs");
+    assert_eq!(eng.players()[2].hole().len(), 4);
+    assert_eq!(format_cards(&eng.board()[..3]), "2c3c4c");
+
+    // Four cards do not fit a Hold'em seat, nor a Two a short deck
+    assert!(matches!(
+        spec.deck(4, 3, GameVariant::Holdem),
+        Err(GameError::InvalidDeck(_))
+    ));
+    let low: DealSpec = "Ah2d".parse().unwrap();
+    assert_eq!(
+        low.deck(4, 2, GameVariant::ShortDeck).unwrap_err(),
+        GameError::NotInShortDeck(parse_cards("2d").unwrap()[0])
+    );
+}
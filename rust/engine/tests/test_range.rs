@@ -0,0 +1,138 @@
+use axiomind_engine::cards::{Rank, parse_cards};
+use axiomind_engine::errors::RangeParseError;
+use axiomind_engine::range::{COMBOS, ClassKind, Combo, HandClass, Range};
+use std::collections::HashSet;
+
+fn range(s: &str) -> Range {
+    s.parse().expect("range")
+}
+
+#[test]
+fn combo_indices_cover_every_hand_once() {
+    let combos: Vec<Combo> = Combo::all().collect();
+    assert_eq!(combos.len(), COMBOS);
+    let unique: HashSet<Combo> = combos.iter().copied().collect();
+    assert_eq!(unique.len(), COMBOS);
+    for (i, combo) in combos.iter().enumerate() {
+        assert_eq!(combo.index(), i);
+    }
+    assert_eq!(Combo::from_index(COMBOS), None);
+}
+
+#[test]
+fn classes_partition_the_combos() {
+    let classes = HandClass::all();
+    assert_eq!(classes.len(), 169);
+    let total: usize = classes.iter().map(|c| c.combos().len()).sum();
+    assert_eq!(total, COMBOS);
+    for class in &classes {
+        for combo in class.combos() {
+            assert_eq!(combo.class(), *class);
+        }
+    }
+}
+
+#[test]
+fn grid_places_suited_above_the_diagonal() {
+    let aks: HandClass = "AKs".parse().unwrap();
+    let ako: HandClass = "KAo".parse().unwrap();
+    assert_eq!(aks.grid_position(), (0, 1));
+    assert_eq!(ako.grid_position(), (1, 0));
+    assert_eq!(ako.high(), Rank::Ace);
+    assert_eq!("22".parse::<HandClass>().unwrap().grid_position(), (12, 12));
+    assert_eq!(HandClass::all()[14].kind(), ClassKind::Pair);
+}
+
+#[test]
+fn expands_plus_and_span_notation() {
+    assert_eq!(range("22+").len(), 78);
+    assert_eq!(range("A2s+").len(), 48);
+    assert_eq!(range("KTo+").len(), 36);
+    assert_eq!(range("AK").len(), 16);
+    assert_eq!(range("99-66").len(), 24);
+    assert_eq!(range("66-99"), range("99-66"));
+    assert_eq!(range("A5s-A2s").len(), 16);
+    assert_eq!(range("AhKh, AsKs").len(), 2);
+    assert_eq!(range("T9+"), range("T9s, T9o"));
+    assert!(range("").is_empty());
+}
+
+#[test]
+fn formats_in_canonical_notation() {
+    for (input, expected) in [
+        ("AA, KK, QQ, JJ", "JJ+"),
+        ("76s, 22+, KTo+, A2s+", "22+, A2s+, 76s, KTo+"),
+        ("99-66, A5s-A2s", "99-66, A5s-A2s"),
+        ("AK", "AKs, AKo"),
+        ("QQ+:0.5, AKs", "QQ+:0.5, AKs"),
+        ("AsKs", "AsKs"),
+    ] {
+        assert_eq!(range(input).to_string(), expected, "{input}");
+    }
+}
+
+#[test]
+fn notation_round_trips_with_weights_and_partial_classes() {
+    let mut r = range("TT+, AQs+:0.75, KQo:0.25, 65s, AhJh");
+    r.remove_blocked(&parse_cards("Kd7c").unwrap());
+    let again = range(&r.to_string());
+    assert_eq!(again, r);
+    assert!((r.total_weight() - (27.0 + 7.0 * 0.75 + 9.0 * 0.25 + 4.0 + 1.0)).abs() < 1e-9);
+}
+
+#[test]
+fn removes_blocked_combos() {
+    let mut r = range("AA, AKs");
+    r.remove_blocked(&parse_cards("Ah2c").unwrap());
+    assert_eq!(r.len(), 3 + 3);
+    assert!(!r.contains("AhAs".parse().unwrap()));
+    assert!(r.contains("AsKs".parse().unwrap()));
+}
+
+#[test]
+fn set_operations_combine_weights() {
+    let a = range("QQ+, AKs:0.5");
+    let b = range("KK+:0.5, AKs, AQs");
+    assert_eq!(a.union(&b).to_string(), "QQ+, AQs+");
+    assert_eq!(a.intersection(&b).to_string(), "KK+:0.5, AKs:0.5");
+    assert_eq!(a.subtract(&b).to_string(), "QQ");
+    assert_eq!(Range::full().subtract(&Range::full()), Range::new());
+}
+
+#[test]
+fn grid_reports_class_weights() {
+    let r = range("AA, AKs:0.5, AhKd");
+    let grid = r.grid();
+    assert_eq!(grid[0][0], 1.0);
+    assert_eq!(grid[0][1], 0.5);
+    assert_eq!(grid[1][0], 1.0 / 12.0);
+    assert_eq!(grid[12][12], 0.0);
+
+    let text = r.render_grid();
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(lines.len(), 13);
+    assert!(lines[0].starts_with("AA  50% "));
+    assert!(lines[1].starts_with("8%  ."));
+}
+
+#[test]
+fn rejects_malformed_notation() {
+    for (input, expected) in [
+        ("AAs", RangeParseError::InvalidToken("AAs".into())),
+        ("AX", RangeParseError::InvalidToken("AX".into())),
+        ("AhAh", RangeParseError::InvalidToken("AhAh".into())),
+        ("QQ-AKs", RangeParseError::InvalidSpan("QQ-AKs".into())),
+        ("A5s-K2s", RangeParseError::InvalidSpan("A5s-K2s".into())),
+        ("AA:1.5", RangeParseError::InvalidWeight("1.5".into())),
+    ] {
+        assert_eq!(input.parse::<Range>(), Err(expected), "{input}");
+    }
+}
+
+#[test]
+fn serializes_as_notation() {
+    let r = range("22+, AKs:0.5");
+    let json = serde_json::to_string(&r).unwrap();
+    assert_eq!(json, "\"22+, AKs:0.5\"");
+    assert_eq!(serde_json::from_str::<Range>(&json).unwrap(), r);
+}
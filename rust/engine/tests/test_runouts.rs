@@ -0,0 +1,203 @@
+use axiomind_engine::cards::Card;
+use axiomind_engine::engine::{EndReason, Engine, MAX_RUNOUTS};
+use axiomind_engine::errors::GameError;
+use axiomind_engine::events::{EngineEvent, EventQueue};
+use axiomind_engine::logger::{HandLogger, HandRecord};
+use axiomind_engine::player::PlayerAction as A;
+use axiomind_engine::rules::BettingStructure;
+use axiomind_engine::variant::GameVariant;
+use std::collections::HashSet;
+use std::fs;
+use std::path::PathBuf;
+
+fn tmp_path(name: &str) -> PathBuf {
+    let mut p = PathBuf::from("target");
+    p.push(format!("{}_{}.jsonl", name, std::process::id()));
+    p
+}
+
+/// Heads-up hand where the small blind shoves and the big blind calls.
+fn all_in_preflop(seed: u64, runouts: usize) -> Engine {
+    let mut eng = Engine::new(Some(seed), 1).with_runouts(runouts).unwrap();
+    eng.deal_hand().unwrap();
+    eng.apply_action(0, A::AllIn).unwrap();
+    eng.apply_action(1, A::Call).unwrap();
+    eng
+}
+
+#[test]
+fn runout_count_is_checked() {
+    assert_eq!(Engine::new(None, 1).runouts(), 1);
+    for bad in [0, MAX_RUNOUTS + 1] {
+        assert_eq!(
+            Engine::new(None, 1).with_runouts(bad).unwrap_err(),
+            GameError::InvalidRunouts(bad)
+        );
+    }
+    assert_eq!(
+        Engine::new(None, 1)
+            .with_runouts(MAX_RUNOUTS)
+            .unwrap()
+            .runouts(),
+        MAX_RUNOUTS
+    );
+}
+
+#[test]
+fn board_is_dealt_once_by_default() {
+    let eng = all_in_preflop(3, 1);
+    assert!(eng.runout_boards().is_empty());
+    let outcome = eng.hand_outcome().unwrap();
+    assert!(outcome.pots.iter().all(|pot| pot.runout == 0));
+    let record = eng.hand_record("19700101-000001", Some(3));
+    assert!(record.showdown.unwrap().runouts.is_empty());
+}
+
+#[test]
+fn running_it_twice_splits_the_pot_between_two_boards() {
+    let eng = all_in_preflop(3, 2);
+    assert!(eng.is_hand_complete());
+    let boards = eng.runout_boards();
+    assert_eq!(boards.len(), 2);
+    assert_eq!(&boards[0], eng.board());
+
+    // The first board is the one a single runout would have dealt
+    assert_eq!(eng.board(), all_in_preflop(3, 1).board());
+
+    // No card comes out twice
+    let mut seen: HashSet<Card> = HashSet::new();
+    for card in boards
+        .iter()
+        .flatten()
+        .chain(eng.players().iter().flat_map(|p| p.hole()))
+    {
+        assert!(seen.insert(*card), "{card} dealt twice");
+    }
+
+    let outcome = eng.hand_outcome().unwrap();
+    assert_eq!(outcome.end_reason, EndReason::Showdown);
+    let runouts: Vec<usize> = outcome.pots.iter().map(|pot| pot.runout).collect();
+    assert_eq!(runouts, vec![0, 1]);
+    assert_eq!(outcome.pots[0].amount, outcome.pots[1].amount);
+    let paid: u32 = outcome.payouts.iter().sum();
+    assert_eq!(paid, 40_000);
+    let stacks: u32 = eng.players().iter().map(|p| p.stack()).sum();
+    assert_eq!(stacks, 40_000);
+}
+
+#[test]
+fn same_seed_runs_the_same_boards() {
+    let first = all_in_preflop(9, 3);
+    let second = all_in_preflop(9, 3);
+    assert_eq!(first.runout_boards().len(), 3);
+    assert_eq!(first.runout_boards(), second.runout_boards());
+    assert_eq!(first.hand_outcome(), second.hand_outcome());
+}
+
+#[test]
+fn runouts_share_the_cards_already_dealt() {
+    let mut eng = Engine::new(Some(4), 1).with_runouts(2).unwrap();
+    let events = EventQueue::new();
+    eng.add_observer(events.clone());
+    eng.deal_hand().unwrap();
+    eng.apply_action(0, A::Call).unwrap();
+    eng.apply_action(1, A::Check).unwrap();
+    let flop = eng.board().clone();
+    events.drain();
+
+    eng.apply_action(1, A::AllIn).unwrap();
+    eng.apply_action(0, A::Call).unwrap();
+    let boards = eng.runout_boards();
+    assert_eq!(boards.len(), 2);
+    assert!(boards.iter().all(|board| board[..3] == flop[..]));
+    assert_ne!(boards[0][3..], boards[1][3..]);
+
+    let runs: Vec<(usize, Vec<Card>)> = events
+        .drain()
+        .into_iter()
+        .filter_map(|e| match e {
+            EngineEvent::RunoutDealt { run, board } => Some((run, board)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(runs, vec![(1, boards[1].clone())]);
+}
+
+#[test]
+fn a_short_deck_deals_fewer_runouts() {
+    // Nine Omaha hands leave 16 cards: enough for two boards with burns
+    let mut eng = Engine::new(Some(2), 1)
+        .with_seats(9)
+        .unwrap()
+        .with_betting_structure(BettingStructure::NoLimit)
+        .with_variant(GameVariant::Omaha)
+        .unwrap()
+        .with_runouts(MAX_RUNOUTS)
+        .unwrap();
+    eng.deal_hand().unwrap();
+    while let Ok(seat) = eng.current_player() {
+        eng.apply_action(seat, A::AllIn).unwrap();
+    }
+    assert!(eng.is_hand_complete());
+    assert_eq!(eng.runout_boards().len(), 2);
+    assert_eq!(eng.deck_remaining(), 0);
+}
+
+#[test]
+fn undo_takes_back_every_runout() {
+    let mut eng = all_in_preflop(5, 2);
+    let boards = eng.runout_boards().to_vec();
+
+    eng.undo_last_action().unwrap();
+    assert!(eng.runout_boards().is_empty());
+    assert!(eng.board().is_empty());
+
+    eng.apply_action(1, A::Call).unwrap();
+    assert_eq!(eng.runout_boards(), &boards[..]);
+}
+
+#[test]
+fn snapshots_keep_the_runout_count() {
+    let eng = all_in_preflop(6, 2);
+    let restored = Engine::restore(eng.snapshot()).unwrap();
+    assert_eq!(restored.runouts(), 2);
+    assert_eq!(restored.runout_boards(), eng.runout_boards());
+
+    // Older snapshots deal the board once
+    let mut json = serde_json::to_value(eng.snapshot()).unwrap();
+    json.as_object_mut().unwrap().remove("runouts");
+    let restored = Engine::restore(serde_json::from_value(json.clone()).unwrap()).unwrap();
+    assert_eq!(restored.runouts(), 1);
+
+    json["runouts"] = serde_json::json!(0);
+    assert!(matches!(
+        Engine::restore(serde_json::from_value(json).unwrap()),
+        Err(GameError::InvalidSnapshot(_))
+    ));
+}
+
+#[test]
+fn records_list_every_runout() {
+    let path = tmp_path("runout_hands");
+    let mut eng = Engine::new(Some(3), 1).with_runouts(2).unwrap();
+    eng.add_observer(HandLogger::create(&path).expect("create logger"));
+    eng.deal_hand().unwrap();
+    eng.apply_action(0, A::AllIn).unwrap();
+    eng.apply_action(1, A::Call).unwrap();
+
+    let record = eng.hand_record("19700101-000001", None);
+    let showdown = record.showdown.as_ref().unwrap();
+    assert_eq!(showdown.runouts, eng.runout_boards());
+    assert_eq!(record.board, showdown.runouts[0]);
+    let notes = showdown.notes.as_deref().unwrap();
+    assert!(notes.starts_with("Run 1: "), "{notes}");
+    assert!(notes.contains("; Run 2: "), "{notes}");
+
+    // The observing logger writes the same boards and notes
+    let content = fs::read_to_string(&path).expect("read log");
+    let logged: HandRecord = serde_json::from_str(content.lines().next().unwrap()).unwrap();
+    let logged = logged.showdown.unwrap();
+    assert_eq!(logged.runouts, showdown.runouts);
+    assert_eq!(logged.notes, showdown.notes);
+    fs::remove_file(&path).ok();
+}
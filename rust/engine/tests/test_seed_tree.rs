@@ -0,0 +1,186 @@
+use axiomind_engine::deck::ShuffleSource;
+use axiomind_engine::engine::{Engine, EngineSnapshot};
+use axiomind_engine::errors::GameError;
+use axiomind_engine::game::Match;
+use axiomind_engine::player::PlayerAction as A;
+use axiomind_engine::seed::{SEED_SCHEME, SeedDerivation, SeedPath, SeedTree};
+use std::collections::HashSet;
+
+/// Heads-up hand where the small blind shoves and the big blind calls.
+fn all_in(engine: &mut Engine) {
+    engine.deal_hand().unwrap();
+    engine.apply_action(0, A::AllIn).unwrap();
+    engine.apply_action(1, A::Call).unwrap();
+}
+
+#[test]
+fn seeds_are_distinct_across_the_tree() {
+    let tree = SeedTree::new(1);
+    let mut seen = HashSet::new();
+    for m in 0..4 {
+        assert!(seen.insert(tree.match_seed(m)));
+        for h in 0..64 {
+            let path = SeedPath::hand(h).in_match(m);
+            assert!(seen.insert(tree.hand_seed(path)));
+            assert!(seen.insert(tree.hand_seed(path.swapped())));
+            for run in 1..3 {
+                assert!(seen.insert(tree.runout_seed(path, run)));
+            }
+        }
+    }
+    // Neighbouring roots do not share hands either
+    let other = SeedTree::new(2);
+    assert!((0..64).all(|h| !seen.contains(&other.hand_seed(SeedPath::hand(h)))));
+}
+
+#[test]
+fn hand_seeds_are_well_mixed() {
+    // Consecutive hands differ in about half of their bits
+    let tree = SeedTree::new(0);
+    let flipped: u32 = (0..256)
+        .map(|h| {
+            (tree.hand_seed(SeedPath::hand(h)) ^ tree.hand_seed(SeedPath::hand(h + 1))).count_ones()
+        })
+        .sum();
+    let average = f64::from(flipped) / 256.0;
+    assert!((28.0..36.0).contains(&average), "{average}");
+}
+
+#[test]
+fn any_hand_of_a_match_can_be_dealt_alone() {
+    let mut game = Match::new(Some(9), 1);
+    let mut holes = Vec::new();
+    for _ in 0..5 {
+        game.start_hand().unwrap();
+        holes.push(game.engine().players()[0].hole().to_vec());
+        let seat = game.engine().current_player().unwrap();
+        game.apply_action(seat, A::Fold).unwrap();
+    }
+    let tree = SeedTree::new(9);
+    for (hand, hole) in holes.iter().enumerate() {
+        let path = SeedPath::hand(hand as u64);
+        let mut engine = Engine::new(None, 1).with_seed_path(tree, path).unwrap();
+        // The button does not change which cards come off the deck
+        engine.deal_hand().unwrap();
+        assert_eq!(engine.players()[0].hole(), hole.as_slice(), "hand {hand}");
+    }
+}
+
+#[test]
+fn derivation_is_recorded_and_rebuilds_the_seed() {
+    let tree = SeedTree::new(77);
+    let path = SeedPath::hand(3).in_match(2).swapped();
+    let mut engine = Engine::new(None, 1).with_seed_path(tree, path).unwrap();
+    engine.deal_hand().unwrap();
+    engine.apply_action(0, A::Fold).unwrap();
+    let record = engine.hand_record("19700101-000001", engine.hand_seed());
+
+    let meta = &record.meta.as_ref().unwrap()["seed_derivation"];
+    assert_eq!(meta["scheme"], SEED_SCHEME);
+    assert_eq!(meta["root"], 77);
+    assert_eq!(meta["match"], 2);
+    assert_eq!(meta["seat_swap"], true);
+    let derivation: SeedDerivation = serde_json::from_value(meta.clone()).unwrap();
+    assert_eq!(derivation.hand_seed().unwrap(), record.seed.unwrap());
+
+    let mut unknown = derivation;
+    unknown.scheme = "xorshift/v0".to_string();
+    assert!(matches!(
+        unknown.hand_seed(),
+        Err(GameError::InvalidSeedDerivation(_))
+    ));
+
+    // Unswapped paths leave the flag out
+    let json = serde_json::to_value(SeedDerivation::new(tree, SeedPath::hand(0))).unwrap();
+    assert!(json.get("seat_swap").is_none());
+}
+
+#[test]
+fn runouts_follow_their_own_seeds() {
+    let tree = SeedTree::new(5);
+    let deal = |path: SeedPath| {
+        let mut engine = Engine::new(None, 1)
+            .with_runouts(3)
+            .unwrap()
+            .with_seed_path(tree, path)
+            .unwrap();
+        all_in(&mut engine);
+        engine.runout_boards().to_vec()
+    };
+    let boards = deal(SeedPath::hand(0));
+    assert_eq!(boards.len(), 3);
+    assert_eq!(boards, deal(SeedPath::hand(0)));
+
+    // Boards never share a card
+    let cards: HashSet<_> = boards.iter().flatten().collect();
+    assert_eq!(cards.len(), 15);
+
+    // The first board comes off the hand's deck as without extra runouts
+    let mut single = Engine::new(None, 1)
+        .with_seed_path(tree, SeedPath::hand(0))
+        .unwrap();
+    all_in(&mut single);
+    assert_eq!(single.board(), &boards[0]);
+}
+
+#[test]
+fn undoing_a_runout_deals_it_again() {
+    let mut engine = Engine::new(None, 1)
+        .with_runouts(2)
+        .unwrap()
+        .with_seed_path(SeedTree::new(11), SeedPath::hand(4))
+        .unwrap();
+    all_in(&mut engine);
+    let boards = engine.runout_boards().to_vec();
+    engine.undo_last_action().unwrap();
+    engine.apply_action(1, A::Call).unwrap();
+    assert_eq!(engine.runout_boards(), boards.as_slice());
+}
+
+#[test]
+fn snapshots_keep_the_derivation() {
+    let path = SeedPath::hand(2);
+    let mut engine = Engine::new(None, 1)
+        .with_runouts(2)
+        .unwrap()
+        .with_seed_path(SeedTree::new(3), path)
+        .unwrap();
+    engine.deal_hand().unwrap();
+    engine.apply_action(0, A::AllIn).unwrap();
+    let json = serde_json::to_string(&engine.snapshot()).unwrap();
+    let mut restored =
+        Engine::restore(serde_json::from_str::<EngineSnapshot>(&json).unwrap()).unwrap();
+    assert_eq!(restored.seed_derivation(), engine.seed_derivation());
+
+    engine.apply_action(1, A::Call).unwrap();
+    restored.apply_action(1, A::Call).unwrap();
+    assert_eq!(restored.runout_boards(), engine.runout_boards());
+
+    let mut snapshot: serde_json::Value = serde_json::from_str(&json).unwrap();
+    snapshot["seed_derivation"]["scheme"] = "unknown".into();
+    let snapshot: EngineSnapshot = serde_json::from_value(snapshot).unwrap();
+    assert!(matches!(
+        Engine::restore(snapshot),
+        Err(GameError::InvalidSnapshot(_))
+    ));
+}
+
+#[test]
+fn only_seeded_decks_take_a_seed_path() {
+    let engine = Engine::new(None, 1)
+        .with_shuffle_source(ShuffleSource::Os)
+        .unwrap();
+    assert!(matches!(
+        engine.with_seed_path(SeedTree::new(1), SeedPath::hand(0)),
+        Err(GameError::InvalidSeedDerivation(_))
+    ));
+
+    // Replacing the deck drops the derivation
+    let engine = Engine::new(None, 1)
+        .with_seed_path(SeedTree::new(1), SeedPath::hand(0))
+        .unwrap()
+        .with_shuffle_source(ShuffleSource::ChaCha20(1))
+        .unwrap();
+    assert!(engine.seed_derivation().is_none());
+    assert!(engine.record_meta().get("seed_derivation").is_none());
+}
@@ -0,0 +1,175 @@
+use axiomind_engine::deck::DealSpec;
+use axiomind_engine::engine::{EndReason, Engine};
+use axiomind_engine::hand::{compare_hands, evaluate_hand};
+use axiomind_engine::player::{PlayerAction as A, STARTING_STACK};
+use axiomind_engine::variant::GameVariant;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::cmp::Ordering;
+
+fn dealt(seed: u64) -> Engine {
+    let mut eng = Engine::new(Some(seed), 1);
+    eng.shuffle();
+    eng.deal_hand().expect("deal ok");
+    eng
+}
+
+/// Deal a hand with cards pinned by a [`DealSpec`], e.g. `"7h7d KcKs | Kd7c2s"`.
+fn dealt_with(spec: &str, seed: u64) -> Engine {
+    let spec: DealSpec = spec.parse().expect("spec");
+    let mut eng = Engine::new(Some(seed), 1)
+        .with_deck(spec.deck(seed, 2, GameVariant::Holdem).expect("deck"))
+        .unwrap();
+    eng.deal_hand().expect("deal ok");
+    eng
+}
+
+fn total_stacks(eng: &Engine) -> u32 {
+    eng.players().iter().map(|p| p.stack()).sum()
+}
+
+#[test]
+fn preflop_fold_awards_blinds_to_big_blind() {
+    let mut eng = dealt(1);
+    assert!(eng.hand_outcome().is_none());
+    eng.apply_action(0, A::Fold).expect("fold");
+
+    let outcome = eng.hand_outcome().expect("settled");
+    assert_eq!(outcome.end_reason, EndReason::Fold);
+    assert_eq!(outcome.winners, vec![1]);
+    assert_eq!(outcome.net, vec![-50, 50]);
+    assert_eq!(outcome.uncalled_bet, Some((1, 50)));
+    assert_eq!(eng.players()[0].stack(), STARTING_STACK - 50);
+    assert_eq!(eng.players()[1].stack(), STARTING_STACK + 50);
+}
+
+#[test]
+fn uncalled_bet_is_returned_to_bettor() {
+    let mut eng = dealt(2);
+    eng.apply_action(0, A::Call).expect("button completes");
+    eng.apply_action(1, A::Check).expect("bb checks");
+    // Flop: big blind acts first and bets, button folds
+    eng.apply_action(1, A::Bet(300)).expect("bet");
+    eng.apply_action(0, A::Fold).expect("fold");
+
+    let outcome = eng.hand_outcome().expect("settled");
+    assert_eq!(outcome.uncalled_bet, Some((1, 300)));
+    assert_eq!(outcome.pots.len(), 1);
+    assert_eq!(outcome.pots[0].amount, 200);
+    assert_eq!(outcome.payouts, vec![0, 500]);
+    assert_eq!(outcome.net, vec![-100, 100]);
+    assert_eq!(total_stacks(&eng), 2 * STARTING_STACK);
+}
+
+#[test]
+fn showdown_pays_best_hand() {
+    let mut eng = dealt(3);
+    eng.apply_action(0, A::Call).expect("call");
+    eng.apply_action(1, A::Check).expect("check");
+    for _ in 0..3 {
+        eng.apply_action(1, A::Check).expect("check");
+        eng.apply_action(0, A::Check).expect("check");
+    }
+
+    let board = eng.board().clone();
+    let strength = |pid: usize| {
+        let hole = eng.players()[pid].hole_cards();
+        let cards = [
+            hole[0].unwrap(),
+            hole[1].unwrap(),
+            board[0],
+            board[1],
+            board[2],
+            board[3],
+            board[4],
+        ];
+        evaluate_hand(&cards)
+    };
+    let expected = match compare_hands(&strength(0), &strength(1)) {
+        Ordering::Greater => vec![0],
+        Ordering::Less => vec![1],
+        Ordering::Equal => vec![1, 0],
+    };
+
+    let outcome = eng.hand_outcome().expect("settled");
+    assert_eq!(outcome.end_reason, EndReason::Showdown);
+    assert_eq!(outcome.winners, expected);
+    assert_eq!(outcome.uncalled_bet, None);
+    assert_eq!(outcome.net.iter().sum::<i64>(), 0);
+    assert_eq!(total_stacks(&eng), 2 * STARTING_STACK);
+}
+
+#[test]
+fn set_over_set_pays_the_higher_set() {
+    let mut eng = dealt_with("7h7d KcKs | Kd7c2s 3h 9c", 1);
+    eng.apply_action(0, A::AllIn).expect("shove");
+    eng.apply_action(1, A::Call).expect("call");
+    while eng.hand_outcome().is_none() {
+        let pid = eng.current_player().expect("actor");
+        eng.apply_action(pid, A::Check).expect("check down");
+    }
+
+    let outcome = eng.hand_outcome().expect("settled");
+    assert_eq!(outcome.end_reason, EndReason::Showdown);
+    assert_eq!(outcome.winners, vec![1]);
+    assert_eq!(eng.players()[1].stack(), 2 * STARTING_STACK);
+}
+
+#[test]
+fn short_all_in_returns_excess_to_covering_player() {
+    let mut eng = Engine::new(Some(4), 1);
+    eng.players_mut()[1]
+        .bet(STARTING_STACK - 1_000)
+        .expect("shrink stack");
+    eng.shuffle();
+    eng.deal_hand().expect("deal ok");
+
+    eng.apply_action(0, A::AllIn).expect("shove");
+    eng.apply_action(1, A::Call).expect("call all-in");
+    while eng.hand_outcome().is_none() {
+        let pid = eng.current_player().expect("actor");
+        eng.apply_action(pid, A::Check).expect("check down");
+    }
+
+    let outcome = eng.hand_outcome().expect("settled");
+    assert_eq!(outcome.end_reason, EndReason::Showdown);
+    assert_eq!(outcome.uncalled_bet, Some((0, STARTING_STACK - 1_000)));
+    assert_eq!(outcome.pots[0].amount, 2_000);
+    assert_eq!(outcome.net.iter().sum::<i64>(), 0);
+    assert_eq!(total_stacks(&eng), STARTING_STACK + 1_000);
+}
+
+#[test]
+fn random_hands_conserve_chips() {
+    let mut rng = StdRng::seed_from_u64(99);
+    let menu = [
+        A::Fold,
+        A::Check,
+        A::Call,
+        A::Bet(100),
+        A::Bet(650),
+        A::Raise(200),
+        A::Raise(1_000),
+        A::AllIn,
+    ];
+    for seed in 0..200 {
+        let mut eng = dealt(seed);
+        while eng.hand_outcome().is_none() {
+            let pid = eng.current_player().expect("actor");
+            let action = menu[rng.random_range(0..menu.len())].clone();
+            // Fold only occasionally so most hands see several streets
+            if action == A::Fold && rng.random_range(0..4) != 0 {
+                continue;
+            }
+            let _ = eng.apply_action(pid, action);
+        }
+
+        let outcome = eng.hand_outcome().expect("settled");
+        assert_eq!(outcome.net.iter().sum::<i64>(), 0, "seed {seed}");
+        assert_eq!(total_stacks(&eng), 2 * STARTING_STACK, "seed {seed}");
+        for (pid, delta) in outcome.net.iter().enumerate() {
+            let stack = i64::from(eng.players()[pid].stack());
+            assert_eq!(stack, i64::from(STARTING_STACK) + delta, "seed {seed}");
+        }
+    }
+}
@@ -0,0 +1,215 @@
+use axiomind_engine::cards::{Rank, format_cards, parse_cards, short_deck};
+use axiomind_engine::deck::Deck;
+use axiomind_engine::engine::{EndReason, Engine};
+use axiomind_engine::errors::GameError;
+use axiomind_engine::events::{EngineEvent, EventQueue};
+use axiomind_engine::hand::{
+    Category, compare_hands, compare_short_deck, evaluate_best, evaluate_short_deck,
+};
+use axiomind_engine::player::PlayerAction as A;
+use axiomind_engine::variant::GameVariant;
+use std::cmp::Ordering;
+
+fn short(cards: &str) -> String {
+    let best = evaluate_short_deck(&parse_cards(cards).unwrap()).unwrap();
+    format!("{} [{}]", best.describe(), format_cards(&best.cards))
+}
+
+/// Hole cards AhKh against 7s7d on 7h6h9h 9c Tc: a flush against a full house.
+fn flush_against_full_house(variant: GameVariant) -> Engine {
+    let order = parse_cards("Ah 7s Kh 7d 8c 7h6h9h 8d 9c 8s Tc").unwrap();
+    let mut eng = Engine::new(Some(1), 1)
+        .with_variant(variant)
+        .unwrap()
+        .with_deck(Deck::from_order(order).unwrap())
+        .unwrap();
+    eng.deal_hand().unwrap();
+    eng.apply_action(0, A::Call).unwrap();
+    eng.apply_action(1, A::Check).unwrap();
+    eng.run_out_board().unwrap();
+    eng
+}
+
+#[test]
+fn short_deck_has_thirty_six_cards_from_six_up() {
+    let cards = short_deck();
+    assert_eq!(cards.len(), 36);
+    assert!(cards.iter().all(|c| c.rank >= Rank::Six));
+
+    let mut deck = Deck::short_with_seed(7);
+    assert!(deck.is_short());
+    deck.shuffle();
+    deck.burn_card();
+    let restored = Deck::restore(deck.snapshot()).unwrap();
+    assert!(restored.is_short());
+    assert_eq!(restored.remaining(), 35);
+    assert!(!Deck::new_with_seed(7).is_short());
+}
+
+#[test]
+fn ace_six_seven_eight_nine_is_the_lowest_straight() {
+    assert_eq!(short("Ah6c7d8s9h"), "Straight, Nine-high [9h8s7d6cAh]");
+    assert_eq!(short("Ah6c7d8s9hTc"), "Straight, Ten-high [Tc9h8s7d6c]");
+    assert_eq!(
+        short("Ah6h7h8h9hKc"),
+        "Straight Flush, Nine-high [9h8h7h6hAh]"
+    );
+    // The same cards are no straight with a full deck
+    let best = evaluate_best(&parse_cards("Ah6c7d8s9h").unwrap()).unwrap();
+    assert_eq!(best.strength.category, Category::HighCard);
+
+    let wheel = evaluate_short_deck(&parse_cards("Ah6c7d8s9h").unwrap()).unwrap();
+    let ten_high = evaluate_short_deck(&parse_cards("Tc6c7d8s9h").unwrap()).unwrap();
+    assert_eq!(
+        compare_short_deck(&wheel.strength, &ten_high.strength),
+        Ordering::Less
+    );
+}
+
+#[test]
+fn flush_beats_full_house_in_short_deck_only() {
+    let flush = evaluate_short_deck(&parse_cards("AhKh7h6h9h").unwrap()).unwrap();
+    let boat = evaluate_short_deck(&parse_cards("7s7d7h9h9c").unwrap()).unwrap();
+    let quads = evaluate_short_deck(&parse_cards("7s7d7h7c9c").unwrap()).unwrap();
+    assert_eq!(
+        compare_short_deck(&flush.strength, &boat.strength),
+        Ordering::Greater
+    );
+    assert_eq!(
+        compare_short_deck(&quads.strength, &flush.strength),
+        Ordering::Greater
+    );
+    assert_eq!(
+        compare_hands(&flush.strength, &boat.strength),
+        Ordering::Less
+    );
+    assert_eq!(
+        GameVariant::ShortDeck.compare_hands(&flush.strength, &boat.strength),
+        Ordering::Greater
+    );
+    assert_eq!(
+        GameVariant::Holdem.compare_hands(&flush.strength, &boat.strength),
+        Ordering::Less
+    );
+
+    let low = parse_cards("Ah5c7d8s9h").unwrap();
+    assert_eq!(
+        evaluate_short_deck(&low).unwrap_err(),
+        GameError::NotInShortDeck(low[1])
+    );
+}
+
+#[test]
+fn short_deck_parses_and_is_opt_in() {
+    for input in ["short_deck", "short-deck", "6+", "ShortDeck"] {
+        assert_eq!(
+            input.parse::<GameVariant>().unwrap(),
+            GameVariant::ShortDeck,
+            "{input}"
+        );
+    }
+    assert_eq!(GameVariant::ShortDeck.to_string(), "short_deck");
+    assert_eq!(
+        serde_json::to_value(GameVariant::ShortDeck).unwrap(),
+        serde_json::json!("short_deck")
+    );
+    assert_eq!(GameVariant::from_hole_cards(2), Some(GameVariant::Holdem));
+
+    let mut eng = Engine::new(Some(3), 1);
+    eng.deal_hand().unwrap();
+    assert_eq!(eng.deck_remaining(), 52 - 4);
+}
+
+#[test]
+fn short_deck_engine_deals_from_thirty_six_cards() {
+    let mut eng = Engine::new(Some(3), 1)
+        .with_seats(6)
+        .unwrap()
+        .with_variant(GameVariant::ShortDeck)
+        .unwrap();
+    let events = EventQueue::new();
+    eng.add_observer(events.clone());
+    eng.deal_hand().unwrap();
+    assert_eq!(eng.deck_remaining(), 36 - 12);
+    assert!(
+        eng.players()
+            .iter()
+            .flat_map(|p| p.hole())
+            .all(|c| c.rank >= Rank::Six)
+    );
+    assert!(matches!(
+        events.drain()[0],
+        EngineEvent::HandStarted {
+            variant: GameVariant::ShortDeck,
+            ..
+        }
+    ));
+
+    // Snapshots keep the short deck
+    let restored = Engine::restore(eng.snapshot()).unwrap();
+    assert_eq!(restored.variant(), GameVariant::ShortDeck);
+    assert_eq!(restored.deck_remaining(), 36 - 12);
+}
+
+#[test]
+fn preset_cards_below_six_cannot_be_dealt_short() {
+    let low = || Deck::from_order(parse_cards("Ah5d").unwrap()).unwrap();
+    let two_five = parse_cards("5d").unwrap()[0];
+    // In either order
+    assert_eq!(
+        Engine::new(Some(1), 1)
+            .with_deck(low())
+            .unwrap()
+            .with_variant(GameVariant::ShortDeck)
+            .unwrap_err(),
+        GameError::NotInShortDeck(two_five)
+    );
+    assert_eq!(
+        Engine::new(Some(1), 1)
+            .with_variant(GameVariant::ShortDeck)
+            .unwrap()
+            .with_deck(low())
+            .unwrap_err(),
+        GameError::NotInShortDeck(two_five)
+    );
+
+    // Preset and full decks handed to a short-deck engine are dealt short
+    for deck in [
+        Deck::from_order(parse_cards("AhKd").unwrap()).unwrap(),
+        Deck::new_with_seed(2),
+    ] {
+        let mut eng = Engine::new(Some(1), 1)
+            .with_variant(GameVariant::ShortDeck)
+            .unwrap()
+            .with_deck(deck)
+            .unwrap();
+        eng.deal_hand().unwrap();
+        eng.run_out_board().unwrap();
+        assert_eq!(eng.deck_remaining(), 36 - 12);
+        let dealt = eng
+            .players()
+            .iter()
+            .flat_map(|p| p.hole())
+            .chain(eng.board());
+        assert!(dealt.into_iter().all(|c| c.rank >= Rank::Six));
+    }
+}
+
+#[test]
+fn short_deck_showdown_pays_the_flush_over_the_full_house() {
+    let holdem = flush_against_full_house(GameVariant::Holdem);
+    assert_eq!(holdem.hand_outcome().unwrap().winners, vec![1]);
+
+    let eng = flush_against_full_house(GameVariant::ShortDeck);
+    assert_eq!(format_cards(eng.board()), "7h6h9h9cTc");
+    let outcome = eng.hand_outcome().unwrap();
+    assert_eq!(outcome.end_reason, EndReason::Showdown);
+    assert_eq!(outcome.winners, vec![0]);
+
+    let record = eng.hand_record("19700101-000001", Some(1));
+    assert_eq!(record.meta.as_ref().unwrap()["variant"], "short_deck");
+    assert_eq!(
+        record.showdown.unwrap().notes.as_deref(),
+        Some("Flush, Ace-high beats Full House, Sevens full of Nines")
+    );
+}
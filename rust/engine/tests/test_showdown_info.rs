@@ -11,6 +11,7 @@ fn showdown_info_serializes() {
             player_id: 0,
             street: Street::River,
             action: A::Check,
+            amount: None,
         }],
         board: vec![Card {
             suit: S::Clubs,
@@ -22,7 +23,10 @@ fn showdown_info_serializes() {
         showdown: Some(ShowdownInfo {
             winners: vec![0],
             notes: Some("kicker A".into()),
+            hands: vec![],
+            runouts: vec![],
         }),
+        ..Default::default()
     };
     let s = serde_json::to_string(&rec).unwrap();
     let back: HandRecord = serde_json::from_str(&s).unwrap();
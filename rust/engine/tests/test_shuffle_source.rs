@@ -0,0 +1,413 @@
+use axiomind_engine::cards::{Card, Rank, format_cards, full_deck, parse_cards};
+use axiomind_engine::deck::{DealSpec, Deck, ShuffleKind, ShuffleReveal, ShuffleSource};
+use axiomind_engine::engine::{Engine, EngineSnapshot};
+use axiomind_engine::errors::GameError;
+use axiomind_engine::logger::HandRecord;
+use axiomind_engine::player::PlayerAction as A;
+use axiomind_engine::rules::BettingStructure;
+use axiomind_engine::variant::GameVariant;
+
+fn dealt(deck: &mut Deck) -> Vec<Card> {
+    std::iter::from_fn(|| deck.deal_card()).collect()
+}
+
+fn reversed_deck() -> Vec<Card> {
+    let mut order = full_deck();
+    order.reverse();
+    order
+}
+
+/// Heads-up hand where the small blind shoves and the big blind calls.
+fn all_in_hand(engine: Engine) -> Engine {
+    let mut engine = engine;
+    engine.deal_hand().unwrap();
+    engine.apply_action(0, A::AllIn).unwrap();
+    engine.apply_action(1, A::Call).unwrap();
+    engine
+}
+
+fn fairness(record: &HandRecord) -> (String, ShuffleReveal) {
+    let fairness = &record.meta.as_ref().unwrap()["fairness"];
+    (
+        fairness["commitment"].as_str().unwrap().to_string(),
+        serde_json::from_value(fairness["reveal"].clone()).unwrap(),
+    )
+}
+
+#[test]
+fn sources_parse_from_text() {
+    assert_eq!(
+        "chacha20:42".parse::<ShuffleSource>().unwrap(),
+        ShuffleSource::ChaCha20(42)
+    );
+    assert_eq!("OS".parse::<ShuffleSource>().unwrap(), ShuffleSource::Os);
+    for bad in ["", "mersenne", "chacha20:x"] {
+        assert!(matches!(
+            bad.parse::<ShuffleSource>(),
+            Err(GameError::InvalidShuffleSource(_))
+        ));
+    }
+}
+
+#[test]
+fn seeded_source_matches_the_seeded_deck() {
+    let mut from_source = Deck::from_source(ShuffleSource::ChaCha20(42)).unwrap();
+    let mut seeded = Deck::new_with_seed(42);
+    for _ in 0..3 {
+        from_source.shuffle();
+        seeded.shuffle();
+        assert_eq!(dealt(&mut from_source), dealt(&mut seeded));
+    }
+    assert_eq!(seeded.source_kind(), ShuffleKind::ChaCha20);
+}
+
+#[test]
+fn os_source_deals_whole_decks_in_fresh_orders() {
+    let mut deck = Deck::from_source(ShuffleSource::Os).unwrap();
+    assert_eq!(deck.source_kind(), ShuffleKind::Os);
+    deck.shuffle();
+    let first = dealt(&mut deck);
+    deck.shuffle();
+    let second = dealt(&mut deck);
+    assert_eq!(first.len(), 52);
+    assert!(full_deck().iter().all(|c| second.contains(c)));
+    assert_ne!(first, second);
+}
+
+#[test]
+fn recorded_orders_are_dealt_in_turn() {
+    let text = format!(
+        "# two hands\n{}\n\n{}\n",
+        format_cards(&reversed_deck()),
+        format_cards(&full_deck())
+    );
+    let mut deck = Deck::from_source(ShuffleSource::recorded(&text).unwrap()).unwrap();
+    for expected in [reversed_deck(), full_deck(), reversed_deck()] {
+        deck.shuffle();
+        assert_eq!(dealt(&mut deck), expected);
+    }
+
+    // A full order used as a short deck drops Twos through Fives
+    let source = ShuffleSource::recorded(&format_cards(&reversed_deck())).unwrap();
+    let mut engine = Engine::new(None, 1)
+        .with_variant(GameVariant::ShortDeck)
+        .unwrap()
+        .with_shuffle_source(source)
+        .unwrap();
+    engine.deal_hand().unwrap();
+    assert_eq!(engine.players()[0].hole()[0].to_string(), "As");
+    assert_eq!(engine.deck_remaining(), 32);
+}
+
+#[test]
+fn recorded_files_must_hold_whole_decks() {
+    for text in ["", "# nothing\n", "AhKd\n", "AhAh\n"] {
+        assert!(
+            matches!(
+                ShuffleSource::recorded(text),
+                Err(GameError::InvalidShuffleSource(_))
+            ),
+            "{text:?}"
+        );
+    }
+    assert!(Deck::from_source(ShuffleSource::Recorded(Vec::new())).is_err());
+}
+
+#[test]
+fn committed_shuffles_stay_reproducible_from_the_seed() {
+    let mut first = Deck::new_with_seed(5).with_commit_reveal().unwrap();
+    let mut second = Deck::new_with_seed(5).with_commit_reveal().unwrap();
+    first.shuffle();
+    second.shuffle();
+    assert_eq!(first.reveal(), second.reveal());
+
+    let reveal = first.reveal().unwrap().clone();
+    assert_eq!(reveal.source, ShuffleKind::ChaCha20);
+    assert_eq!(reveal.seed.len(), 64);
+    assert_eq!(dealt(&mut first), reveal.deck);
+    assert_eq!(reveal.commitment().len(), 64);
+    assert!(reveal.verify(&reveal.commitment().to_uppercase()).is_ok());
+
+    first.shuffle();
+    assert_ne!(first.reveal().unwrap().seed, reveal.seed);
+}
+
+#[test]
+fn altered_reveals_are_rejected() {
+    let mut deck = Deck::from_source(ShuffleSource::Os)
+        .unwrap()
+        .with_commit_reveal()
+        .unwrap();
+    deck.shuffle();
+    let reveal = deck.reveal().unwrap().clone();
+    let commitment = reveal.commitment();
+    assert!(reveal.verify(&commitment).is_ok());
+
+    let mut swapped = reveal.clone();
+    swapped.deck.swap(0, 1);
+    assert!(matches!(
+        swapped.verify(&commitment),
+        Err(GameError::ShuffleMismatch(_))
+    ));
+    // A matching hash is not enough: the seed must produce the order
+    assert!(swapped.verify(&swapped.commitment()).is_err());
+
+    let mut other_seed = reveal.clone();
+    other_seed.seed = "00".repeat(32);
+    assert!(other_seed.verify(&other_seed.commitment()).is_err());
+
+    // Recorded orders are only bound by the hash
+    let mut recorded = swapped;
+    recorded.source = ShuffleKind::Recorded;
+    assert!(recorded.verify(&recorded.commitment()).is_ok());
+    recorded.deck.pop();
+    assert!(recorded.verify(&recorded.commitment()).is_err());
+}
+
+#[test]
+fn pinned_decks_cannot_commit() {
+    let deck = Deck::from_order(parse_cards("AhAd").unwrap()).unwrap();
+    assert!(matches!(
+        deck.with_commit_reveal(),
+        Err(GameError::InvalidDeck(_))
+    ));
+    let spec: DealSpec = "AhAd ???? | ??????".parse().unwrap();
+    assert!(
+        Engine::new(None, 1)
+            .with_deck(spec.deck(1, 2, GameVariant::Holdem).unwrap())
+            .unwrap()
+            .with_commit_reveal()
+            .is_err()
+    );
+}
+
+#[test]
+fn replacing_the_deck_keeps_commit_reveal() {
+    let commits = |engine: Engine| {
+        let mut engine = engine;
+        engine.deal_hand().unwrap();
+        engine.shuffle_commitment().is_some()
+    };
+    let committed = || Engine::new(Some(3), 1).with_commit_reveal().unwrap();
+
+    // A new deck or shuffle source, before or after commit-reveal is enabled
+    assert!(commits(
+        committed().with_deck(Deck::new_with_seed(4)).unwrap()
+    ));
+    assert!(commits(
+        Engine::new(Some(3), 1)
+            .with_deck(Deck::new_with_seed(4))
+            .unwrap()
+            .with_commit_reveal()
+            .unwrap()
+    ));
+    assert!(commits(
+        committed().with_shuffle_source(ShuffleSource::Os).unwrap()
+    ));
+    assert!(commits(
+        Engine::new(Some(3), 1)
+            .with_shuffle_source(ShuffleSource::Os)
+            .unwrap()
+            .with_commit_reveal()
+            .unwrap()
+    ));
+
+    // A pinned deck cannot commit, so it is refused rather than dropping it
+    let pinned = Deck::from_order(parse_cards("AhAd").unwrap()).unwrap();
+    assert!(matches!(
+        committed().with_deck(pinned),
+        Err(GameError::InvalidDeck(_))
+    ));
+}
+
+#[test]
+fn reveal_waits_for_the_end_of_the_hand() {
+    let mut engine = Engine::new(Some(8), 1).with_commit_reveal().unwrap();
+    assert!(engine.shuffle_commitment().is_none());
+    engine.deal_hand().unwrap();
+    let commitment = engine.shuffle_commitment().unwrap();
+    assert!(engine.shuffle_reveal().is_none());
+    let meta = engine.record_meta();
+    assert_eq!(meta["fairness"]["commitment"], commitment.as_str());
+    assert!(meta["fairness"].get("reveal").is_none());
+
+    engine.apply_action(0, A::Fold).unwrap();
+    let record = engine.hand_record("19700101-000001", Some(8));
+    let (logged, reveal) = fairness(&record);
+    assert_eq!(logged, commitment);
+    assert_eq!(Some(&reveal), engine.shuffle_reveal());
+    reveal.verify(&logged).unwrap();
+    reveal.check_deal(&record).unwrap();
+    assert!(record.meta.unwrap().get("shuffle_source").is_none());
+}
+
+#[test]
+fn check_deal_follows_the_engine_deal_order() {
+    // Omaha at six seats, all in preflop, board run three times
+    let mut engine = Engine::new(Some(4), 1)
+        .with_seats(6)
+        .unwrap()
+        .with_betting_structure(BettingStructure::NoLimit)
+        .with_variant(GameVariant::Omaha)
+        .unwrap()
+        .with_runouts(3)
+        .unwrap()
+        .with_shuffle_source(ShuffleSource::Os)
+        .unwrap()
+        .with_commit_reveal()
+        .unwrap();
+    engine.deal_hand().unwrap();
+    while let Ok(seat) = engine.current_player() {
+        engine.apply_action(seat, A::AllIn).unwrap();
+    }
+    assert_eq!(engine.runout_boards().len(), 3);
+    let record = engine.hand_record("19700101-000001", None);
+    assert_eq!(record.meta.as_ref().unwrap()["shuffle_source"], "os");
+    let (commitment, reveal) = fairness(&record);
+    assert_eq!(reveal.source, ShuffleKind::Os);
+    reveal.verify(&commitment).unwrap();
+    reveal.check_deal(&record).unwrap();
+
+    // Swapping two dealt cards is caught
+    let mut altered = record.clone();
+    let showdown = altered.showdown.as_mut().unwrap();
+    let last = showdown.runouts[2][4];
+    showdown.runouts[2][4] = showdown.runouts[1][4];
+    showdown.runouts[1][4] = last;
+    let err = reveal.check_deal(&altered).unwrap_err();
+    assert!(err.to_string().contains("run 2 card 5"), "{err}");
+
+    let mut altered = record;
+    altered.players[3].hole_cards.as_mut().unwrap().swap(0, 1);
+    let err = reveal.check_deal(&altered).unwrap_err();
+    assert!(err.to_string().contains("p3 hole card 1"), "{err}");
+}
+
+#[test]
+fn check_deal_skips_busted_seats() {
+    let mut engine = Engine::new(Some(2), 1)
+        .with_seats(3)
+        .unwrap()
+        .with_commit_reveal()
+        .unwrap();
+    let busted = &mut engine.players_mut()[1];
+    busted.bet(busted.stack()).unwrap();
+    engine.deal_hand().unwrap();
+    while let Ok(seat) = engine.current_player() {
+        engine.apply_action(seat, A::AllIn).unwrap();
+    }
+    let record = engine.hand_record("19700101-000001", Some(2));
+    let (commitment, reveal) = fairness(&record);
+    reveal.verify(&commitment).unwrap();
+    reveal.check_deal(&record).unwrap();
+}
+
+#[test]
+fn check_deal_covers_runouts_after_the_flop() {
+    let mut engine = Engine::new(Some(4), 1)
+        .with_runouts(2)
+        .unwrap()
+        .with_commit_reveal()
+        .unwrap();
+    engine.deal_hand().unwrap();
+    engine.apply_action(0, A::Call).unwrap();
+    engine.apply_action(1, A::Check).unwrap();
+    engine.apply_action(1, A::AllIn).unwrap();
+    engine.apply_action(0, A::Call).unwrap();
+    let record = engine.hand_record("19700101-000001", Some(4));
+    let (commitment, reveal) = fairness(&record);
+    reveal.verify(&commitment).unwrap();
+    reveal.check_deal(&record).unwrap();
+}
+
+#[test]
+fn recorded_commitments_cover_the_played_order() {
+    let source = ShuffleSource::recorded(&format_cards(&reversed_deck())).unwrap();
+    let engine = all_in_hand(
+        Engine::new(None, 1)
+            .with_shuffle_source(source)
+            .unwrap()
+            .with_commit_reveal()
+            .unwrap(),
+    );
+    let record = engine.hand_record("19700101-000001", None);
+    let (commitment, reveal) = fairness(&record);
+    assert_eq!(reveal.source, ShuffleKind::Recorded);
+    assert_eq!(reveal.deck, reversed_deck());
+    reveal.verify(&commitment).unwrap();
+    reveal.check_deal(&record).unwrap();
+    assert_eq!(
+        record.players[0].hole_cards.as_ref().unwrap()[0].rank,
+        Rank::Ace
+    );
+}
+
+#[test]
+fn snapshots_keep_the_source_and_commitment() {
+    let source = ShuffleSource::recorded(&format!(
+        "{}\n{}",
+        format_cards(&full_deck()),
+        format_cards(&reversed_deck())
+    ))
+    .unwrap();
+    let mut deck = Deck::from_source(source)
+        .unwrap()
+        .with_commit_reveal()
+        .unwrap();
+    deck.shuffle();
+    let mut copy = Deck::restore(deck.snapshot()).unwrap();
+    assert_eq!(copy.reveal(), deck.reveal());
+    assert!(copy.commits());
+    copy.shuffle();
+    assert_eq!(dealt(&mut copy), reversed_deck());
+
+    // Seeded decks keep their old snapshot form
+    let json = serde_json::to_value(Deck::new_with_seed(1).snapshot()).unwrap();
+    assert!(json.get("entropy").is_none());
+    assert!(json.get("commit_reveal").is_none());
+}
+
+#[test]
+fn snapshots_keep_a_committed_deck_secret_until_the_hand_is_over() {
+    let mut engine = Engine::new(Some(6), 1).with_commit_reveal().unwrap();
+    engine.deal_hand().unwrap();
+    let secret = engine.snapshot_with_secrets();
+    let reveal = serde_json::to_value(&secret).unwrap()["deck"]["reveal"].clone();
+    let seed = reveal["seed"].as_str().unwrap().to_string();
+
+    // Mid-hand the seed, the order and the RNG state stay out
+    let json = serde_json::to_string(&engine.snapshot()).unwrap();
+    assert!(!json.contains(&seed));
+    let sealed: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert!(sealed["deck"].get("reveal").is_none());
+    assert_eq!(sealed["deck"]["cards"], serde_json::json!([]));
+    assert!(
+        sealed["deck"]["rng_seed"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .all(|b| b == 0)
+    );
+    let snapshot: EngineSnapshot = serde_json::from_str(&json).unwrap();
+    assert!(matches!(
+        Engine::restore(snapshot),
+        Err(GameError::InvalidSnapshot(_))
+    ));
+
+    // The full snapshot still restores and plays on
+    let mut restored = Engine::restore(secret).unwrap();
+    restored.apply_action(0, A::Fold).unwrap();
+    assert_eq!(
+        serde_json::to_value(restored.shuffle_reveal()).unwrap(),
+        reveal
+    );
+
+    // Once the hand is over nothing is left to hide
+    engine.apply_action(0, A::Fold).unwrap();
+    assert!(
+        serde_json::to_string(&engine.snapshot())
+            .unwrap()
+            .contains(&seed)
+    );
+    Engine::restore(engine.snapshot()).unwrap();
+}
@@ -0,0 +1,104 @@
+use axiomind_engine::engine::{Engine, EngineSnapshot};
+use axiomind_engine::errors::GameError;
+use axiomind_engine::player::PlayerAction as A;
+use axiomind_engine::rules::{BlindLevel, BlindStructure};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// Pick a legal action for the seat on turn, sizing bets from the legal range.
+fn pick_action(eng: &Engine, rng: &mut ChaCha8Rng) -> (usize, A) {
+    let actor = eng.current_player().expect("actor");
+    let legal = eng.legal_actions(actor);
+    let mut choices = Vec::new();
+    if legal.check {
+        choices.push(A::Check);
+    }
+    if legal.call.is_some() {
+        choices.push(A::Call);
+    }
+    if let Some(range) = legal.bet {
+        choices.push(A::Bet(range.clamp(range.min * 2)));
+    }
+    if let Some(action) = legal.raise_to_action(legal.current_bet * 3) {
+        choices.push(action);
+    }
+    if choices.is_empty() {
+        choices.push(A::Fold);
+    }
+    (actor, choices[rng.random_range(0..choices.len())].clone())
+}
+
+/// Play `hands` hands from the engine's current state, returning stacks and boards seen.
+fn play_on(eng: &mut Engine, hands: usize, seed: u64) -> Vec<(Vec<u32>, usize)> {
+    let mut rng = ChaCha8Rng::seed_from_u64(seed);
+    let mut seen = Vec::new();
+    for _ in 0..hands {
+        let needs_deal = eng.current_street().is_none() || eng.is_hand_complete();
+        if needs_deal && eng.deal_hand().is_err() {
+            break;
+        }
+        while !eng.is_hand_complete() {
+            let (actor, action) = pick_action(eng, &mut rng);
+            eng.apply_action(actor, action).expect("legal action");
+        }
+        let stacks = eng.players().iter().map(|p| p.stack()).collect();
+        seen.push((stacks, eng.board().len()));
+        eng.set_button(eng.button() + 1);
+    }
+    seen
+}
+
+#[test]
+fn restored_engine_continues_identically_mid_hand() {
+    let mut original = Engine::new(Some(2024), 1);
+    original.deal_hand().expect("deal");
+    original.apply_action(0, A::Call).expect("call");
+    original.apply_action(1, A::Check).expect("check");
+    assert_eq!(original.board().len(), 3);
+
+    let json = serde_json::to_string(&original.snapshot()).expect("serialize");
+    let snapshot: EngineSnapshot = serde_json::from_str(&json).expect("deserialize");
+    let mut restored = Engine::restore(snapshot).expect("restore");
+    assert_eq!(restored.snapshot(), original.snapshot());
+
+    let a = play_on(&mut original, 25, 77);
+    let b = play_on(&mut restored, 25, 77);
+    assert_eq!(a, b);
+    assert_eq!(restored.snapshot(), original.snapshot());
+}
+
+#[test]
+fn snapshot_between_hands_preserves_future_shuffles() {
+    let mut original = Engine::new(Some(5), 3).with_blind_structure(BlindStructure {
+        hands_per_level: 4,
+        big_blind_ante: true,
+        levels: vec![BlindLevel {
+            sb: 50,
+            bb: 100,
+            ante: 100,
+        }],
+    });
+    play_on(&mut original, 3, 1);
+
+    let mut restored = Engine::restore(original.snapshot()).expect("restore");
+    original.deal_hand().expect("deal");
+    restored.deal_hand().expect("deal");
+    assert_eq!(
+        restored.players()[0].hole_cards(),
+        original.players()[0].hole_cards()
+    );
+    assert_eq!(restored.pot(), original.pot());
+    assert_eq!(restored.blind_structure(), original.blind_structure());
+}
+
+#[test]
+fn restore_rejects_impossible_button() {
+    let eng = Engine::new(Some(1), 1);
+    let mut value = serde_json::to_value(eng.snapshot()).expect("serialize");
+    value["button_position"] = serde_json::json!(3);
+    let snapshot: EngineSnapshot = serde_json::from_value(value).expect("deserialize");
+    assert!(matches!(
+        Engine::restore(snapshot),
+        Err(GameError::InvalidSnapshot(_))
+    ));
+}
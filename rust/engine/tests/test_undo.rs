@@ -0,0 +1,158 @@
+use axiomind_engine::engine::{Engine, EngineSnapshot};
+use axiomind_engine::errors::GameError;
+use axiomind_engine::logger::Street;
+use axiomind_engine::player::PlayerAction as A;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// Pick any legal action for the seat on turn, including folds and all-ins.
+fn random_action(eng: &Engine, rng: &mut ChaCha8Rng) -> (usize, A) {
+    let actor = eng.current_player().expect("actor");
+    let legal = eng.legal_actions(actor);
+    let mut choices = Vec::new();
+    if legal.fold && rng.random_range(0..4) == 0 {
+        choices.push(A::Fold);
+    }
+    if legal.check {
+        choices.push(A::Check);
+    }
+    if legal.call.is_some() {
+        choices.push(A::Call);
+    }
+    if let Some(range) = legal.bet {
+        choices.push(A::Bet(range.clamp(rng.random_range(range.min..=range.max))));
+    }
+    if let Some(range) = legal.raise_to {
+        let total = rng.random_range(range.min..=range.max);
+        if let Some(action) = legal.raise_to_action(total) {
+            choices.push(action);
+        }
+    }
+    if legal.all_in.is_some() && rng.random_range(0..6) == 0 {
+        choices.push(A::AllIn);
+    }
+    (actor, choices[rng.random_range(0..choices.len())].clone())
+}
+
+/// Deal a hand and play it to the end, returning the snapshot before every action.
+fn play_recorded(eng: &mut Engine, rng: &mut ChaCha8Rng) -> Vec<EngineSnapshot> {
+    eng.deal_hand().expect("deal");
+    let mut before = Vec::new();
+    while !eng.is_hand_complete() {
+        before.push(eng.snapshot());
+        let (actor, action) = random_action(eng, rng);
+        eng.apply_action(actor, action).expect("legal action");
+    }
+    before
+}
+
+#[test]
+fn undo_round_trips_random_hands() {
+    let mut rng = ChaCha8Rng::seed_from_u64(8);
+    for seed in 0..200 {
+        let mut eng = Engine::new(Some(seed), 1 + (seed % 5) as u8);
+        eng.set_button(seed as usize);
+        let before = play_recorded(&mut eng, &mut rng);
+        let history = eng.action_history();
+
+        for (index, expected) in before.iter().enumerate().rev() {
+            let undone = eng.undo_last_action().expect("undo");
+            assert_eq!(undone, history[index]);
+            assert_eq!(&eng.snapshot(), expected, "seed {} action {}", seed, index);
+        }
+        assert_eq!(eng.undo_last_action(), Err(GameError::NothingToUndo));
+    }
+}
+
+#[test]
+fn rewind_then_replay_matches_original() {
+    let mut rng = ChaCha8Rng::seed_from_u64(80);
+    for seed in 0..200 {
+        let mut eng = Engine::new(Some(seed), 1);
+        let before = play_recorded(&mut eng, &mut rng);
+        let finished = eng.snapshot();
+        let history = eng.action_history();
+
+        let index = rng.random_range(0..before.len());
+        eng.rewind_to(index).expect("rewind");
+        assert_eq!(eng.snapshot(), before[index]);
+        assert_eq!(eng.action_history(), history[..index]);
+
+        for record in &history[index..] {
+            eng.apply_action(record.player_id, record.action.clone())
+                .expect("replay");
+        }
+        assert_eq!(eng.snapshot(), finished, "seed {}", seed);
+    }
+}
+
+#[test]
+fn undo_restores_min_raise_after_short_all_in() {
+    let mut eng = Engine::new(Some(3), 1);
+    eng.players_mut()[1].bet(19_650).expect("shorten stack");
+    eng.deal_hand().expect("deal");
+    eng.apply_action(0, A::Raise(200)).expect("raise to 300");
+    assert_eq!(eng.min_raise(), Some(200));
+
+    // 350 total is only a 50 raise, so min-raise stays at 200
+    eng.apply_action(1, A::AllIn).expect("short all-in");
+    assert_eq!(eng.current_bet(), Some(350));
+    assert_eq!(eng.min_raise(), Some(200));
+
+    eng.undo_last_action().expect("undo");
+    assert_eq!(eng.current_bet(), Some(300));
+    assert_eq!(eng.min_raise(), Some(200));
+    assert_eq!(eng.players()[1].stack(), 250);
+    assert_eq!(eng.legal_actions(1).all_in, Some(250));
+    assert_eq!(eng.current_player(), Ok(1));
+}
+
+#[test]
+fn undo_reverses_street_change_and_settlement() {
+    let mut eng = Engine::new(Some(11), 1);
+    eng.deal_hand().expect("deal");
+    eng.apply_action(0, A::Call).expect("call");
+    eng.apply_action(1, A::Check).expect("check");
+    eng.apply_action(1, A::Bet(200)).expect("bet");
+    eng.apply_action(0, A::Fold).expect("fold");
+    assert!(eng.is_hand_complete());
+    assert_eq!(eng.players()[1].stack(), 20_100);
+
+    eng.undo_last_action().expect("undo fold");
+    assert!(!eng.is_hand_complete());
+    assert_eq!(eng.hand_outcome(), None);
+    assert_eq!(eng.players()[1].stack(), 19_700);
+    assert_eq!(eng.pot(), 400);
+
+    eng.rewind_to(1).expect("rewind to before the check");
+    assert_eq!(eng.current_street(), Some(Street::Preflop));
+    assert!(eng.board().is_empty());
+    assert_eq!(eng.deck_remaining(), 48);
+    assert_eq!(eng.current_player(), Ok(1));
+
+    // The same flop comes out again
+    eng.apply_action(1, A::Check).expect("check");
+    assert_eq!(eng.board().len(), 3);
+}
+
+#[test]
+fn rewind_rejects_future_and_pre_snapshot_points() {
+    let mut eng = Engine::new(Some(1), 1);
+    eng.deal_hand().expect("deal");
+    eng.apply_action(0, A::Call).expect("call");
+    assert_eq!(
+        eng.rewind_to(2),
+        Err(GameError::InvalidRewind {
+            index: 2,
+            actions: 1
+        })
+    );
+    assert_eq!(eng.rewind_to(1), Ok(()));
+
+    let mut restored = Engine::restore(eng.snapshot()).expect("restore");
+    assert_eq!(restored.undo_last_action(), Err(GameError::NothingToUndo));
+    assert!(restored.rewind_to(0).is_err());
+    restored.apply_action(1, A::Check).expect("check");
+    restored.rewind_to(1).expect("rewind past own action");
+    assert!(restored.board().is_empty());
+}
@@ -77,7 +77,7 @@ mod tests {
         let ai = create_ai("baseline");
         let engine = Engine::new(Some(42), 1);
 
-        let action = ai.get_action(&engine, 1);
+        let action = ai.get_action(&engine.observation(1).unwrap());
         // Should return some valid action
         assert!(matches!(
             action,
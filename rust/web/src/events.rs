@@ -267,4 +267,7 @@ pub struct PlayerInfo {
 pub struct HandResult {
     pub winner_ids: Vec<usize>,
     pub pot: u32,
+    /// Winning hand at showdown, e.g. "Two Pair, Kings and Sevens"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hand_description: Option<String>,
 }
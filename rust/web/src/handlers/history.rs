@@ -148,6 +148,7 @@ mod tests {
                     player_id: 0,
                     street: Street::Preflop,
                     action: PlayerAction::Check,
+                    amount: None,
                 }],
                 board: vec![Card {
                     rank: Rank::Ace,
@@ -157,6 +158,7 @@ mod tests {
                 ts: Some(format!("2025-01-0{}T10:00:00Z", i + 1)),
                 meta: None,
                 showdown: None,
+                ..Default::default()
             };
             history.add_hand(hand).expect("add hand");
         }
@@ -234,11 +234,13 @@ mod tests {
                     player_id: 0,
                     street: Street::Preflop,
                     action: PlayerAction::Bet(100),
+                    amount: None,
                 },
                 ActionRecord {
                     player_id: 1,
                     street: Street::Preflop,
                     action: PlayerAction::Call,
+                    amount: None,
                 },
             ],
             board: vec![
@@ -259,6 +261,7 @@ mod tests {
             ts: ts.map(String::from),
             meta: None,
             showdown: None,
+            ..Default::default()
         }
     }
 
@@ -114,11 +114,7 @@ impl MetricsCollector {
     /// Log current metrics
     pub fn log_metrics(&self) {
         let snapshot = self.snapshot();
-        let avg_response_time = if snapshot.total_requests > 0 {
-            snapshot.total_response_time_ms / snapshot.total_requests
-        } else {
-            0
-        };
+        let avg_response_time = snapshot.average_response_time_ms();
 
         tracing::info!(
             total_requests = snapshot.total_requests,
@@ -145,11 +141,9 @@ pub struct MetricsSnapshot {
 
 impl MetricsSnapshot {
     pub fn average_response_time_ms(&self) -> u64 {
-        if self.total_requests > 0 {
-            self.total_response_time_ms / self.total_requests
-        } else {
-            0
-        }
+        self.total_response_time_ms
+            .checked_div(self.total_requests)
+            .unwrap_or(0)
     }
 
     pub fn success_rate(&self) -> f64 {
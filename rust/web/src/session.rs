@@ -2,13 +2,14 @@ use crate::ai::{AIOpponent, create_ai};
 use crate::events::{EventBus, GameEvent, HandResult, PlayerInfo};
 use crate::history::HistoryStore;
 use axiomind_engine::cards::Card;
-use axiomind_engine::engine::Engine;
-use axiomind_engine::hand::{compare_hands, evaluate_hand};
-use axiomind_engine::logger::{ActionRecord, HandRecord, Street};
+use axiomind_engine::engine::{EndReason, Engine, HandOutcome};
+use axiomind_engine::events::{EngineEvent, EventQueue};
+use axiomind_engine::game::Match;
+use axiomind_engine::logger::{HandRecord, Street};
 use axiomind_engine::player::{PlayerAction, Position as EnginePosition};
+use axiomind_engine::rules::LegalActions;
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
-use std::cmp::{Ordering, min};
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex, RwLock};
 use std::time::{Duration, Instant};
@@ -23,14 +24,18 @@ const DEFAULT_SESSION_TTL: Duration = Duration::from_secs(30 * 60);
 #[serde(rename_all = "snake_case")]
 pub enum SeatPosition {
     Button,
+    SmallBlind,
     BigBlind,
+    Other,
 }
 
 impl From<EnginePosition> for SeatPosition {
     fn from(position: EnginePosition) -> Self {
         match position {
             EnginePosition::Button => SeatPosition::Button,
+            EnginePosition::SmallBlind => SeatPosition::SmallBlind,
             EnginePosition::BigBlind => SeatPosition::BigBlind,
+            EnginePosition::Other => SeatPosition::Other,
         }
     }
 }
@@ -39,7 +44,9 @@ impl From<SeatPosition> for EnginePosition {
     fn from(position: SeatPosition) -> Self {
         match position {
             SeatPosition::Button => EnginePosition::Button,
+            SeatPosition::SmallBlind => EnginePosition::SmallBlind,
             SeatPosition::BigBlind => EnginePosition::BigBlind,
+            SeatPosition::Other => EnginePosition::Other,
         }
     }
 }
@@ -78,7 +85,7 @@ impl From<SeatPosition> for EnginePosition {
 ///
 /// // Process a player action
 /// use axiomind_engine::player::PlayerAction;
-/// manager.process_action(&session_id, PlayerAction::Check)
+/// manager.process_action(&session_id, PlayerAction::Call)
 ///     .expect("Failed to process action");
 /// ```
 #[derive(Debug)]
@@ -133,8 +140,8 @@ impl SessionManager {
     /// Creates a new game session with the given configuration.
     ///
     /// This method initializes a new poker game session, generates a unique session ID,
-    /// starts the first hand, and broadcasts initialization events (GameStarted, HandStarted,
-    /// CardsDealt) to all subscribers.
+    /// starts the first hand, and broadcasts GameStarted followed by the engine's events
+    /// for the deal (HandStarted, CardsDealt) to all subscribers.
     ///
     /// # Arguments
     /// * `config` - Game configuration including seed, blind level, and opponent type
@@ -175,7 +182,7 @@ impl SessionManager {
         );
 
         let session = Arc::new(GameSession::new(id.clone(), config));
-        let hand = session.start_new_hand()?;
+        let hand_id = session.start_new_hand()?;
 
         {
             let mut guard = self
@@ -187,7 +194,7 @@ impl SessionManager {
 
         tracing::debug!(
             session_id = %id,
-            hand_id = %hand.hand_id,
+            hand_id = %hand_id,
             "session created and first hand started"
         );
 
@@ -212,25 +219,7 @@ impl SessionManager {
             },
         );
 
-        self.event_bus.broadcast(
-            &id,
-            GameEvent::HandStarted {
-                session_id: id.clone(),
-                hand_id: hand.hand_id.clone(),
-                button_player: hand.button_player,
-            },
-        );
-
-        for (player_id, cards) in hand.player_cards {
-            self.event_bus.broadcast(
-                &id,
-                GameEvent::CardsDealt {
-                    session_id: id.clone(),
-                    player_id,
-                    cards,
-                },
-            );
-        }
+        self.publish_engine_events(&id, &session);
 
         Ok(id)
     }
@@ -285,11 +274,10 @@ impl SessionManager {
     /// # Behavior
     /// 1. Validates session exists and is not expired
     /// 2. Applies the action to the game engine
-    /// 3. Broadcasts PlayerAction event
-    /// 4. Advances to the next player's turn
-    /// 5. Automatically processes AI actions if next player is AI
-    /// 6. Finalizes hand if game is complete
-    /// 7. Records hand to history store if configured
+    /// 3. Broadcasts the engine's events (PlayerAction, CommunityCards, HandCompleted)
+    /// 4. Automatically processes AI actions if next player is AI
+    /// 5. Finalizes hand if game is complete
+    /// 6. Records hand to history store if configured
     ///
     /// # Example
     /// ```no_run
@@ -300,7 +288,7 @@ impl SessionManager {
     /// # let event_bus = Arc::new(EventBus::new());
     /// # let manager = SessionManager::new(event_bus);
     /// # let session_id = manager.create_session(GameConfig::default())?;
-    /// let event = manager.process_action(&session_id, PlayerAction::Check)?;
+    /// let event = manager.process_action(&session_id, PlayerAction::Call)?;
     /// println!("Action processed: {:?}", event);
     /// # Ok::<(), axiomind_web::session::SessionError>(())
     /// ```
@@ -334,36 +322,18 @@ impl SessionManager {
             "processing player action"
         );
 
-        // Apply action to engine and get current street
-        let current_street = session.apply_action(player_id, action.clone())?;
-
-        // Record action in session
-        session.record_action(player_id, action.clone(), current_street)?;
-
-        let event = GameEvent::PlayerAction {
-            session_id: session_id.clone(),
-            player_id,
-            action: action.clone(),
-        };
-        self.event_bus.broadcast(session_id, event.clone());
-
-        // Check if this action completes the hand (e.g., Fold)
-        let hand_complete_by_action = matches!(action, PlayerAction::Fold);
+        session.apply_action(player_id, action)?;
+        let event = self
+            .publish_engine_events(session_id, &session)
+            .into_iter()
+            .find(|event| matches!(event, GameEvent::PlayerAction { .. }))
+            .ok_or_else(|| SessionError::EngineError("Action was not reported".to_string()))?;
 
-        if hand_complete_by_action {
-            // Fold immediately ends the hand
-            self.finalize_hand(session_id, &session)?;
+        if session.check_hand_complete()? {
+            self.finalize_hand(&session)?;
         } else {
-            // Advance turn
-            session.advance_turn()?;
-
             // Process AI action if next player is AI
             self.process_ai_turn_if_needed(session_id)?;
-
-            // Check if hand is complete after actions
-            if session.check_hand_complete()? {
-                self.finalize_hand(session_id, &session)?;
-            }
         }
 
         Ok(event)
@@ -391,55 +361,43 @@ impl SessionManager {
                 SessionError::InvalidAction("AI failed to provide action".to_string())
             })?;
 
-            // Apply action to engine
-            let current_street = session.apply_action(current_player, action.clone())?;
-
-            // Record AI action
-            session.record_action(current_player, action.clone(), current_street)?;
-
-            // Broadcast AI action
-            let event = GameEvent::PlayerAction {
-                session_id: session_id.clone(),
-                player_id: current_player,
-                action: action.clone(),
-            };
-            self.event_bus.broadcast(session_id, event);
-
-            // Advance to next turn
-            session.advance_turn()?;
+            session.apply_action(current_player, action)?;
+            self.publish_engine_events(session_id, &session);
 
             // Check if hand is complete after AI action
             if session.check_hand_complete()? {
-                self.finalize_hand(session_id, &session)?;
+                self.finalize_hand(&session)?;
                 return Ok(());
             }
         }
     }
 
-    /// Finalize a completed hand
-    fn finalize_hand(
+    /// Broadcast the engine's events since the last call, translated for clients.
+    ///
+    /// The engine is the only source of hand events, so what subscribers see
+    /// always matches the engine state.
+    fn publish_engine_events(
         &self,
         session_id: &SessionId,
         session: &GameSession,
-    ) -> Result<(), SessionError> {
-        // Get final state
-        let state = session.state_snapshot()?;
+    ) -> Vec<GameEvent> {
+        let events: Vec<GameEvent> = session
+            .events
+            .drain()
+            .into_iter()
+            .filter_map(|event| session.game_event(event))
+            .collect();
+        for event in &events {
+            self.event_bus.broadcast(session_id, event.clone());
+        }
+        events
+    }
 
-        // Determine winners based on game state
+    /// Finalize a completed hand
+    fn finalize_hand(&self, session: &GameSession) -> Result<(), SessionError> {
+        // The engine settled the hand and HandCompleted has been broadcast
         let winners = session.determine_winners()?;
 
-        // Broadcast hand completed event
-        self.event_bus.broadcast(
-            session_id,
-            GameEvent::HandCompleted {
-                session_id: session_id.clone(),
-                result: HandResult {
-                    winner_ids: winners.clone(),
-                    pot: state.pot,
-                },
-            },
-        );
-
         // Record to history if available
         if let Some(history) = &self.history_store {
             let record = session.create_hand_record()?;
@@ -544,16 +502,16 @@ impl SessionManager {
 #[allow(dead_code)]
 pub struct GameSession {
     id: SessionId,
-    engine: Mutex<Engine>,
+    /// Match the session plays; it moves the button, raises the blinds and
+    /// seeds each hand from the session's root seed
+    game: Mutex<Match>,
     config: GameConfig,
     state: Mutex<GameSessionState>,
     created_at: Instant,
     last_active: Mutex<Instant>,
-    button_tracker: Mutex<usize>,
     ai_opponent: Option<Box<dyn AIOpponent>>,
-    action_history: Mutex<Vec<ActionRecord>>,
-    pot_tracker: Mutex<u32>,
-    actions_this_street: Mutex<usize>,
+    /// Engine events not yet broadcast
+    events: EventQueue,
 }
 
 impl std::fmt::Debug for GameSession {
@@ -574,15 +532,15 @@ impl std::fmt::Debug for GameSession {
     }
 }
 
-struct HandMetadata {
-    hand_id: String,
-    button_player: usize,
-    player_cards: Vec<(usize, Option<Vec<Card>>)>,
-}
-
 impl GameSession {
     fn new(id: SessionId, config: GameConfig) -> Self {
-        let engine = Engine::new(config.seed, config.level);
+        // Every deck is committed to, so players can check it after the hand
+        let root = config.seed.unwrap_or_else(rand::random);
+        let mut game = Match::new(Some(root), config.level)
+            .with_commit_reveal()
+            .expect("session decks have no pinned cards");
+        let events = EventQueue::new();
+        game.add_observer(events.clone());
         let ai_opponent = match &config.opponent_type {
             OpponentType::AI(name) => Some(create_ai(name)),
             OpponentType::Human => None,
@@ -590,16 +548,13 @@ impl GameSession {
         let now = Instant::now();
         Self {
             id,
-            engine: Mutex::new(engine),
+            game: Mutex::new(game),
             config,
             state: Mutex::new(GameSessionState::WaitingForPlayers),
             created_at: now,
             last_active: Mutex::new(now),
-            button_tracker: Mutex::new(0),
             ai_opponent,
-            action_history: Mutex::new(Vec::new()),
-            pot_tracker: Mutex::new(0),
-            actions_this_street: Mutex::new(0),
+            events,
         }
     }
 
@@ -611,8 +566,8 @@ impl GameSession {
         }
 
         let ai = self.ai_opponent.as_ref()?;
-        let engine = self.engine.lock().ok()?;
-        Some(ai.get_action(&engine, player_id))
+        let game = self.game.lock().ok()?;
+        Some(ai.get_action(&game.engine().observation(player_id)?))
     }
 
     /// Check if the specified player is AI-controlled
@@ -620,106 +575,46 @@ impl GameSession {
         player_id != 0 && self.ai_opponent.is_some()
     }
 
-    /// Apply action to engine and update game state
-    fn apply_action(&self, player_id: usize, action: PlayerAction) -> Result<Street, SessionError> {
-        let all_in_stack = if matches!(action, PlayerAction::AllIn) {
-            let engine = self
-                .engine
-                .lock()
-                .map_err(|_| SessionError::StoragePoisoned)?;
-            let stack = engine.players()[player_id].stack();
-            drop(engine);
-            Some(stack)
-        } else {
-            None
-        };
-
-        let mut pot = self
-            .pot_tracker
+    /// Apply action to engine and follow the turn and street it moves to
+    fn apply_action(&self, player_id: usize, action: PlayerAction) -> Result<(), SessionError> {
+        let mut game = self
+            .game
             .lock()
             .map_err(|_| SessionError::StoragePoisoned)?;
-        let mut actions = self
-            .actions_this_street
-            .lock()
-            .map_err(|_| SessionError::StoragePoisoned)?;
-
-        // Simple action application - in reality, this would interact with engine
-        match action {
-            PlayerAction::Fold => {
-                // Hand ends immediately
-            }
-            PlayerAction::Check => {
-                *actions += 1;
-            }
-            PlayerAction::Call => {
-                // Add call amount to pot (simplified: assume small blind amount)
-                *pot += 50;
-                *actions += 1;
-            }
-            PlayerAction::Bet(amount) => {
-                *pot += amount;
-                *actions += 1;
-            }
-            PlayerAction::Raise(amount) => {
-                *pot += amount;
-                *actions += 1;
-            }
-            PlayerAction::AllIn => {
-                if let Some(stack) = all_in_stack {
-                    *pot += stack;
-                }
-                *actions += 1;
-            }
-        }
+        game.apply_action(player_id, action)
+            .map_err(|e| SessionError::InvalidAction(e.to_string()))?;
+        let next_player = game.engine().current_player().ok();
+        let next_street = game.engine().current_street();
+        drop(game);
 
-        // Get current street
-        let state = self
+        let mut state = self
             .state
             .lock()
             .map_err(|_| SessionError::StoragePoisoned)?;
-        let current_street = match &*state {
-            GameSessionState::HandInProgress { street, .. } => *street,
-            _ => return Err(SessionError::InvalidAction("No active hand".to_string())),
-        };
-
-        Ok(current_street)
-    }
-
-    /// Record an action in the hand history
-    fn record_action(
-        &self,
-        player_id: usize,
-        action: PlayerAction,
-        street: Street,
-    ) -> Result<(), SessionError> {
-        let mut history = self
-            .action_history
-            .lock()
-            .map_err(|_| SessionError::StoragePoisoned)?;
-        history.push(ActionRecord {
-            player_id,
+        if let GameSessionState::HandInProgress {
+            current_player,
             street,
-            action,
-        });
+            ..
+        } = &mut *state
+        {
+            if let Some(player) = next_player {
+                *current_player = player;
+            }
+            if let Some(next) = next_street {
+                *street = next;
+            }
+        }
         Ok(())
     }
 
     /// Check if the current hand is complete
     fn check_hand_complete(&self) -> Result<bool, SessionError> {
-        let state = self
-            .state
+        let game = self
+            .game
             .lock()
             .map_err(|_| SessionError::StoragePoisoned)?;
-
-        // Hand is complete if someone folded or if we've seen enough actions
-        let actions = self
-            .actions_this_street
-            .lock()
-            .map_err(|_| SessionError::StoragePoisoned)?;
-
-        // Simplified: hand is complete after 8 actions (2 per street * 4 streets)
-        // or if state is Completed
-        Ok(matches!(*state, GameSessionState::Completed { .. }) || *actions >= 8)
+        let engine = game.engine();
+        Ok(engine.is_hand_complete())
     }
 
     /// Get current state
@@ -752,79 +647,34 @@ impl GameSession {
         };
         drop(state);
 
-        let engine = self
-            .engine
+        let game = self
+            .game
             .lock()
             .map_err(|_| SessionError::StoragePoisoned)?;
+        let engine = game.engine();
 
-        let board = engine.board().clone();
-        drop(engine);
-
-        let actions = self
-            .action_history
-            .lock()
-            .map_err(|_| SessionError::StoragePoisoned)?
-            .clone();
-
-        Ok(HandRecord {
-            hand_id,
-            seed: self.config.seed,
-            actions,
-            board,
-            result: Some("hand completed".to_string()),
-            ts: Some(chrono::Utc::now().to_rfc3339()),
-            meta: None,
-            showdown: None,
-        })
+        let mut record = engine.hand_record(hand_id, engine.hand_seed());
+        record.ts = Some(chrono::Utc::now().to_rfc3339());
+        Ok(record)
     }
 
     fn config(&self) -> GameConfig {
         self.config.clone()
     }
 
-    fn start_new_hand(&self) -> Result<HandMetadata, SessionError> {
-        let mut engine = self
-            .engine
-            .lock()
-            .map_err(|_| SessionError::StoragePoisoned)?;
-        let mut button = self
-            .button_tracker
+    fn start_new_hand(&self) -> Result<String, SessionError> {
+        // The match moves the button, raises the blinds and shuffles each hand
+        // from its own seed, so any hand can be dealt again from the root
+        let mut game = self
+            .game
             .lock()
             .map_err(|_| SessionError::StoragePoisoned)?;
-
-        let button_player = *button;
-        *button = 1 - *button;
-
-        for player in engine.players_mut().iter_mut() {
-            player.clear_cards();
-        }
-
-        for (idx, player) in engine.players_mut().iter_mut().enumerate() {
-            let position = if idx == button_player {
-                EnginePosition::Button
-            } else {
-                EnginePosition::BigBlind
-            };
-            player.set_position(position);
-        }
-
-        engine.shuffle();
-        engine.deal_hand().map_err(SessionError::EngineError)?;
-
-        let player_cards = engine
-            .players()
-            .iter()
-            .enumerate()
-            .map(|(idx, player)| {
-                let cards: Vec<Card> = player.hole_cards().into_iter().flatten().collect();
-                let cards = if idx == 0 && !cards.is_empty() {
-                    Some(cards)
-                } else {
-                    None
-                };
-                (idx, cards)
-            })
-            .collect();
+        game.start_hand()
+            .map_err(|e| SessionError::EngineError(e.to_string()))?;
+        let first_player = game
+            .engine()
+            .current_player()
+            .map_err(|e| SessionError::EngineError(e.to_string()))?;
 
         let hand_id = Uuid::new_v4().to_string();
         {
@@ -834,53 +684,67 @@ impl GameSession {
                 .map_err(|_| SessionError::StoragePoisoned)?;
             *state = GameSessionState::HandInProgress {
                 hand_id: hand_id.clone(),
-                current_player: button_player,
+                current_player: first_player,
                 street: Street::Preflop,
             };
         }
-        drop(engine);
+        drop(game);
 
-        // Clear action history for new hand
-        {
-            let mut history = self
-                .action_history
-                .lock()
-                .map_err(|_| SessionError::StoragePoisoned)?;
-            history.clear();
-        }
+        self.touch();
 
-        // Reset pot tracker
-        {
-            let mut pot = self
-                .pot_tracker
-                .lock()
-                .map_err(|_| SessionError::StoragePoisoned)?;
-            *pot = 150; // Initial pot with blinds (50 SB + 100 BB)
-        }
+        Ok(hand_id)
+    }
 
-        // Reset actions counter
-        {
-            let mut actions = self
-                .actions_this_street
-                .lock()
-                .map_err(|_| SessionError::StoragePoisoned)?;
-            *actions = 0;
+    /// Translate an engine event into what clients receive, if they care about it.
+    ///
+    /// Only the human in seat 0 gets to see hole cards.
+    fn game_event(&self, event: EngineEvent) -> Option<GameEvent> {
+        let session_id = self.id.clone();
+        match event {
+            EngineEvent::HandStarted { button, .. } => Some(GameEvent::HandStarted {
+                session_id,
+                hand_id: self.hand_id()?,
+                button_player: button,
+            }),
+            EngineEvent::HoleCardsDealt { player_id, cards } => Some(GameEvent::CardsDealt {
+                session_id,
+                player_id,
+                cards: (player_id == 0).then_some(cards),
+            }),
+            EngineEvent::ActionApplied { record, .. } => Some(GameEvent::PlayerAction {
+                session_id,
+                player_id: record.player_id,
+                action: record.action,
+            }),
+            EngineEvent::StreetChanged { street, board, .. } => Some(GameEvent::CommunityCards {
+                session_id,
+                cards: board,
+                street,
+            }),
+            EngineEvent::HandCompleted(outcome) => Some(GameEvent::HandCompleted {
+                session_id,
+                result: hand_result(
+                    &outcome,
+                    self.game.lock().ok().as_deref().map(Match::engine),
+                ),
+            }),
+            _ => None,
         }
+    }
 
-        self.touch();
-
-        Ok(HandMetadata {
-            hand_id,
-            button_player,
-            player_cards,
-        })
+    fn hand_id(&self) -> Option<String> {
+        match &*self.state.lock().ok()? {
+            GameSessionState::HandInProgress { hand_id, .. } => Some(hand_id.clone()),
+            _ => None,
+        }
     }
 
     fn snapshot_players(&self) -> Result<Vec<PlayerInfo>, SessionError> {
-        let engine = self
-            .engine
+        let game = self
+            .game
             .lock()
             .map_err(|_| SessionError::StoragePoisoned)?;
+        let engine = game.engine();
         let players = engine
             .players()
             .iter()
@@ -918,17 +782,6 @@ impl GameSession {
         })
     }
 
-    fn advance_turn(&self) -> Result<(), SessionError> {
-        let mut state = self
-            .state
-            .lock()
-            .map_err(|_| SessionError::StoragePoisoned)?;
-        if let GameSessionState::HandInProgress { current_player, .. } = &mut *state {
-            *current_player = (*current_player + 1) % 2;
-        }
-        Ok(())
-    }
-
     fn state_snapshot(&self) -> Result<GameStateResponse, SessionError> {
         let state = self
             .state
@@ -947,18 +800,18 @@ impl GameSession {
             _ => (None, None, None),
         };
 
-        let engine = self
-            .engine
+        let game = self
+            .game
             .lock()
             .map_err(|_| SessionError::StoragePoisoned)?;
+        let engine = game.engine();
         let players = engine
             .players()
             .iter()
             .enumerate()
             .map(|(idx, player)| {
-                let cards_vec: Vec<Card> = player.hole_cards().into_iter().flatten().collect();
-                let hole_cards = if idx == 0 && !cards_vec.is_empty() {
-                    Some(cards_vec)
+                let hole_cards = if idx == 0 && !player.hole().is_empty() {
+                    Some(player.hole().to_vec())
                 } else {
                     None
                 };
@@ -974,15 +827,14 @@ impl GameSession {
             })
             .collect();
 
-        let board_full = engine.board().clone();
-        drop(engine);
-
-        let board = visible_board(&board_full, street);
-
-        let pot = *self
-            .pot_tracker
-            .lock()
-            .map_err(|_| SessionError::StoragePoisoned)?;
+        // The engine only exposes community cards that have been dealt
+        let board = engine.board().clone();
+        let available_actions = current_player
+            .map(|player| Self::available_actions(&engine.legal_actions(player)))
+            .unwrap_or_default();
+        let pot = engine.pot();
+        let shuffle_commitment = engine.shuffle_commitment();
+        drop(game);
 
         Ok(GameStateResponse {
             session_id: self.id.clone(),
@@ -990,96 +842,70 @@ impl GameSession {
             board,
             pot,
             current_player,
-            available_actions: Self::default_actions(),
+            available_actions,
             hand_id,
             street,
+            shuffle_commitment,
         })
     }
 
-    fn default_actions() -> Vec<AvailableAction> {
-        vec![
-            AvailableAction {
-                action_type: "fold".into(),
-                min_amount: None,
-                max_amount: None,
-            },
-            AvailableAction {
-                action_type: "check".into(),
-                min_amount: None,
-                max_amount: None,
-            },
-            AvailableAction {
+    /// Translate the engine's legal actions into the API's action list.
+    ///
+    /// Raise amounts are what gets added on top of the call, matching how
+    /// `PlayerAction::Raise` is submitted.
+    fn available_actions(legal: &LegalActions) -> Vec<AvailableAction> {
+        let simple = |action_type: &str| AvailableAction {
+            action_type: action_type.into(),
+            min_amount: None,
+            max_amount: None,
+        };
+        let mut actions = Vec::new();
+        if legal.fold {
+            actions.push(simple("fold"));
+        }
+        if legal.check {
+            actions.push(simple("check"));
+        }
+        if let Some(amount) = legal.call {
+            actions.push(AvailableAction {
+                action_type: "call".into(),
+                min_amount: Some(amount),
+                max_amount: Some(amount),
+            });
+        }
+        if let Some(range) = legal.bet {
+            actions.push(AvailableAction {
                 action_type: "bet".into(),
-                min_amount: Some(100),
-                max_amount: Some(2_000),
-            },
-        ]
+                min_amount: Some(range.min),
+                max_amount: Some(range.max),
+            });
+        }
+        if let Some(range) = legal.raise_to {
+            actions.push(AvailableAction {
+                action_type: "raise".into(),
+                min_amount: Some(range.min - legal.current_bet),
+                max_amount: Some(range.max - legal.current_bet),
+            });
+        }
+        if legal.all_in.is_some() {
+            actions.push(simple("all_in"));
+        }
+        actions
     }
 
-    /// Determine winners based on current game state
+    /// Winners of the main pot of the hand the engine just settled
     fn determine_winners(&self) -> Result<Vec<usize>, SessionError> {
-        // Check if hand ended by fold
-        if let Some(last_action) = self
-            .action_history
-            .lock()
-            .ok()
-            .and_then(|h| h.last().cloned())
-            && matches!(last_action.action, PlayerAction::Fold)
-        {
-            // If player folded, the other player wins
-            let winner = if last_action.player_id == 0 { 1 } else { 0 };
-            return Ok(vec![winner]);
-        }
-
-        // Showdown - evaluate hands
-        let engine = self
-            .engine
+        let game = self
+            .game
             .lock()
             .map_err(|_| SessionError::StoragePoisoned)?;
-
-        let board = engine.board();
-        if board.len() != 5 {
-            return Err(SessionError::InvalidAction(format!(
-                "Invalid board size: {} (expected 5)",
-                board.len()
-            )));
-        }
-
-        // Get player hands
-        let players = engine.players();
-
-        // Extract player 0's cards
-        let p0_hole = players[0].hole_cards();
-        let p0_card0 = p0_hole[0]
-            .ok_or_else(|| SessionError::InvalidAction("Player 0 missing hole card".into()))?;
-        let p0_card1 = p0_hole[1]
-            .ok_or_else(|| SessionError::InvalidAction("Player 0 missing hole card".into()))?;
-
-        // Extract player 1's cards
-        let p1_hole = players[1].hole_cards();
-        let p1_card0 = p1_hole[0]
-            .ok_or_else(|| SessionError::InvalidAction("Player 1 missing hole card".into()))?;
-        let p1_card1 = p1_hole[1]
-            .ok_or_else(|| SessionError::InvalidAction("Player 1 missing hole card".into()))?;
-
-        // Build 7-card arrays
-        let p0_seven = [
-            p0_card0, p0_card1, board[0], board[1], board[2], board[3], board[4],
-        ];
-        let p1_seven = [
-            p1_card0, p1_card1, board[0], board[1], board[2], board[3], board[4],
-        ];
-
-        // Evaluate hands
-        let p0_strength = evaluate_hand(&p0_seven);
-        let p1_strength = evaluate_hand(&p1_seven);
-
-        // Compare and determine winner(s)
-        match compare_hands(&p0_strength, &p1_strength) {
-            Ordering::Greater => Ok(vec![0]),  // Player 0 wins
-            Ordering::Less => Ok(vec![1]),     // Player 1 wins
-            Ordering::Equal => Ok(vec![0, 1]), // Split pot
-        }
+        let engine = game.engine();
+        let mut winners = engine
+            .hand_outcome()
+            .map(|outcome| outcome.winners.clone())
+            .ok_or_else(|| SessionError::EngineError("Hand was not settled".into()))?;
+        winners.sort_unstable();
+        Ok(winners)
     }
 
     /// Complete hand and store winners
@@ -1095,6 +921,30 @@ impl GameSession {
     }
 }
 
+/// Summarize a settled hand for the HandCompleted event.
+///
+/// Hands won at showdown are described from the engine's final board.
+fn hand_result(outcome: &HandOutcome, engine: Option<&Engine>) -> HandResult {
+    let mut winner_ids = outcome.winners.clone();
+    winner_ids.sort_unstable();
+    let uncalled = outcome.uncalled_bet.map_or(0, |(_, amount)| amount);
+    let hand_description = engine
+        .filter(|_| outcome.end_reason == EndReason::Showdown)
+        .and_then(|engine| {
+            let winner = *outcome.winners.first()?;
+            engine
+                .variant()
+                .best_hand(engine.players()[winner].hole(), engine.board())
+                .ok()
+                .map(|best| best.describe())
+        });
+    HandResult {
+        winner_ids,
+        pot: outcome.pots.iter().map(|pot| pot.amount).sum::<u32>() + uncalled,
+        hand_description,
+    }
+}
+
 #[cfg(test)]
 impl GameSession {
     fn force_last_active(&self, instant: Instant) {
@@ -1104,19 +954,10 @@ impl GameSession {
     }
 }
 
-fn visible_board(cards: &[Card], street: Option<Street>) -> Vec<Card> {
-    let count = match street {
-        Some(Street::Preflop) | None => 0,
-        Some(Street::Flop) => min(3, cards.len()),
-        Some(Street::Turn) => min(4, cards.len()),
-        Some(Street::River) => min(5, cards.len()),
-    };
-    cards.iter().cloned().take(count).collect()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
+    use axiomind_engine::seed::{SeedPath, SeedTree};
     use std::collections::HashSet;
     use std::sync::Arc;
     use std::thread;
@@ -1143,7 +984,7 @@ mod tests {
 
         let mut sub = manager.event_bus().subscribe(id.clone());
         let event = manager
-            .process_action(&id, PlayerAction::Check)
+            .process_action(&id, PlayerAction::Call)
             .expect("process action");
         match event {
             GameEvent::PlayerAction { session_id, .. } => assert_eq!(session_id, id),
@@ -1290,7 +1131,7 @@ mod tests {
         // If it's human's turn (player 0)
         if current_player == 0 {
             manager
-                .process_action(&id, PlayerAction::Check)
+                .process_action(&id, PlayerAction::Call)
                 .expect("process action");
 
             // Check events - should have both human action and AI response
@@ -1363,18 +1204,22 @@ mod tests {
 
         let id = manager.create_session(config).expect("create session");
 
-        // Simulate some actions
-        let session = manager.get_session(&id).expect("get session");
-        session
-            .record_action(0, PlayerAction::Check, Street::Preflop)
-            .expect("record action");
-        session
-            .record_action(1, PlayerAction::Check, Street::Preflop)
-            .expect("record action");
-
-        // Verify actions were recorded in session
-        let actions = session.action_history.lock().expect("lock history");
-        assert_eq!(actions.len(), 2);
+        // Play the hand until it ends
+        manager
+            .process_action(&id, PlayerAction::Call)
+            .expect("call");
+        manager
+            .process_action(&id, PlayerAction::Check)
+            .expect("check");
+        manager
+            .process_action(&id, PlayerAction::Fold)
+            .expect("fold");
+
+        // The finished hand was recorded with the engine's actions
+        let hands = history.get_recent_hands(None).expect("recent hands");
+        assert_eq!(hands.len(), 1);
+        assert_eq!(hands[0].actions.len(), 3);
+        assert_eq!(hands[0].board.len(), 3);
     }
 
     #[test]
@@ -1391,22 +1236,110 @@ mod tests {
         let id = manager.create_session(config).expect("create session");
         let session = manager.get_session(&id).expect("get session");
 
-        // Record some actions
-        session
-            .record_action(0, PlayerAction::Bet(100), Street::Preflop)
-            .expect("record action");
-        session
-            .record_action(1, PlayerAction::Call, Street::Preflop)
-            .expect("record action");
+        // Take some actions
+        manager
+            .process_action(&id, PlayerAction::Raise(100))
+            .expect("raise");
+        manager
+            .process_action(&id, PlayerAction::Call)
+            .expect("call");
 
         // Create hand record
         let record = session.create_hand_record().expect("create record");
 
-        assert_eq!(record.seed, Some(123));
+        let derivation = &record.meta.as_ref().expect("meta")["seed_derivation"];
+        assert_eq!(derivation["root"], 123);
+        assert_eq!(derivation["hand"], 0);
+        assert_eq!(
+            record.seed,
+            Some(SeedTree::new(123).hand_seed(SeedPath::hand(0)))
+        );
         assert_eq!(record.actions.len(), 2);
         assert_eq!(record.actions[0].player_id, 0);
         assert_eq!(record.actions[1].player_id, 1);
     }
+
+    #[test]
+    fn deck_commitment_is_published_and_revealed_in_history() {
+        let event_bus = Arc::new(EventBus::new());
+        let history = Arc::new(HistoryStore::new());
+        let manager = SessionManager::with_ttl_and_history(
+            event_bus.clone(),
+            Duration::from_secs(60),
+            history.clone(),
+        );
+
+        let config = GameConfig {
+            seed: Some(7),
+            level: 1,
+            opponent_type: OpponentType::Human,
+        };
+        let id = manager.create_session(config).expect("create session");
+
+        // The commitment is public while the hand is played
+        let commitment = manager
+            .state(&id)
+            .expect("state")
+            .shuffle_commitment
+            .expect("commitment");
+        manager
+            .process_action(&id, PlayerAction::Fold)
+            .expect("fold");
+
+        let hands = history.get_recent_hands(None).expect("recent hands");
+        let fairness = &hands[0].meta.as_ref().expect("meta")["fairness"];
+        assert_eq!(fairness["commitment"], commitment.as_str());
+        let reveal: axiomind_engine::deck::ShuffleReveal =
+            serde_json::from_value(fairness["reveal"].clone()).expect("reveal");
+        reveal.verify(&commitment).expect("reveal matches");
+        reveal.check_deal(&hands[0]).expect("dealt from the deck");
+    }
+
+    #[test]
+    fn every_hand_is_dealt_from_its_own_seed() {
+        let event_bus = Arc::new(EventBus::new());
+        let history = Arc::new(HistoryStore::new());
+        let manager = SessionManager::with_ttl_and_history(
+            event_bus.clone(),
+            Duration::from_secs(60),
+            history.clone(),
+        );
+
+        let config = GameConfig {
+            seed: Some(5),
+            level: 1,
+            opponent_type: OpponentType::Human,
+        };
+        let id = manager.create_session(config).expect("create session");
+        let session = manager.get_session(&id).expect("get session");
+        manager
+            .process_action(&id, PlayerAction::Fold)
+            .expect("fold");
+        session.start_new_hand().expect("second hand");
+        let record = session.create_hand_record().expect("create record");
+
+        let tree = SeedTree::new(5);
+        let first = &history.get_recent_hands(None).expect("recent hands")[0];
+        assert_eq!(first.seed, Some(tree.hand_seed(SeedPath::hand(0))));
+        assert_eq!(record.seed, Some(tree.hand_seed(SeedPath::hand(1))));
+        assert_eq!(
+            record.meta.as_ref().expect("meta")["seed_derivation"]["hand"],
+            1
+        );
+
+        // Hand 1 deals the same cards when dealt on its own
+        let mut alone = Engine::new(None, 1)
+            .with_commit_reveal()
+            .expect("no pinned cards")
+            .with_seed_path(tree, SeedPath::hand(1))
+            .expect("seeded deck");
+        alone.set_button(1);
+        alone.deal_hand().expect("deal");
+        assert_eq!(
+            record.players[0].hole_cards.as_deref(),
+            Some(alone.players()[0].hole())
+        );
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -1493,7 +1426,11 @@ pub struct PlayerStateResponse {
     pub id: usize,
     pub stack: u32,
     pub position: SeatPosition,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "axiomind_engine::cards::compact::option_vec"
+    )]
     pub hole_cards: Option<Vec<Card>>,
     pub is_active: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1504,6 +1441,7 @@ pub struct PlayerStateResponse {
 pub struct GameStateResponse {
     pub session_id: SessionId,
     pub players: Vec<PlayerStateResponse>,
+    #[serde(with = "axiomind_engine::cards::compact::vec")]
     pub board: Vec<Card>,
     pub pot: u32,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1513,6 +1451,10 @@ pub struct GameStateResponse {
     pub hand_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub street: Option<Street>,
+    /// SHA-256 commitment to the hand's deck; the seed and order behind it
+    /// are revealed in the hand history once the hand is over
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub shuffle_commitment: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -197,7 +197,7 @@ async fn test_session_cleanup_isolation() {
     // Second session should still be functional
     let result = context
         .sessions()
-        .process_action(&session_id_2, axiomind_engine::player::PlayerAction::Check);
+        .process_action(&session_id_2, axiomind_engine::player::PlayerAction::Call);
     assert!(result.is_ok());
 }
 
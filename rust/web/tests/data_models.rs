@@ -61,8 +61,16 @@ fn game_state_response_roundtrips_via_json() {
         }],
         hand_id: Some("h1".into()),
         street: Some(Street::Flop),
+        shuffle_commitment: None,
     };
 
+    let value = serde_json::to_value(&state).expect("serialize state");
+    assert_eq!(value["board"], serde_json::json!(["Th"]));
+    assert_eq!(
+        value["players"][0]["hole_cards"],
+        serde_json::json!(["As", "Ks"])
+    );
+
     let json = serde_json::to_string(&state).expect("serialize state");
     let decoded: GameStateResponse = serde_json::from_str(&json).expect("deserialize state");
     assert_eq!(decoded.session_id, "s1");
@@ -28,7 +28,7 @@ async fn test_engine_full_hand_showdown() {
 
     // Play through actions
     let actions = vec![
-        PlayerAction::Check,
+        PlayerAction::Call,
         PlayerAction::Check, // Preflop
         PlayerAction::Check,
         PlayerAction::Check, // Flop
@@ -477,3 +477,142 @@ impl ActionDiscriminant for PlayerAction {
         }
     }
 }
+
+/// Test available actions come from the engine's legal actions
+#[tokio::test]
+async fn test_available_actions_follow_engine() {
+    let context = AppContext::new(ServerConfig::for_tests()).expect("create context");
+
+    let session_id = context
+        .sessions()
+        .create_session(GameConfig {
+            seed: Some(555555),
+            level: 1,
+            opponent_type: OpponentType::Human,
+        })
+        .expect("create session");
+
+    let state = context.sessions().state(&session_id).expect("get state");
+    let player = state.current_player.expect("someone to act");
+    assert_eq!(player, 0, "Button acts first preflop");
+
+    let types: Vec<&str> = state
+        .available_actions
+        .iter()
+        .map(|a| a.action_type.as_str())
+        .collect();
+    assert_eq!(types, vec!["fold", "call", "raise", "all_in"]);
+
+    let call = &state.available_actions[1];
+    assert_eq!(call.min_amount, Some(50));
+    let raise = &state.available_actions[2];
+    assert_eq!(raise.min_amount, Some(100));
+    assert_eq!(raise.max_amount, Some(19_900));
+}
+
+/// Events are the engine's own account of the hand
+#[tokio::test]
+async fn test_events_follow_engine_state() {
+    let context = AppContext::new(ServerConfig::for_tests()).expect("create context");
+
+    let session_id = context
+        .sessions()
+        .create_session(GameConfig {
+            seed: Some(121212),
+            level: 1,
+            opponent_type: OpponentType::Human,
+        })
+        .expect("create session");
+    let mut subscription = context.event_bus().subscribe(session_id.clone());
+
+    // An illegal action is rejected and reports nothing
+    assert!(
+        context
+            .sessions()
+            .process_action(&session_id, PlayerAction::Check)
+            .is_err()
+    );
+    assert!(subscription.receiver.try_recv().is_err());
+
+    context
+        .sessions()
+        .process_action(&session_id, PlayerAction::Call)
+        .expect("call");
+    context
+        .sessions()
+        .process_action(&session_id, PlayerAction::Check)
+        .expect("check");
+
+    let mut events = Vec::new();
+    while let Ok(event) = subscription.receiver.try_recv() {
+        events.push(event);
+    }
+    let state = context.sessions().state(&session_id).expect("get state");
+    assert_eq!(state.pot, 200);
+    assert_eq!(state.board.len(), 3);
+    match events.as_slice() {
+        [
+            GameEvent::PlayerAction { player_id: 0, .. },
+            GameEvent::PlayerAction { player_id: 1, .. },
+            GameEvent::CommunityCards { cards, street, .. },
+        ] => {
+            assert_eq!(cards, &state.board);
+            assert_eq!(*street, axiomind_engine::logger::Street::Flop);
+        }
+        other => panic!("unexpected events: {:?}", other),
+    }
+
+    // The big blind acts first after the flop
+    assert_eq!(state.current_player, Some(1));
+    context
+        .sessions()
+        .process_action(&session_id, PlayerAction::Fold)
+        .expect("fold");
+    let mut completed = None;
+    while let Ok(event) = subscription.receiver.try_recv() {
+        if let GameEvent::HandCompleted { result, .. } = event {
+            completed = Some(result);
+        }
+    }
+    let result = completed.expect("hand completed event");
+    assert_eq!(result.winner_ids, vec![0]);
+    assert_eq!(result.pot, 200);
+    assert_eq!(result.hand_description, None);
+}
+
+/// A showdown result names the winning hand
+#[tokio::test]
+async fn test_showdown_result_describes_winning_hand() {
+    let context = AppContext::new(ServerConfig::for_tests()).expect("create context");
+
+    let session_id = context
+        .sessions()
+        .create_session(GameConfig {
+            seed: Some(343434),
+            level: 1,
+            opponent_type: OpponentType::Human,
+        })
+        .expect("create session");
+    let mut subscription = context.event_bus().subscribe(session_id.clone());
+
+    context
+        .sessions()
+        .process_action(&session_id, PlayerAction::Call)
+        .expect("call");
+    for _ in 0..7 {
+        context
+            .sessions()
+            .process_action(&session_id, PlayerAction::Check)
+            .expect("check");
+    }
+
+    let mut completed = None;
+    while let Ok(event) = subscription.receiver.try_recv() {
+        if let GameEvent::HandCompleted { result, .. } = event {
+            completed = Some(result);
+        }
+    }
+    let result = completed.expect("hand completed event");
+    let description = result.hand_description.expect("description");
+    assert!(description.contains(", ") || description == "Royal Flush");
+}
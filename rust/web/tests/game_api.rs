@@ -90,7 +90,7 @@ async fn session_api_lifecycle() {
         .method(hyper::Method::POST)
         .uri(action_uri.clone())
         .header(hyper::header::CONTENT_TYPE, "application/json")
-        .body(Body::from(json!({ "action": "Check" }).to_string()))
+        .body(Body::from(json!({ "action": "Call" }).to_string()))
         .expect("build action request");
     let action_response = client
         .request(action_request)
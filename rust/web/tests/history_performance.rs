@@ -16,11 +16,13 @@ fn create_test_hand(hand_id: &str) -> HandRecord {
                 player_id: 0,
                 street: Street::Preflop,
                 action: PlayerAction::Bet(100),
+                amount: None,
             },
             ActionRecord {
                 player_id: 1,
                 street: Street::Preflop,
                 action: PlayerAction::Call,
+                amount: None,
             },
         ],
         board: vec![
@@ -41,6 +43,7 @@ fn create_test_hand(hand_id: &str) -> HandRecord {
         ts: Some("2025-01-01T12:00:00Z".to_string()),
         meta: None,
         showdown: None,
+        ..Default::default()
     }
 }
 